@@ -0,0 +1,549 @@
+// Proptest strategies for fuzzing code that handles blocks, with values Slack
+// would accept:
+//
+//     use proptest::prelude::*;
+//     use slack_blocks::surfaces::Modal;
+//
+//     proptest! {
+//         #[test]
+//         fn handles_any_modal(modal in any::<Modal>()) { ... }
+//     }
+//
+// Everything generated passes `Validate`. Text uses the full length each
+// field allows, but lists are kept shorter than Slack's limits so cases stay
+// quick to generate and shrink.
+use crate::blocks::{
+    Divider, Input, InputBuilder, InputElement, Section, SectionBuilder, SectionElement,
+};
+use crate::elements::*;
+use crate::objects::{ConfirmationDialog, Mrkdwn, OptionInput, OptionInputGroup, PlainText, Text};
+use crate::surfaces::{HomeTab, HomeTabBlock, Modal, ModalBlock, ModalBuilder};
+
+use proptest::collection::vec;
+use proptest::option::of;
+use proptest::prelude::*;
+use proptest::sample::{select, subsequence};
+use proptest::string::string_regex;
+use url::Url;
+
+// Standalone text is kept to the shortest common label limit, e.g. a
+// button's; fields with other limits generate their own.
+const TEXT_LENGTH: usize = 75;
+
+macro_rules! arbitrary {
+    ($($ty:ty => $strategy:expr;)*) => {
+        $(
+            impl Arbitrary for $ty {
+                type Parameters = ();
+                type Strategy = BoxedStrategy<Self>;
+
+                fn arbitrary_with(_: ()) -> Self::Strategy {
+                    $strategy.boxed()
+                }
+            }
+        )*
+    };
+}
+
+arbitrary! {
+    PlainText => plain_text(TEXT_LENGTH);
+    Mrkdwn => mrkdwn(TEXT_LENGTH);
+    Text => text(TEXT_LENGTH);
+    OptionInput<PlainText> => menu_option();
+    OptionInput<Text> => choice_option();
+    Button => button();
+    SelectMenu => select_menu();
+    MultiSelectMenu => multi_select_menu();
+    OverflowMenu => overflow_menu();
+    Section => section();
+    Input => input();
+    Modal => modal();
+    HomeTab => home_tab();
+}
+
+// Non-empty, up to `max` characters.
+fn string(max: usize) -> impl Strategy<Value = String> {
+    string_regex(&format!(r"\PC{{1,{}}}", max)).unwrap()
+}
+
+fn url() -> impl Strategy<Value = Url> {
+    string_regex(r"https://example\.com/[a-z0-9/_-]{0,64}")
+        .unwrap()
+        .prop_map(|url| Url::parse(&url).unwrap())
+}
+
+fn user_id() -> impl Strategy<Value = String> {
+    string_regex("U[A-Z0-9]{8,10}").unwrap()
+}
+
+fn conversation_id() -> impl Strategy<Value = String> {
+    string_regex("[CDG][A-Z0-9]{8,10}").unwrap()
+}
+
+fn channel_id() -> impl Strategy<Value = String> {
+    string_regex("C[A-Z0-9]{8,10}").unwrap()
+}
+
+fn plain_text(max: usize) -> impl Strategy<Value = PlainText> {
+    (string(max), any::<Option<bool>>()).prop_map(|(text, emoji)| match emoji {
+        Some(emoji) => PlainText::new_with_emoji(text, emoji),
+        None => PlainText::new(text),
+    })
+}
+
+fn mrkdwn(max: usize) -> impl Strategy<Value = Mrkdwn> {
+    (string(max), any::<bool>()).prop_map(|(text, verbatim)| match verbatim {
+        true => Mrkdwn::new_verbatim(text),
+        false => Mrkdwn::new(text),
+    })
+}
+
+fn text(max: usize) -> impl Strategy<Value = Text> {
+    prop_oneof![
+        plain_text(max).prop_map(Text::from),
+        mrkdwn(max).prop_map(Text::from),
+    ]
+}
+
+fn confirm() -> impl Strategy<Value = ConfirmationDialog> {
+    (string(100), text(300), string(30), string(30)).prop_map(|(title, text, confirm, deny)| {
+        ConfirmationDialog::new(title, text, confirm, deny)
+    })
+}
+
+// Options in select menus only have a label and value.
+fn menu_option() -> impl Strategy<Value = OptionInput<PlainText>> {
+    (plain_text(75), string(150))
+        .prop_map(|(text, value)| OptionInput::builder(text, value).build())
+}
+
+// Checkbox and radio button options can also be described.
+fn choice_option() -> impl Strategy<Value = OptionInput<Text>> {
+    (text(75), string(150), of(text(75))).prop_map(|(text, value, description)| {
+        let mut option = OptionInput::builder(text, value);
+        if let Some(description) = description {
+            option = option.set_description(description);
+        }
+        option.build()
+    })
+}
+
+// Overflow menu options can also link somewhere.
+fn overflow_option() -> impl Strategy<Value = OptionInput<PlainText>> {
+    (plain_text(75), string(150), of(url())).prop_map(|(text, value, url)| {
+        let mut option = OptionInput::builder(text, value);
+        if let Some(url) = url {
+            option = option.set_url(url);
+        }
+        option.build()
+    })
+}
+
+fn button() -> impl Strategy<Value = Button> {
+    let style = prop_oneof![Just(ButtonStyle::Primary), Just(ButtonStyle::Danger)];
+    (
+        string(255),
+        plain_text(75),
+        of(url()),
+        of(string(2000)),
+        of(style),
+        of(confirm()),
+    )
+        .prop_map(|(action_id, text, url, value, style, confirm)| {
+            let mut button = ButtonBuilder::new(action_id, text);
+            if let Some(url) = url {
+                button = button.set_url(url);
+            }
+            if let Some(value) = value {
+                button = button.set_value(value);
+            }
+            if let Some(style) = style {
+                button = button.set_style(style);
+            }
+            if let Some(confirm) = confirm {
+                button = button.set_confirm(confirm);
+            }
+            button.build()
+        })
+}
+
+fn options() -> impl Strategy<Value = OptionNestingType> {
+    let group = (plain_text(75), vec(menu_option(), 1..=5))
+        .prop_map(|(label, options)| OptionInputGroup::new(label, options));
+    prop_oneof![
+        vec(menu_option(), 1..=10).prop_map(OptionNestingType::Flat),
+        vec(group, 1..=5).prop_map(OptionNestingType::Groups),
+    ]
+}
+
+// Every option in the menu, to pick initial ones from.
+fn all_options(options: &OptionNestingType) -> Vec<OptionInput<PlainText>> {
+    match options {
+        OptionNestingType::Flat(options) => options.clone(),
+        OptionNestingType::Groups(groups) => groups
+            .iter()
+            .flat_map(|group| group.options().to_vec())
+            .collect(),
+    }
+}
+
+fn select_menu_type() -> impl Strategy<Value = SelectMenuType> {
+    let static_menu = options()
+        .prop_flat_map(|options| {
+            let initial = of(select(all_options(&options)));
+            (Just(options), initial)
+        })
+        .prop_map(|(options, initial)| match initial {
+            Some(initial) => StaticMenu::new_with_initial(options, initial),
+            None => StaticMenu::new(options),
+        });
+    let external_menu = (of(menu_option()), of(0u32..=10)).prop_map(|(initial, query_length)| {
+        let mut menu = ExternalMenuBuilder::new();
+        if let Some(initial) = initial {
+            menu = menu.set_initial_option(initial);
+        }
+        if let Some(query_length) = query_length {
+            menu = menu.set_query_length(query_length);
+        }
+        menu.build()
+    });
+    prop_oneof![
+        static_menu.prop_map(SelectMenuType::Static),
+        external_menu.prop_map(SelectMenuType::External),
+        of(user_id()).prop_map(|initial| SelectMenuType::User(match initial {
+            Some(initial) => UserMenu::new_with_initial(initial),
+            None => UserMenu::new(),
+        })),
+        of(conversation_id()).prop_map(|initial| SelectMenuType::Conversation(match initial {
+            Some(initial) => ConversationMenu::new_with_initial(initial),
+            None => ConversationMenu::new(),
+        })),
+        of(channel_id()).prop_map(|initial| SelectMenuType::Channel(match initial {
+            Some(initial) => ChannelMenu::new_with_initial(initial),
+            None => ChannelMenu::new(),
+        })),
+    ]
+}
+
+fn select_menu() -> impl Strategy<Value = SelectMenu> {
+    (
+        string(255),
+        plain_text(150),
+        select_menu_type(),
+        of(confirm()),
+    )
+        .prop_map(|(action_id, placeholder, menu_type, confirm)| {
+            let mut menu = SelectMenu::builder(action_id, placeholder, menu_type);
+            if let Some(confirm) = confirm {
+                menu = menu.set_confirm(confirm);
+            }
+            menu.build()
+        })
+}
+
+fn multi_select_menu_type() -> impl Strategy<Value = MultiSelectMenuType> {
+    let static_menu = options()
+        .prop_flat_map(|options| {
+            let all = all_options(&options);
+            let initial = of(subsequence(all.clone(), 0..=all.len()));
+            (Just(options), initial)
+        })
+        .prop_map(|(options, initial)| match initial {
+            Some(initial) => StaticMultiMenu::new_with_initial(options, initial),
+            None => StaticMultiMenu::new(options),
+        });
+    let external_menu =
+        (of(vec(menu_option(), 1..=5)), of(0u32..=10)).prop_map(|(initial, query_length)| {
+            let mut menu = ExternalMultiMenuBuilder::new();
+            if let Some(initial) = initial {
+                menu = menu.set_initial_options(initial);
+            }
+            if let Some(query_length) = query_length {
+                menu = menu.set_query_length(query_length);
+            }
+            menu.build()
+        });
+    prop_oneof![
+        static_menu.prop_map(MultiSelectMenuType::Static),
+        external_menu.prop_map(MultiSelectMenuType::External),
+        of(vec(user_id(), 1..=5)).prop_map(|initial| MultiSelectMenuType::User(match initial {
+            Some(initial) => UserMultiMenu::new_with_initial(initial),
+            None => UserMultiMenu::new(),
+        })),
+        of(vec(conversation_id(), 1..=5)).prop_map(|initial| {
+            MultiSelectMenuType::Conversation(match initial {
+                Some(initial) => ConversationMultiMenu::new_with_initial(initial),
+                None => ConversationMultiMenu::new(),
+            })
+        }),
+        of(vec(channel_id(), 1..=5)).prop_map(|initial| {
+            MultiSelectMenuType::Channel(match initial {
+                Some(initial) => ChannelMultiMenu::new_with_initial(initial),
+                None => ChannelMultiMenu::new(),
+            })
+        }),
+    ]
+}
+
+fn multi_select_menu() -> impl Strategy<Value = MultiSelectMenu> {
+    (
+        string(255),
+        plain_text(150),
+        multi_select_menu_type(),
+        of(1u32..=100),
+        of(confirm()),
+    )
+        .prop_map(
+            |(action_id, placeholder, menu_type, max_selected, confirm)| {
+                let mut menu = MultiSelectMenu::builder(action_id, placeholder, menu_type);
+                if let Some(max_selected) = max_selected {
+                    menu = menu.set_max_selected_items(max_selected);
+                }
+                if let Some(confirm) = confirm {
+                    menu = menu.set_confirm(confirm);
+                }
+                menu.build()
+            },
+        )
+}
+
+fn overflow_menu() -> impl Strategy<Value = OverflowMenu> {
+    (string(255), vec(overflow_option(), 2..=5), of(confirm())).prop_map(
+        |(action_id, options, confirm)| {
+            let mut menu = OverflowMenu::builder(action_id, options);
+            if let Some(confirm) = confirm {
+                menu = menu.set_confirm(confirm);
+            }
+            menu.build()
+        },
+    )
+}
+
+fn plain_text_input() -> impl Strategy<Value = PlainTextInput> {
+    (
+        string(255),
+        of(plain_text(150)),
+        of(string(3000)),
+        any::<Option<bool>>(),
+        of(0u32..=3000),
+        of(1u32..=3000),
+    )
+        .prop_map(
+            |(action_id, placeholder, initial_value, multiline, min, max)| {
+                // Swapped lengths are still in range, as the larger is at
+                // least 1.
+                let (min, max) = match (min, max) {
+                    (Some(min), Some(max)) if min > max => (Some(max), Some(min)),
+                    lengths => lengths,
+                };
+                let mut input = PlainTextInputBuilder::new(action_id);
+                if let Some(placeholder) = placeholder {
+                    input = input.set_placeholder(placeholder);
+                }
+                if let Some(initial_value) = initial_value {
+                    input = input.set_initial_value(initial_value);
+                }
+                if let Some(multiline) = multiline {
+                    input = input.set_multiline(multiline);
+                }
+                if let Some(min) = min {
+                    input = input.set_min_length(min);
+                }
+                if let Some(max) = max {
+                    input = input.set_max_length(max);
+                }
+                input.build()
+            },
+        )
+}
+
+fn section() -> impl Strategy<Value = Section> {
+    let accessory = prop_oneof![
+        any::<Button>().prop_map(SectionElement::Button),
+        any::<SelectMenu>().prop_map(SectionElement::SelectMenu),
+        any::<MultiSelectMenu>().prop_map(SectionElement::MultiSelectMenu),
+        any::<OverflowMenu>().prop_map(SectionElement::OverflowMenu),
+    ];
+    (
+        text(3000),
+        of(string(255)),
+        of(vec(text(2000), 1..=10)),
+        of(accessory),
+    )
+        .prop_map(|(text, block_id, fields, accessory)| {
+            let mut section = SectionBuilder::new(text);
+            if let Some(block_id) = block_id {
+                section = section.set_block_id(block_id);
+            }
+            if let Some(fields) = fields {
+                section = section.set_fields(fields);
+            }
+            if let Some(accessory) = accessory {
+                section = section.set_accessory(accessory);
+            }
+            section.build()
+        })
+}
+
+fn input() -> impl Strategy<Value = Input> {
+    let element = prop_oneof![
+        plain_text_input().prop_map(InputElement::PlainTextInput),
+        any::<SelectMenu>().prop_map(InputElement::SelectMenu),
+        any::<MultiSelectMenu>().prop_map(InputElement::MultiSelectMenu),
+    ];
+    (
+        plain_text(2000),
+        element,
+        of(string(255)),
+        of(plain_text(2000)),
+        any::<Option<bool>>(),
+    )
+        .prop_map(|(label, element, block_id, hint, optional)| {
+            let mut input = InputBuilder::new(label, element);
+            if let Some(block_id) = block_id {
+                input = input.set_block_id(block_id);
+            }
+            if let Some(hint) = hint {
+                input = input.set_hint(hint);
+            }
+            if let Some(optional) = optional {
+                input = input.set_optional(optional);
+            }
+            input.build()
+        })
+}
+
+fn modal() -> impl Strategy<Value = Modal> {
+    let block = prop_oneof![
+        Just(ModalBlock::Divider(Divider::new())),
+        any::<Section>().prop_map(ModalBlock::Section),
+        any::<Input>().prop_map(ModalBlock::Input),
+    ];
+    (
+        plain_text(24),
+        vec(block, 0..=10),
+        of(plain_text(24)),
+        plain_text(24),
+        any::<bool>(),
+    )
+        .prop_map(|(title, blocks, close, submit, has_submit)| {
+            // Slack needs a submit button to send inputs anywhere.
+            let has_input = blocks.iter().any(|b| matches!(b, ModalBlock::Input(_)));
+            let mut modal = ModalBuilder::new(title, blocks);
+            if let Some(close) = close {
+                modal = modal.set_close(close);
+            }
+            if has_submit || has_input {
+                modal = modal.set_submit(submit);
+            }
+            modal.build()
+        })
+}
+
+fn home_tab() -> impl Strategy<Value = HomeTab> {
+    let block = prop_oneof![
+        Just(HomeTabBlock::Divider(Divider::new())),
+        any::<Section>().prop_map(HomeTabBlock::Section),
+    ];
+    vec(block, 0..=10).prop_map(HomeTab::new)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validation::Validate;
+
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use std::fmt::Debug;
+
+    fn round_trip<T>(value: &T) -> Result<(), TestCaseError>
+    where
+        T: Debug + DeserializeOwned + PartialEq + Serialize,
+    {
+        let json = serde_json::to_string(value).unwrap();
+        let parsed: T = serde_json::from_str(&json)
+            .map_err(|e| TestCaseError::fail(format!("{}: {}", e, json)))?;
+        prop_assert_eq!(&parsed, value);
+        Ok(())
+    }
+
+    fn check<T>(value: &T) -> Result<(), TestCaseError>
+    where
+        T: Debug + DeserializeOwned + PartialEq + Serialize + Validate,
+    {
+        value
+            .validate()
+            .map_err(|e| TestCaseError::fail(e.to_string()))?;
+        round_trip(value)
+    }
+
+    proptest! {
+        #[test]
+        fn text(text in any::<Text>()) {
+            prop_assert!(text.text().chars().count() <= TEXT_LENGTH);
+            round_trip(&text)?;
+        }
+
+        #[test]
+        fn plain_text(text in any::<PlainText>()) {
+            round_trip(&text)?;
+        }
+
+        #[test]
+        fn mrkdwn(text in any::<Mrkdwn>()) {
+            round_trip(&text)?;
+        }
+
+        #[test]
+        fn options(menu in any::<OptionInput<PlainText>>(), choice in any::<OptionInput<Text>>()) {
+            check(&menu)?;
+            check(&choice)?;
+        }
+
+        #[test]
+        fn button(button in any::<Button>()) {
+            check(&button)?;
+        }
+
+        #[test]
+        fn select_menu(menu in any::<SelectMenu>()) {
+            check(&menu)?;
+        }
+
+        #[test]
+        fn multi_select_menu(menu in any::<MultiSelectMenu>()) {
+            check(&menu)?;
+        }
+
+        #[test]
+        fn overflow_menu(menu in any::<OverflowMenu>()) {
+            check(&menu)?;
+        }
+
+        #[test]
+        fn section(section in any::<Section>()) {
+            check(&section)?;
+        }
+
+        #[test]
+        fn input(input in any::<Input>()) {
+            check(&input)?;
+        }
+    }
+
+    // Surfaces hold many blocks each, so fewer cases cover as much.
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn modal(modal in any::<Modal>()) {
+            check(&modal)?;
+        }
+
+        #[test]
+        fn home_tab(home_tab in any::<HomeTab>()) {
+            check(&home_tab)?;
+        }
+    }
+}
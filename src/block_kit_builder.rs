@@ -0,0 +1,190 @@
+// Reference: https://app.slack.com/block-kit-builder
+//
+// The Builder keeps what it shows in its URL, as percent-encoded JSON after
+// the `#`, e.g. `https://app.slack.com/block-kit-builder/#%7B%22blocks%22...`.
+// Messages are shown from `{"blocks": [...]}`, and modals and home tabs from
+// their view JSON.
+use crate::surfaces::{HomeTab, MessageBlock, Modal};
+
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::error::Error;
+use std::fmt;
+use url::Url;
+
+const BUILDER_URL: &str = "https://app.slack.com/block-kit-builder/";
+
+// What JavaScript's `encodeURIComponent` encodes, which the Builder uses.
+const FRAGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'!')
+    .remove(b'~')
+    .remove(b'*')
+    .remove(b'\'')
+    .remove(b'(')
+    .remove(b')');
+
+/// Something the Builder can show, i.e. a `Modal`, a `HomeTab` or a
+/// message's blocks.
+pub trait Surface: sealed::Sealed {}
+
+impl Surface for Modal {}
+impl Surface for HomeTab {}
+impl Surface for Vec<MessageBlock> {}
+
+/// A link that opens `surface` in the Block Kit Builder.
+pub fn to_url<S: Surface>(surface: &S) -> Url {
+    let json = surface.to_json();
+    let fragment = utf8_percent_encode(&json, FRAGMENT).to_string();
+    let mut url = Url::parse(BUILDER_URL).unwrap();
+    url.set_fragment(Some(&fragment));
+    url
+}
+
+/// Reads what a Block Kit Builder link shows.
+pub fn from_url<S: Surface>(url: &str) -> Result<S, BlockKitBuilderError> {
+    let fragment = match url.split_once('#') {
+        Some((_, fragment)) if !fragment.is_empty() => fragment,
+        _ => return Err(BlockKitBuilderError::MissingFragment),
+    };
+    let json = percent_decode_str(fragment)
+        .decode_utf8()
+        .map_err(|_| BlockKitBuilderError::Encoding)?;
+    from_json(&json)
+}
+
+/// Reads JSON copied from the Block Kit Builder's editor.
+pub fn from_json<S: Surface>(json: &str) -> Result<S, BlockKitBuilderError> {
+    let value =
+        serde_json::from_str(json).map_err(|e| BlockKitBuilderError::Json(e.to_string()))?;
+    S::from_json(value).map_err(|e| BlockKitBuilderError::Json(e.to_string()))
+}
+
+/// Why a Block Kit Builder link or its JSON couldn't be read.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BlockKitBuilderError {
+    /// The URL has nothing after its `#`, where the Builder keeps its JSON.
+    MissingFragment,
+    /// The JSON after the `#` isn't percent-encoded UTF-8.
+    Encoding,
+    /// The JSON isn't valid, or isn't the kind of surface expected.
+    Json(String),
+}
+
+impl fmt::Display for BlockKitBuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BlockKitBuilderError::MissingFragment => {
+                write!(f, "the URL has no blocks after its `#`")
+            }
+            BlockKitBuilderError::Encoding => write!(f, "the blocks in the URL aren't valid UTF-8"),
+            BlockKitBuilderError::Json(e) => write!(f, "invalid blocks: {}", e),
+        }
+    }
+}
+
+impl Error for BlockKitBuilderError {}
+
+// The Builder's JSON for a message, which only has its blocks.
+#[derive(Deserialize, Serialize)]
+struct Message<B> {
+    blocks: B,
+}
+
+mod sealed {
+    use super::*;
+
+    // Surfaces only have string keys and none of their fields can fail to
+    // serialize, so this never panics. A `Value` would be infallible too, but
+    // would sort the keys, putting each block's "type" after its fields.
+    fn to_string<T: Serialize>(value: &T) -> String {
+        serde_json::to_string(value).expect("surfaces always serialize")
+    }
+
+    pub trait Sealed: Sized {
+        fn to_json(&self) -> String;
+        fn from_json(value: Value) -> Result<Self, serde_json::Error>;
+    }
+
+    impl Sealed for Modal {
+        fn to_json(&self) -> String {
+            to_string(self)
+        }
+
+        fn from_json(value: Value) -> Result<Self, serde_json::Error> {
+            serde_json::from_value(value)
+        }
+    }
+
+    impl Sealed for HomeTab {
+        fn to_json(&self) -> String {
+            to_string(self)
+        }
+
+        fn from_json(value: Value) -> Result<Self, serde_json::Error> {
+            serde_json::from_value(value)
+        }
+    }
+
+    impl Sealed for Vec<MessageBlock> {
+        fn to_json(&self) -> String {
+            to_string(&Message { blocks: self })
+        }
+
+        fn from_json(value: Value) -> Result<Self, serde_json::Error> {
+            serde_json::from_value::<Message<Self>>(value).map(|e| e.blocks)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::blocks::{Divider, SectionBuilder};
+    use crate::objects::{Mrkdwn, PlainText};
+    use crate::surfaces::ModalBuilder;
+
+    #[test]
+    fn message_url() {
+        let blocks: Vec<MessageBlock> = vec![
+            SectionBuilder::new(Mrkdwn::new("*Hi* & bye").into())
+                .build()
+                .into(),
+            Divider::new().into(),
+        ];
+        let url = to_url(&blocks);
+        assert_eq!(
+            url.as_str(),
+            "https://app.slack.com/block-kit-builder/#%7B%22blocks%22%3A%5B%7B%22type%22%3A%22section%22%2C%22text%22%3A%7B%22type%22%3A%22mrkdwn%22%2C%22text%22%3A%22*Hi*%20%26%20bye%22%7D%7D%2C%7B%22type%22%3A%22divider%22%7D%5D%7D"
+        );
+        assert_eq!(from_url::<Vec<MessageBlock>>(url.as_str()), Ok(blocks));
+    }
+
+    #[test]
+    fn modal_url() {
+        let modal = ModalBuilder::new(PlainText::new("Settings"), vec![Divider::new().into()])
+            .set_submit(PlainText::new("Save"))
+            .build();
+        // As the Builder writes it, with a workspace and unencoded brackets.
+        let url = "https://app.slack.com/block-kit-builder/T123#{%22type%22:%22modal%22,%22title%22:{%22type%22:%22plain_text%22,%22text%22:%22Settings%22},%22submit%22:{%22type%22:%22plain_text%22,%22text%22:%22Save%22},%22blocks%22:[{%22type%22:%22divider%22}]}";
+        assert_eq!(from_url::<Modal>(url), Ok(modal.clone()));
+        assert_eq!(from_url::<Modal>(to_url(&modal).as_str()), Ok(modal));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            from_url::<HomeTab>("https://app.slack.com/block-kit-builder/T123"),
+            Err(BlockKitBuilderError::MissingFragment)
+        );
+        assert_eq!(
+            from_json::<HomeTab>(r#"{"type":"home","blocks":[{"type":"input"}]}"#),
+            Err(BlockKitBuilderError::Json(
+                "unknown home tab block type `input`".to_string()
+            ))
+        );
+    }
+}
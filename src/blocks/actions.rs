@@ -1,57 +1,78 @@
+use crate::de;
 use crate::elements::*;
+use crate::validation::{Validate, Validator};
 
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 
-impl Into<ActionsElement> for Button {
-    fn into(self) -> ActionsElement {
-        ActionsElement::Button(self)
+impl From<Button> for ActionsElement {
+    fn from(e: Button) -> Self {
+        ActionsElement::Button(e)
     }
 }
 
-impl Into<ActionsElement> for Checkboxes {
-    fn into(self) -> ActionsElement {
-        ActionsElement::Checkboxes(self)
+impl From<Checkboxes> for ActionsElement {
+    fn from(e: Checkboxes) -> Self {
+        ActionsElement::Checkboxes(e)
     }
 }
 
-impl Into<ActionsElement> for DatePicker {
-    fn into(self) -> ActionsElement {
-        ActionsElement::DatePicker(self)
+impl From<DatePicker> for ActionsElement {
+    fn from(e: DatePicker) -> Self {
+        ActionsElement::DatePicker(e)
     }
 }
 
-impl Into<ActionsElement> for OverflowMenu {
-    fn into(self) -> ActionsElement {
-        ActionsElement::OverflowMenu(self)
+impl From<DateTimePicker> for ActionsElement {
+    fn from(e: DateTimePicker) -> Self {
+        ActionsElement::DateTimePicker(e)
     }
 }
 
-impl Into<ActionsElement> for PlainTextInput {
-    fn into(self) -> ActionsElement {
-        ActionsElement::PlainTextInput(self)
+impl From<OverflowMenu> for ActionsElement {
+    fn from(e: OverflowMenu) -> Self {
+        ActionsElement::OverflowMenu(e)
     }
 }
 
-impl Into<ActionsElement> for RadioButtonGroup {
-    fn into(self) -> ActionsElement {
-        ActionsElement::RadioButtonGroup(self)
+impl From<PlainTextInput> for ActionsElement {
+    fn from(e: PlainTextInput) -> Self {
+        ActionsElement::PlainTextInput(e)
     }
 }
 
-impl Into<ActionsElement> for SelectMenu {
-    fn into(self) -> ActionsElement {
-        ActionsElement::SelectMenu(self)
+impl From<RadioButtonGroup> for ActionsElement {
+    fn from(e: RadioButtonGroup) -> Self {
+        ActionsElement::RadioButtonGroup(e)
     }
 }
 
+impl From<SelectMenu> for ActionsElement {
+    fn from(e: SelectMenu) -> Self {
+        ActionsElement::SelectMenu(e)
+    }
+}
+
+impl From<TimePicker> for ActionsElement {
+    fn from(e: TimePicker) -> Self {
+        ActionsElement::TimePicker(e)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema), schemars(untagged))]
 pub enum ActionsElement {
     Button(Button),
     Checkboxes(Checkboxes),
     DatePicker(DatePicker),
+    DateTimePicker(DateTimePicker),
     OverflowMenu(OverflowMenu),
     PlainTextInput(PlainTextInput),
     RadioButtonGroup(RadioButtonGroup),
     SelectMenu(SelectMenu),
+    TimePicker(TimePicker),
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    Unknown(Value),
 }
 
 impl Serialize for ActionsElement {
@@ -63,12 +84,54 @@ impl Serialize for ActionsElement {
             ActionsElement::Button(e) => e.serialize(serializer),
             ActionsElement::Checkboxes(e) => e.serialize(serializer),
             ActionsElement::DatePicker(e) => e.serialize(serializer),
+            ActionsElement::DateTimePicker(e) => e.serialize(serializer),
             ActionsElement::OverflowMenu(e) => e.serialize(serializer),
             ActionsElement::PlainTextInput(e) => e.serialize(serializer),
             ActionsElement::RadioButtonGroup(e) => e.serialize(serializer),
             ActionsElement::SelectMenu(e) => e.serialize(serializer),
+            ActionsElement::TimePicker(e) => e.serialize(serializer),
+            ActionsElement::Unknown(e) => e.serialize(serializer),
         }
     }
 }
 
+impl<'de> Deserialize<'de> for ActionsElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (kind, value) = de::tagged(deserializer)?;
+        match kind.as_str() {
+            "button" => de::from_value(value).map(ActionsElement::Button),
+            "checkboxes" => de::from_value(value).map(ActionsElement::Checkboxes),
+            "datepicker" => de::from_value(value).map(ActionsElement::DatePicker),
+            "datetimepicker" => de::from_value(value).map(ActionsElement::DateTimePicker),
+            "overflow" => de::from_value(value).map(ActionsElement::OverflowMenu),
+            "plain_text_input" => de::from_value(value).map(ActionsElement::PlainTextInput),
+            "radio_buttons" => de::from_value(value).map(ActionsElement::RadioButtonGroup),
+            "timepicker" => de::from_value(value).map(ActionsElement::TimePicker),
+            kind if SELECT_MENU_TYPES.contains(&kind) => {
+                de::from_value(value).map(ActionsElement::SelectMenu)
+            }
+            other => de::unknown(other, "actions element", is_element_type(other), value)
+                .map(ActionsElement::Unknown),
+        }
+    }
+}
 
+impl Validate for ActionsElement {
+    fn validate_into(&self, v: &mut Validator) {
+        match self {
+            ActionsElement::Button(e) => e.validate_into(v),
+            ActionsElement::Checkboxes(e) => e.validate_into(v),
+            ActionsElement::DatePicker(e) => e.validate_into(v),
+            ActionsElement::DateTimePicker(e) => e.validate_into(v),
+            ActionsElement::OverflowMenu(e) => e.validate_into(v),
+            ActionsElement::PlainTextInput(e) => e.validate_into(v),
+            ActionsElement::RadioButtonGroup(e) => e.validate_into(v),
+            ActionsElement::SelectMenu(e) => e.validate_into(v),
+            ActionsElement::TimePicker(e) => e.validate_into(v),
+            ActionsElement::Unknown(_) => {}
+        }
+    }
+}
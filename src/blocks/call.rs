@@ -0,0 +1,82 @@
+use crate::limits::MAX_BLOCK_ID_LENGTH;
+use crate::validation::{Validate, Validator};
+use crate::Error;
+
+use serde::{Deserialize, Serialize};
+
+// Calls can only be posted in messages, after registering them with calls.add.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "Call", transform = crate::schema::tagged("call"))
+)]
+#[serde(tag = "type", rename = "call")]
+pub struct Call {
+    call_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_BLOCK_ID_LENGTH)))]
+    block_id: Option<String>,
+}
+
+impl Call {
+    pub fn new<S: Into<String>>(call_id: S) -> Self {
+        CallBuilder::new(call_id).build()
+    }
+
+    pub fn builder<S: Into<String>>(call_id: S) -> CallBuilder {
+        CallBuilder::new(call_id)
+    }
+}
+
+pub struct CallBuilder {
+    call_id: String,
+    block_id: Option<String>,
+}
+
+impl CallBuilder {
+    pub fn new<S: Into<String>>(call_id: S) -> Self {
+        Self {
+            call_id: call_id.into(),
+            block_id: None,
+        }
+    }
+
+    pub fn set_block_id<S: Into<String>>(mut self, block_id: S) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    pub fn build(self) -> Call {
+        Call {
+            call_id: self.call_id,
+            block_id: self.block_id,
+        }
+    }
+
+    pub fn try_build(self) -> Result<Call, Error> {
+        let call = self.build();
+        call.validate()?;
+        Ok(call)
+    }
+}
+
+impl Validate for Call {
+    fn validate_into(&self, v: &mut Validator) {
+        v.optional_max_length("block_id", &self.block_id, MAX_BLOCK_ID_LENGTH);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn call() {
+        let call = Call::new("R014CKQGVRT");
+        let json = serde_json::to_string(&call).unwrap();
+        assert_eq!(json.as_str(), r#"{"type":"call","call_id":"R014CKQGVRT"}"#);
+        let value: Call = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, call);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::limits::MAX_BLOCK_ID_LENGTH;
+use crate::objects::PlainText;
+use crate::validation::{Validate, Validator};
+use crate::Error;
+
+use serde::{Deserialize, Serialize};
+
+pub(crate) const MAX_HEADER_LENGTH: usize = 150;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "Header", transform = crate::schema::tagged("header"))
+)]
+#[serde(tag = "type", rename = "header")]
+pub struct Header {
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_HEADER_LENGTH))
+    )]
+    pub(crate) text: PlainText,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_BLOCK_ID_LENGTH)))]
+    block_id: Option<String>,
+}
+
+impl Header {
+    pub fn new(text: PlainText) -> Self {
+        HeaderBuilder::new(text).build()
+    }
+
+    pub fn builder(text: PlainText) -> HeaderBuilder {
+        HeaderBuilder::new(text)
+    }
+}
+
+pub struct HeaderBuilder {
+    text: PlainText,
+    block_id: Option<String>,
+}
+
+impl HeaderBuilder {
+    pub fn new(text: PlainText) -> Self {
+        Self {
+            text,
+            block_id: None,
+        }
+    }
+
+    pub fn set_block_id<S: Into<String>>(mut self, block_id: S) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    pub fn build(self) -> Header {
+        Header {
+            text: self.text,
+            block_id: self.block_id,
+        }
+    }
+
+    pub fn try_build(self) -> Result<Header, Error> {
+        let header = self.build();
+        header.validate()?;
+        Ok(header)
+    }
+}
+
+impl Validate for Header {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_text_length("text", &self.text, MAX_HEADER_LENGTH);
+        v.optional_max_length("block_id", &self.block_id, MAX_BLOCK_ID_LENGTH);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn header() {
+        let header = Header::builder(PlainText::new("Budget Performance"))
+            .set_block_id("header1")
+            .build();
+        let json = serde_json::to_string(&header).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"header","text":{"type":"plain_text","text":"Budget Performance"},"block_id":"header1"}"#
+        );
+        let value: Header = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, header);
+    }
+
+    #[test]
+    fn validate_text_length() {
+        let header = Header::builder(PlainText::new("x".repeat(151)));
+        assert_eq!(
+            header.try_build().unwrap_err().to_string(),
+            "invalid payload: text: must be at most 150 characters long, but is 151"
+        );
+    }
+}
@@ -1,20 +1,40 @@
-use crate::objects::Text;
+use crate::limits::{MAX_ALT_TEXT_LENGTH, MAX_BLOCK_ID_LENGTH, MAX_URL_LENGTH};
+use crate::objects::PlainText;
+use crate::validation::{Validate, Validator};
+use crate::Error;
 
-use serde::ser::SerializeMap;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Serialize};
 use url::Url;
 
+const MAX_TITLE_LENGTH: usize = 2000;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "ImageBlock", transform = crate::schema::tagged("image"))
+)]
+#[serde(tag = "type", rename = "image")]
 pub struct Image {
-    image_url: Url,
-    alt_text: String,
-    title: Option<Text>,
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_URL_LENGTH)))]
+    pub(crate) image_url: Url,
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ALT_TEXT_LENGTH)))]
+    pub(crate) alt_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_TITLE_LENGTH))
+    )]
+    pub(crate) title: Option<PlainText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_BLOCK_ID_LENGTH)))]
     block_id: Option<String>,
 }
 
 pub struct ImageBuilder {
     image_url: Url,
     alt_text: String,
-    title: Option<Text>,
+    title: Option<PlainText>,
     block_id: Option<String>,
 }
 
@@ -28,13 +48,13 @@ impl ImageBuilder {
         }
     }
 
-    pub fn set_title(mut self, title: Text) -> Self {
+    pub fn set_title(mut self, title: PlainText) -> Self {
         self.title = Some(title);
         self
     }
 
-    pub fn set_block_id(mut self, block_id: String) -> Self {
-        self.block_id = Some(block_id);
+    pub fn set_block_id<S: Into<String>>(mut self, block_id: S) -> Self {
+        self.block_id = Some(block_id.into());
         self
     }
 
@@ -46,32 +66,61 @@ impl ImageBuilder {
             block_id: self.block_id,
         }
     }
-}
 
-impl Serialize for Image {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let mut size = 2;
-        if self.title.is_some() {
-            size += 1;
-        }
-        if self.block_id.is_some() {
-            size += 2;
-        }
+    pub fn try_build(self) -> Result<Image, Error> {
+        let image = self.build();
+        image.validate()?;
+        Ok(image)
+    }
+}
 
-        let mut map = serializer.serialize_map(Some(size))?;
-        map.serialize_entry("image_url", self.image_url.as_str())?;
-        map.serialize_entry("alt_text", &self.alt_text)?;
-        if let Some(t) = &self.title {
-            map.serialize_entry("title", &t)?;
-        }
-        if let Some(id) = &self.block_id {
-            map.serialize_entry("block_id", &id)?;
-        }
-        map.end()
+impl Validate for Image {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_length("image_url", self.image_url.as_str(), MAX_URL_LENGTH);
+        v.max_length("alt_text", &self.alt_text, MAX_ALT_TEXT_LENGTH);
+        v.optional_max_text_length("title", &self.title, MAX_TITLE_LENGTH);
+        v.optional_max_length("block_id", &self.block_id, MAX_BLOCK_ID_LENGTH);
     }
 }
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn basic() {
+        let image = ImageBuilder::new(
+            "http://placekitten.com/500/500".parse().unwrap(),
+            "An incredibly cute kitten.",
+        )
+        .build();
+        let json = serde_json::to_string(&image).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"image","image_url":"http://placekitten.com/500/500","alt_text":"An incredibly cute kitten."}"#
+        );
+    }
+
+    #[test]
+    fn all() {
+        let image = ImageBuilder::new(
+            "http://placekitten.com/500/500".parse().unwrap(),
+            "An incredibly cute kitten.",
+        )
+        .set_title(PlainText::new("Please enjoy this photo of a kitten"))
+        .set_block_id("image4".to_string())
+        .build();
+        let json = serde_json::to_string(&image).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"image","image_url":"http://placekitten.com/500/500","alt_text":"An incredibly cute kitten.","title":{"type":"plain_text","text":"Please enjoy this photo of a kitten"},"block_id":"image4"}"#
+        );
+    }
 
+    #[test]
+    fn round_trip() {
+        let json = r#"{"type":"image","image_url":"http://placekitten.com/500/500","alt_text":"An incredibly cute kitten.","title":{"type":"plain_text","text":"Please enjoy this photo of a kitten"},"block_id":"image4"}"#;
+        let value: Image = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap().as_str(), json);
+    }
+}
@@ -1,32 +1,53 @@
+use crate::de;
 use crate::elements::*;
-use crate::objects::Text;
+use crate::limits::MAX_BLOCK_ID_LENGTH;
+use crate::objects::PlainText;
+use crate::validation::{Validate, Validator};
+use crate::Error;
 
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 
-#[derive(Serialize)]
+const MAX_LABEL_LENGTH: usize = 2000;
+const MAX_HINT_LENGTH: usize = 2000;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "Input", transform = crate::schema::tagged("input"))
+)]
+#[serde(tag = "type", rename = "input")]
 pub struct Input {
-    label: Text,
-    element: InputElement,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_LABEL_LENGTH))
+    )]
+    pub(crate) label: PlainText,
+    pub(crate) element: InputElement,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_BLOCK_ID_LENGTH)))]
     block_id: Option<String>,
-    // This must be plain-text, is there any way to enforce this at
-    // compile-time?
     #[serde(skip_serializing_if = "Option::is_none")]
-    hint: Option<Text>,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_HINT_LENGTH))
+    )]
+    pub(crate) hint: Option<PlainText>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    optional: Option<bool>,
+    pub(crate) optional: Option<bool>,
 }
 
 pub struct InputBuilder {
-    label: Text,
+    label: PlainText,
     element: InputElement,
     block_id: Option<String>,
-    hint: Option<Text>,
+    hint: Option<PlainText>,
     optional: Option<bool>,
 }
 
 impl InputBuilder {
-    pub fn new(label: Text, element: InputElement) -> Self {
+    pub fn new(label: PlainText, element: InputElement) -> Self {
         Self {
             label,
             element,
@@ -36,12 +57,12 @@ impl InputBuilder {
         }
     }
 
-    pub fn set_block_id(mut self, block_id: String) -> Self {
-        self.block_id = Some(block_id);
+    pub fn set_block_id<S: Into<String>>(mut self, block_id: S) -> Self {
+        self.block_id = Some(block_id.into());
         self
     }
 
-    pub fn set_hint(mut self, hint: Text) -> Self {
+    pub fn set_hint(mut self, hint: PlainText) -> Self {
         self.hint = Some(hint);
         self
     }
@@ -60,51 +81,110 @@ impl InputBuilder {
             optional: self.optional,
         }
     }
+
+    pub fn try_build(self) -> Result<Input, Error> {
+        let input = self.build();
+        input.validate()?;
+        Ok(input)
+    }
+}
+
+impl From<Checkboxes> for InputElement {
+    fn from(e: Checkboxes) -> Self {
+        InputElement::Checkboxes(e)
+    }
+}
+
+impl From<DatePicker> for InputElement {
+    fn from(e: DatePicker) -> Self {
+        InputElement::DatePicker(e)
+    }
+}
+
+impl From<DateTimePicker> for InputElement {
+    fn from(e: DateTimePicker) -> Self {
+        InputElement::DateTimePicker(e)
+    }
+}
+
+impl From<EmailInput> for InputElement {
+    fn from(e: EmailInput) -> Self {
+        InputElement::EmailInput(e)
+    }
+}
+
+impl From<FileInput> for InputElement {
+    fn from(e: FileInput) -> Self {
+        InputElement::FileInput(e)
+    }
+}
+
+impl From<MultiSelectMenu> for InputElement {
+    fn from(e: MultiSelectMenu) -> Self {
+        InputElement::MultiSelectMenu(e)
+    }
+}
+
+impl From<NumberInput> for InputElement {
+    fn from(e: NumberInput) -> Self {
+        InputElement::NumberInput(e)
+    }
 }
 
-impl Into<InputElement> for Checkboxes {
-    fn into(self) -> InputElement {
-        InputElement::Checkboxes(self)
+impl From<PlainTextInput> for InputElement {
+    fn from(e: PlainTextInput) -> Self {
+        InputElement::PlainTextInput(e)
     }
 }
 
-impl Into<InputElement> for DatePicker {
-    fn into(self) -> InputElement {
-        InputElement::DatePicker(self)
+impl From<RadioButtonGroup> for InputElement {
+    fn from(e: RadioButtonGroup) -> Self {
+        InputElement::RadioButtonGroup(e)
     }
 }
 
-impl Into<InputElement> for MultiSelectMenu {
-    fn into(self) -> InputElement {
-        InputElement::MultiSelectMenu(self)
+impl From<RichTextInput> for InputElement {
+    fn from(e: RichTextInput) -> Self {
+        InputElement::RichTextInput(e)
     }
 }
 
-impl Into<InputElement> for PlainTextInput {
-    fn into(self) -> InputElement {
-        InputElement::PlainTextInput(self)
+impl From<SelectMenu> for InputElement {
+    fn from(e: SelectMenu) -> Self {
+        InputElement::SelectMenu(e)
     }
 }
 
-impl Into<InputElement> for RadioButtonGroup {
-    fn into(self) -> InputElement {
-        InputElement::RadioButtonGroup(self)
+impl From<TimePicker> for InputElement {
+    fn from(e: TimePicker) -> Self {
+        InputElement::TimePicker(e)
     }
 }
 
-impl Into<InputElement> for SelectMenu {
-    fn into(self) -> InputElement {
-        InputElement::SelectMenu(self)
+impl From<UrlInput> for InputElement {
+    fn from(e: UrlInput) -> Self {
+        InputElement::UrlInput(e)
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema), schemars(untagged))]
 pub enum InputElement {
     Checkboxes(Checkboxes),
     DatePicker(DatePicker),
+    DateTimePicker(DateTimePicker),
+    EmailInput(EmailInput),
+    FileInput(FileInput),
     MultiSelectMenu(MultiSelectMenu),
+    NumberInput(NumberInput),
     PlainTextInput(PlainTextInput),
     RadioButtonGroup(RadioButtonGroup),
+    RichTextInput(RichTextInput),
     SelectMenu(SelectMenu),
+    TimePicker(TimePicker),
+    UrlInput(UrlInput),
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    Unknown(Value),
 }
 
 impl Serialize for InputElement {
@@ -115,11 +195,143 @@ impl Serialize for InputElement {
         match self {
             InputElement::Checkboxes(e) => e.serialize(serializer),
             InputElement::DatePicker(e) => e.serialize(serializer),
+            InputElement::DateTimePicker(e) => e.serialize(serializer),
+            InputElement::EmailInput(e) => e.serialize(serializer),
+            InputElement::FileInput(e) => e.serialize(serializer),
             InputElement::MultiSelectMenu(e) => e.serialize(serializer),
+            InputElement::NumberInput(e) => e.serialize(serializer),
             InputElement::PlainTextInput(e) => e.serialize(serializer),
             InputElement::RadioButtonGroup(e) => e.serialize(serializer),
+            InputElement::RichTextInput(e) => e.serialize(serializer),
             InputElement::SelectMenu(e) => e.serialize(serializer),
+            InputElement::TimePicker(e) => e.serialize(serializer),
+            InputElement::UrlInput(e) => e.serialize(serializer),
+            InputElement::Unknown(e) => e.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for InputElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (kind, value) = de::tagged(deserializer)?;
+        match kind.as_str() {
+            "checkboxes" => de::from_value(value).map(InputElement::Checkboxes),
+            "datepicker" => de::from_value(value).map(InputElement::DatePicker),
+            "datetimepicker" => de::from_value(value).map(InputElement::DateTimePicker),
+            "email_text_input" => de::from_value(value).map(InputElement::EmailInput),
+            "file_input" => de::from_value(value).map(InputElement::FileInput),
+            kind if MULTI_SELECT_MENU_TYPES.contains(&kind) => {
+                de::from_value(value).map(InputElement::MultiSelectMenu)
+            }
+            "number_input" => de::from_value(value).map(InputElement::NumberInput),
+            "plain_text_input" => de::from_value(value).map(InputElement::PlainTextInput),
+            "radio_buttons" => de::from_value(value).map(InputElement::RadioButtonGroup),
+            "rich_text_input" => de::from_value(value).map(InputElement::RichTextInput),
+            "timepicker" => de::from_value(value).map(InputElement::TimePicker),
+            "url_text_input" => de::from_value(value).map(InputElement::UrlInput),
+            kind if SELECT_MENU_TYPES.contains(&kind) => {
+                de::from_value(value).map(InputElement::SelectMenu)
+            }
+            other => de::unknown(other, "input element", is_element_type(other), value)
+                .map(InputElement::Unknown),
         }
     }
 }
 
+impl Validate for Input {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_text_length("label", &self.label, MAX_LABEL_LENGTH);
+        v.field("element", &self.element);
+        v.optional_max_length("block_id", &self.block_id, MAX_BLOCK_ID_LENGTH);
+        v.optional_max_text_length("hint", &self.hint, MAX_HINT_LENGTH);
+    }
+}
+
+impl Validate for InputElement {
+    fn validate_into(&self, v: &mut Validator) {
+        match self {
+            InputElement::Checkboxes(e) => e.validate_into(v),
+            InputElement::DatePicker(e) => e.validate_into(v),
+            InputElement::DateTimePicker(e) => e.validate_into(v),
+            InputElement::EmailInput(e) => e.validate_into(v),
+            InputElement::FileInput(e) => e.validate_into(v),
+            InputElement::MultiSelectMenu(e) => e.validate_into(v),
+            InputElement::NumberInput(e) => e.validate_into(v),
+            InputElement::PlainTextInput(e) => e.validate_into(v),
+            InputElement::RadioButtonGroup(e) => e.validate_into(v),
+            InputElement::RichTextInput(e) => e.validate_into(v),
+            InputElement::SelectMenu(e) => e.validate_into(v),
+            InputElement::TimePicker(e) => e.validate_into(v),
+            InputElement::UrlInput(e) => e.validate_into(v),
+            InputElement::Unknown(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn basic() {
+        let input = InputBuilder::new(
+            PlainText::new("Label"),
+            PlainTextInputBuilder::new("plain_input").build().into(),
+        )
+        .build();
+        let json = serde_json::to_string(&input).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"input","label":{"type":"plain_text","text":"Label"},"element":{"type":"plain_text_input","action_id":"plain_input"}}"#
+        );
+    }
+
+    #[test]
+    fn all() {
+        let input = InputBuilder::new(
+            PlainText::new("Label"),
+            PlainTextInputBuilder::new("plain_input")
+                .set_placeholder(PlainText::new("Enter some plain text"))
+                .build()
+                .into(),
+        )
+        .set_block_id("input1".to_string())
+        .set_hint(PlainText::new("Hint"))
+        .set_optional(true)
+        .build();
+        let json = serde_json::to_string(&input).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"input","label":{"type":"plain_text","text":"Label"},"element":{"type":"plain_text_input","action_id":"plain_input","placeholder":{"type":"plain_text","text":"Enter some plain text"}},"block_id":"input1","hint":{"type":"plain_text","text":"Hint"},"optional":true}"#
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let json = r#"{"type":"input","label":{"type":"plain_text","text":"Label"},"element":{"type":"multi_users_select","action_id":"users","placeholder":{"type":"plain_text","text":"Select users"}},"block_id":"input1","optional":true}"#;
+        let value: Input = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn new_elements_round_trip() {
+        for element in &[
+            r#"{"type":"timepicker","action_id":"time","initial_time":"13:30"}"#,
+            r#"{"type":"datetimepicker","action_id":"when","initial_date_time":1628633820}"#,
+            r#"{"type":"number_input","is_decimal_allowed":false,"action_id":"count","min_value":"1"}"#,
+            r#"{"type":"email_text_input","action_id":"email"}"#,
+            r#"{"type":"url_text_input","action_id":"url"}"#,
+            r#"{"type":"file_input","action_id":"upload","max_files":1}"#,
+        ] {
+            let json = format!(
+                r#"{{"type":"input","label":{{"type":"plain_text","text":"Label"}},"element":{}}}"#,
+                element
+            );
+            let value: Input = serde_json::from_str(&json).unwrap();
+            assert_eq!(serde_json::to_string(&value).unwrap(), json);
+        }
+    }
+}
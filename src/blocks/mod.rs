@@ -1,20 +1,61 @@
+// Reference: https://api.slack.com/reference/block-kit/blocks
 mod actions;
+mod call;
+mod header;
 mod image;
 mod input;
+mod rich_text;
 mod section;
+mod video;
 
 pub use crate::blocks::actions::*;
+pub use crate::blocks::call::*;
+pub use crate::blocks::header::*;
 pub use crate::blocks::image::*;
 pub use crate::blocks::input::*;
+pub use crate::blocks::rich_text::*;
 pub use crate::blocks::section::*;
+pub use crate::blocks::video::*;
 
-use crate::objects::Text;
+use crate::de;
+use crate::elements;
+use crate::limits::MAX_BLOCK_ID_LENGTH;
+use crate::objects::{Mrkdwn, PlainText, Text};
+use crate::validation::{Validate, Validator};
 
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 
-#[derive(Serialize)]
+const MAX_ACTIONS_ELEMENTS: usize = 25;
+const MAX_CONTEXT_ELEMENTS: usize = 10;
+
+// Every block the crate models, whichever surfaces allow it.
+pub(crate) const BLOCK_TYPES: &[&str] = &[
+    "actions",
+    "call",
+    "context",
+    "divider",
+    "file",
+    "header",
+    "image",
+    "input",
+    "rich_text",
+    "section",
+    "video",
+];
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "Actions", transform = crate::schema::tagged("actions"))
+)]
+#[serde(tag = "type", rename = "actions")]
 pub struct Actions {
-    elements: Vec<ActionsElement>,
+    #[cfg_attr(feature = "schema", schemars(length(min = 1, max = MAX_ACTIONS_ELEMENTS)))]
+    pub(crate) elements: Vec<ActionsElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_BLOCK_ID_LENGTH)))]
     block_id: Option<String>,
 }
 
@@ -34,9 +75,18 @@ impl Actions {
     }
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "Context", transform = crate::schema::tagged("context"))
+)]
+#[serde(tag = "type", rename = "context")]
 pub struct Context {
+    #[cfg_attr(feature = "schema", schemars(length(min = 1, max = MAX_CONTEXT_ELEMENTS)))]
     pub elements: Vec<ContextElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_BLOCK_ID_LENGTH)))]
     pub block_id: Option<String>,
 }
 
@@ -56,8 +106,16 @@ impl Context {
     }
 }
 
-#[derive(Default, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "Divider", transform = crate::schema::tagged("divider"))
+)]
+#[serde(tag = "type", rename = "divider")]
 pub struct Divider {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_BLOCK_ID_LENGTH)))]
     pub block_id: Option<String>,
 }
 
@@ -73,9 +131,19 @@ impl Divider {
     }
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "File", transform = crate::schema::tagged("file"))
+)]
+#[serde(tag = "type", rename = "file")]
 pub struct File {
     external_id: String,
+    // Slack only supports remote files here, so this is always "remote".
+    source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_BLOCK_ID_LENGTH)))]
     block_id: Option<String>,
 }
 
@@ -83,6 +151,7 @@ impl File {
     pub fn new<S: Into<String>>(external_id: S) -> Self {
         File {
             external_id: external_id.into(),
+            source: "remote".to_string(),
             block_id: None,
         }
     }
@@ -90,25 +159,43 @@ impl File {
     pub fn new_with_id<S: Into<String>, T: Into<String>>(block_id: S, external_id: T) -> Self {
         Self {
             external_id: external_id.into(),
+            source: "remote".to_string(),
             block_id: Some(block_id.into()),
         }
     }
 }
-impl Into<ContextElement> for Image {
-    fn into(self) -> ContextElement {
-        ContextElement::Image(self)
+
+impl From<elements::Image> for ContextElement {
+    fn from(e: elements::Image) -> Self {
+        ContextElement::Image(e)
     }
 }
 
-impl Into<ContextElement> for Text {
-    fn into(self) -> ContextElement {
-        ContextElement::Text(self)
+impl From<Text> for ContextElement {
+    fn from(e: Text) -> Self {
+        ContextElement::Text(e)
     }
 }
 
+impl From<Mrkdwn> for ContextElement {
+    fn from(e: Mrkdwn) -> Self {
+        ContextElement::Text(e.into())
+    }
+}
+
+impl From<PlainText> for ContextElement {
+    fn from(e: PlainText) -> Self {
+        ContextElement::Text(e.into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema), schemars(untagged))]
 pub enum ContextElement {
-    Image(Image),
+    Image(elements::Image),
     Text(Text),
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    Unknown(Value),
 }
 
 impl Serialize for ContextElement {
@@ -119,7 +206,155 @@ impl Serialize for ContextElement {
         match self {
             ContextElement::Image(e) => e.serialize(serializer),
             ContextElement::Text(e) => e.serialize(serializer),
+            ContextElement::Unknown(e) => e.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ContextElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (kind, value) = de::tagged(deserializer)?;
+        match kind.as_str() {
+            "image" => de::from_value(value).map(ContextElement::Image),
+            "plain_text" | "mrkdwn" => de::from_value(value).map(ContextElement::Text),
+            other => de::unknown(
+                other,
+                "context element",
+                elements::is_element_type(other),
+                value,
+            )
+            .map(ContextElement::Unknown),
         }
     }
 }
 
+impl Validate for Actions {
+    fn validate_into(&self, v: &mut Validator) {
+        v.item_count("elements", self.elements.len(), 1, MAX_ACTIONS_ELEMENTS);
+        v.items("elements", &self.elements);
+        v.optional_max_length("block_id", &self.block_id, MAX_BLOCK_ID_LENGTH);
+    }
+}
+
+impl Validate for Context {
+    fn validate_into(&self, v: &mut Validator) {
+        v.item_count("elements", self.elements.len(), 1, MAX_CONTEXT_ELEMENTS);
+        v.items("elements", &self.elements);
+        v.optional_max_length("block_id", &self.block_id, MAX_BLOCK_ID_LENGTH);
+    }
+}
+
+impl Validate for Divider {
+    fn validate_into(&self, v: &mut Validator) {
+        v.optional_max_length("block_id", &self.block_id, MAX_BLOCK_ID_LENGTH);
+    }
+}
+
+impl Validate for File {
+    fn validate_into(&self, v: &mut Validator) {
+        v.optional_max_length("block_id", &self.block_id, MAX_BLOCK_ID_LENGTH);
+    }
+}
+
+impl Validate for ContextElement {
+    fn validate_into(&self, v: &mut Validator) {
+        match self {
+            ContextElement::Image(e) => e.validate_into(v),
+            ContextElement::Text(_) => {}
+            ContextElement::Unknown(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::elements::ButtonBuilder;
+    use crate::objects::{FormattingType, PlainText};
+    use crate::validation::Validate;
+
+    #[test]
+    fn actions() {
+        let button = ButtonBuilder::new("button", PlainText::new("Click")).build();
+        let actions = Actions::new_with_id("actions1", vec![button.into()]);
+        let json = serde_json::to_string(&actions).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"actions","elements":[{"type":"button","text":{"type":"plain_text","text":"Click"},"action_id":"button"}],"block_id":"actions1"}"#
+        );
+    }
+
+    #[test]
+    fn context() {
+        let image = elements::Image::new(
+            "https://image.freepik.com/free-photo/red-drawing-pin_1156-445.jpg"
+                .parse()
+                .unwrap(),
+            "images".to_string(),
+        );
+        let text = Text::builder(FormattingType::Markdown, "Location: **Dogpatch**").build();
+        let context = Context::new(vec![image.into(), text.into()]);
+        let json = serde_json::to_string(&context).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"context","elements":[{"type":"image","image_url":"https://image.freepik.com/free-photo/red-drawing-pin_1156-445.jpg","alt_text":"images"},{"type":"mrkdwn","text":"Location: **Dogpatch**"}]}"#
+        );
+    }
+
+    #[test]
+    fn divider() {
+        let json = serde_json::to_string(&Divider::new()).unwrap();
+        assert_eq!(json.as_str(), r#"{"type":"divider"}"#);
+    }
+
+    #[test]
+    fn file() {
+        let file = File::new("ABCD1");
+        let json = serde_json::to_string(&file).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"file","external_id":"ABCD1","source":"remote"}"#
+        );
+    }
+
+    #[test]
+    fn actions_round_trip() {
+        let json = r#"{"type":"actions","elements":[{"type":"button","text":{"type":"plain_text","text":"Click"},"action_id":"button"},{"type":"datepicker","action_id":"date","initial_date":"1990-04-28"}],"block_id":"actions1"}"#;
+        let value: Actions = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn context_round_trip() {
+        let json = r#"{"type":"context","elements":[{"type":"image","image_url":"https://image.freepik.com/free-photo/red-drawing-pin_1156-445.jpg","alt_text":"images"},{"type":"mrkdwn","text":"Location: **Dogpatch**"}]}"#;
+        let value: Context = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn file_round_trip() {
+        let json = r#"{"type":"file","external_id":"ABCD1","source":"remote","block_id":"file1"}"#;
+        let value: File = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn context_element_unknown_type() {
+        let err = serde_json::from_str::<ContextElement>(r#"{"type":"button","action_id":"b"}"#)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "unknown context element type `button`");
+    }
+
+    #[test]
+    fn validate_actions_count() {
+        let button = ButtonBuilder::new("button", PlainText::new("Click")).build();
+        let actions = Actions::new((0..26).map(|_| button.clone().into()).collect());
+        assert_eq!(
+            actions.validate().unwrap_err().to_string(),
+            "elements: must have at most 25 items, but has 26"
+        );
+    }
+}
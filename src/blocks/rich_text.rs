@@ -0,0 +1,117 @@
+use crate::limits::MAX_BLOCK_ID_LENGTH;
+use crate::objects::rich_text::RichTextElement;
+use crate::validation::{Validate, Validator};
+use crate::Error;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "RichText", transform = crate::schema::tagged("rich_text"))
+)]
+#[serde(tag = "type", rename = "rich_text")]
+pub struct RichText {
+    pub elements: Vec<RichTextElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_BLOCK_ID_LENGTH)))]
+    pub block_id: Option<String>,
+}
+
+impl RichText {
+    pub fn new(elements: Vec<RichTextElement>) -> Self {
+        RichTextBuilder::new(elements).build()
+    }
+
+    pub fn builder(elements: Vec<RichTextElement>) -> RichTextBuilder {
+        RichTextBuilder::new(elements)
+    }
+}
+
+pub struct RichTextBuilder {
+    elements: Vec<RichTextElement>,
+    block_id: Option<String>,
+}
+
+impl RichTextBuilder {
+    pub fn new(elements: Vec<RichTextElement>) -> Self {
+        Self {
+            elements,
+            block_id: None,
+        }
+    }
+
+    pub fn set_block_id<S: Into<String>>(mut self, block_id: S) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    pub fn build(self) -> RichText {
+        RichText {
+            elements: self.elements,
+            block_id: self.block_id,
+        }
+    }
+
+    pub fn try_build(self) -> Result<RichText, Error> {
+        let rich_text = self.build();
+        rich_text.validate()?;
+        Ok(rich_text)
+    }
+}
+
+impl Validate for RichText {
+    fn validate_into(&self, v: &mut Validator) {
+        v.items("elements", &self.elements);
+        v.optional_max_length("block_id", &self.block_id, MAX_BLOCK_ID_LENGTH);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::objects::rich_text::*;
+
+    #[test]
+    fn rich_text() {
+        let rich_text = RichText::builder(vec![
+            RichTextSection::new(vec![
+                "Hello there, ".into(),
+                RichTextInline::text("I am a bold rich text block!").bold(),
+            ])
+            .into(),
+            RichTextList::builder(ListStyle::Bullet)
+                .add_item(vec!["item".into()])
+                .build()
+                .into(),
+            RichTextPreformatted::new(vec!["cargo test".into()]).into(),
+        ])
+        .set_block_id("rich1")
+        .build();
+        let json = serde_json::to_string(&rich_text).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"rich_text","elements":[{"type":"rich_text_section","elements":[{"type":"text","text":"Hello there, "},{"type":"text","text":"I am a bold rich text block!","style":{"bold":true}}]},{"type":"rich_text_list","style":"bullet","elements":[{"type":"rich_text_section","elements":[{"type":"text","text":"item"}]}]},{"type":"rich_text_preformatted","elements":[{"type":"text","text":"cargo test"}]}],"block_id":"rich1"}"#
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let json = r#"{"type":"rich_text","elements":[{"type":"rich_text_quote","elements":[{"type":"user","user_id":"U12345"},{"type":"text","text":" said "},{"type":"emoji","name":"wave","unicode":"1f44b"},{"type":"broadcast","range":"here"},{"type":"link","url":"https://slack.com","text":"Slack"},{"type":"date","timestamp":1628633089,"format":"{date_short}"}],"border":1}]}"#;
+        let value: RichText = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn validate_nested_list() {
+        let rich_text = RichText::new(vec![
+            RichTextSection::new(vec!["Steps:".into()]).into(),
+            RichTextList::new(ListStyle::Ordered, vec![]).into(),
+        ]);
+        assert_eq!(
+            rich_text.validate().unwrap_err().to_string(),
+            "elements[1].elements: must have at least 1 items, but has 0"
+        );
+    }
+}
@@ -1,21 +1,50 @@
+use crate::de;
 use crate::elements::*;
+use crate::limits::MAX_BLOCK_ID_LENGTH;
 use crate::objects::Text;
+use crate::validation::{Validate, Validator};
+use crate::Error;
 
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 
-#[derive(Serialize)]
+pub(crate) const MAX_SECTION_TEXT_LENGTH: usize = 3000;
+pub(crate) const MAX_SECTION_FIELDS: usize = 10;
+pub(crate) const MAX_FIELD_LENGTH: usize = 2000;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "Section", transform = crate::schema::tagged("section"))
+)]
+#[serde(tag = "type", rename = "section")]
 pub struct Section {
-    text: Text,
+    // A section needs text, fields, or both.
     #[serde(skip_serializing_if = "Option::is_none")]
-    block_id: Option<String>,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_SECTION_TEXT_LENGTH))
+    )]
+    pub(crate) text: Option<Text>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    fields: Option<Vec<Text>>,
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_BLOCK_ID_LENGTH)))]
+    pub(crate) block_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    accessory: Option<SectionElement>,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(
+            length(max = MAX_SECTION_FIELDS),
+            transform = crate::schema::max_text_length(MAX_FIELD_LENGTH)
+        )
+    )]
+    pub(crate) fields: Option<Vec<Text>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) accessory: Option<SectionElement>,
 }
 
 pub struct SectionBuilder {
-    text: Text,
+    text: Option<Text>,
     block_id: Option<String>,
     fields: Option<Vec<Text>>,
     accessory: Option<SectionElement>,
@@ -24,15 +53,30 @@ pub struct SectionBuilder {
 impl SectionBuilder {
     pub fn new(text: Text) -> Self {
         Self {
-            text,
+            text: Some(text),
             block_id: None,
             fields: None,
             accessory: None,
         }
     }
 
-    pub fn set_block_id(mut self, block_id: String) -> Self {
-        self.block_id = Some(block_id);
+    /// A section of only fields, shown in two columns.
+    pub fn new_with_fields(fields: Vec<Text>) -> Self {
+        Self {
+            text: None,
+            block_id: None,
+            fields: Some(fields),
+            accessory: None,
+        }
+    }
+
+    pub fn set_text(mut self, text: Text) -> Self {
+        self.text = Some(text);
+        self
+    }
+
+    pub fn set_block_id<S: Into<String>>(mut self, block_id: S) -> Self {
+        self.block_id = Some(block_id.into());
         self
     }
 
@@ -54,62 +98,76 @@ impl SectionBuilder {
             accessory: self.accessory,
         }
     }
+
+    pub fn try_build(self) -> Result<Section, Error> {
+        let section = self.build();
+        section.validate()?;
+        Ok(section)
+    }
 }
 
-impl Into<SectionElement> for Button {
-    fn into(self) -> SectionElement {
-        SectionElement::Button(self)
+impl From<Button> for SectionElement {
+    fn from(e: Button) -> Self {
+        SectionElement::Button(e)
     }
 }
 
-impl Into<SectionElement> for Checkboxes {
-    fn into(self) -> SectionElement {
-        SectionElement::Checkboxes(self)
+impl From<Checkboxes> for SectionElement {
+    fn from(e: Checkboxes) -> Self {
+        SectionElement::Checkboxes(e)
     }
 }
 
-impl Into<SectionElement> for DatePicker {
-    fn into(self) -> SectionElement {
-        SectionElement::DatePicker(self)
+impl From<DatePicker> for SectionElement {
+    fn from(e: DatePicker) -> Self {
+        SectionElement::DatePicker(e)
     }
 }
 
-impl Into<SectionElement> for Image {
-    fn into(self) -> SectionElement {
-        SectionElement::Image(self)
+impl From<Image> for SectionElement {
+    fn from(e: Image) -> Self {
+        SectionElement::Image(e)
     }
 }
 
-impl Into<SectionElement> for MultiSelectMenu {
-    fn into(self) -> SectionElement {
-        SectionElement::MultiSelectMenu(self)
+impl From<MultiSelectMenu> for SectionElement {
+    fn from(e: MultiSelectMenu) -> Self {
+        SectionElement::MultiSelectMenu(e)
     }
 }
 
-impl Into<SectionElement> for OverflowMenu {
-    fn into(self) -> SectionElement {
-        SectionElement::OverflowMenu(self)
+impl From<OverflowMenu> for SectionElement {
+    fn from(e: OverflowMenu) -> Self {
+        SectionElement::OverflowMenu(e)
     }
 }
 
-impl Into<SectionElement> for PlainTextInput {
-    fn into(self) -> SectionElement {
-        SectionElement::PlainTextInput(self)
+impl From<PlainTextInput> for SectionElement {
+    fn from(e: PlainTextInput) -> Self {
+        SectionElement::PlainTextInput(e)
     }
 }
 
-impl Into<SectionElement> for RadioButtonGroup {
-    fn into(self) -> SectionElement {
-        SectionElement::RadioButtonGroup(self)
+impl From<RadioButtonGroup> for SectionElement {
+    fn from(e: RadioButtonGroup) -> Self {
+        SectionElement::RadioButtonGroup(e)
     }
 }
 
-impl Into<SectionElement> for SelectMenu {
-    fn into(self) -> SectionElement {
-        SectionElement::SelectMenu(self)
+impl From<SelectMenu> for SectionElement {
+    fn from(e: SelectMenu) -> Self {
+        SectionElement::SelectMenu(e)
     }
 }
 
+impl From<TimePicker> for SectionElement {
+    fn from(e: TimePicker) -> Self {
+        SectionElement::TimePicker(e)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema), schemars(untagged))]
 pub enum SectionElement {
     Button(Button),
     Checkboxes(Checkboxes),
@@ -120,6 +178,9 @@ pub enum SectionElement {
     PlainTextInput(PlainTextInput),
     RadioButtonGroup(RadioButtonGroup),
     SelectMenu(SelectMenu),
+    TimePicker(TimePicker),
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    Unknown(Value),
 }
 
 impl Serialize for SectionElement {
@@ -137,8 +198,170 @@ impl Serialize for SectionElement {
             SectionElement::PlainTextInput(e) => e.serialize(serializer),
             SectionElement::RadioButtonGroup(e) => e.serialize(serializer),
             SectionElement::SelectMenu(e) => e.serialize(serializer),
+            SectionElement::TimePicker(e) => e.serialize(serializer),
+            SectionElement::Unknown(e) => e.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SectionElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (kind, value) = de::tagged(deserializer)?;
+        match kind.as_str() {
+            "button" => de::from_value(value).map(SectionElement::Button),
+            "checkboxes" => de::from_value(value).map(SectionElement::Checkboxes),
+            "datepicker" => de::from_value(value).map(SectionElement::DatePicker),
+            "image" => de::from_value(value).map(SectionElement::Image),
+            kind if MULTI_SELECT_MENU_TYPES.contains(&kind) => {
+                de::from_value(value).map(SectionElement::MultiSelectMenu)
+            }
+            "overflow" => de::from_value(value).map(SectionElement::OverflowMenu),
+            "plain_text_input" => de::from_value(value).map(SectionElement::PlainTextInput),
+            "radio_buttons" => de::from_value(value).map(SectionElement::RadioButtonGroup),
+            "timepicker" => de::from_value(value).map(SectionElement::TimePicker),
+            kind if SELECT_MENU_TYPES.contains(&kind) => {
+                de::from_value(value).map(SectionElement::SelectMenu)
+            }
+            other => de::unknown(other, "section element", is_element_type(other), value)
+                .map(SectionElement::Unknown),
+        }
+    }
+}
+
+impl Validate for Section {
+    fn validate_into(&self, v: &mut Validator) {
+        let has_fields = matches!(&self.fields, Some(fields) if !fields.is_empty());
+        v.required_without("text", self.text.is_some(), "fields", has_fields);
+        v.optional_max_text_length("text", &self.text, MAX_SECTION_TEXT_LENGTH);
+        v.optional_max_length("block_id", &self.block_id, MAX_BLOCK_ID_LENGTH);
+        if let Some(fields) = &self.fields {
+            v.item_count("fields", fields.len(), 0, MAX_SECTION_FIELDS);
+            v.max_text_lengths("fields", fields, MAX_FIELD_LENGTH);
         }
+        v.optional_field("accessory", &self.accessory);
     }
 }
 
+impl Validate for SectionElement {
+    fn validate_into(&self, v: &mut Validator) {
+        match self {
+            SectionElement::Button(e) => e.validate_into(v),
+            SectionElement::Checkboxes(e) => e.validate_into(v),
+            SectionElement::DatePicker(e) => e.validate_into(v),
+            SectionElement::Image(e) => e.validate_into(v),
+            SectionElement::MultiSelectMenu(e) => e.validate_into(v),
+            SectionElement::OverflowMenu(e) => e.validate_into(v),
+            SectionElement::PlainTextInput(e) => e.validate_into(v),
+            SectionElement::RadioButtonGroup(e) => e.validate_into(v),
+            SectionElement::SelectMenu(e) => e.validate_into(v),
+            SectionElement::TimePicker(e) => e.validate_into(v),
+            SectionElement::Unknown(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::objects::{FormattingType, PlainText};
+    use crate::validation::Validate;
 
+    #[test]
+    fn basic() {
+        let section = SectionBuilder::new(
+            Text::builder(
+                FormattingType::Markdown,
+                "A message *with some bold text* and _some italicized text_.",
+            )
+            .build(),
+        )
+        .build();
+        let json = serde_json::to_string(&section).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"section","text":{"type":"mrkdwn","text":"A message *with some bold text* and _some italicized text_."}}"#
+        );
+    }
+
+    #[test]
+    fn fields_and_accessory() {
+        let button = ButtonBuilder::new("button", PlainText::new("Click Me"))
+            .set_value("click_me_123".to_string())
+            .build();
+        let section =
+            SectionBuilder::new(Text::builder(FormattingType::Markdown, "A message").build())
+                .set_block_id("section567".to_string())
+                .set_fields(vec![
+                    Text::builder(FormattingType::Markdown, "*Priority*").build(),
+                    PlainText::new("High").into(),
+                ])
+                .set_accessory(button.into())
+                .build();
+        let json = serde_json::to_string(&section).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"section","text":{"type":"mrkdwn","text":"A message"},"block_id":"section567","fields":[{"type":"mrkdwn","text":"*Priority*"},{"type":"plain_text","text":"High"}],"accessory":{"type":"button","text":{"type":"plain_text","text":"Click Me"},"action_id":"button","value":"click_me_123"}}"#
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let json = r#"{"type":"section","text":{"type":"mrkdwn","text":"Pick a channel"},"accessory":{"type":"channels_select","initial_channel":"C12345","action_id":"channel","placeholder":{"type":"plain_text","text":"Select a channel"}}}"#;
+        let value: Section = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn fields_only() {
+        let json = r#"{"type":"section","fields":[{"type":"mrkdwn","text":"*Type:*\nPaid time off"},{"type":"plain_text","text":"Mar 10"}]}"#;
+        let section: Section = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            section,
+            SectionBuilder::new_with_fields(vec![
+                Text::builder(FormattingType::Markdown, "*Type:*\nPaid time off").build(),
+                PlainText::new("Mar 10").into(),
+            ])
+            .build()
+        );
+        assert_eq!(serde_json::to_string(&section).unwrap().as_str(), json);
+        assert!(section.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_text_or_fields() {
+        let section = SectionBuilder::new_with_fields(vec![]).build();
+        assert_eq!(
+            section.validate().unwrap_err().to_string(),
+            "text: is required without fields"
+        );
+    }
+
+    #[test]
+    fn validate_limits() {
+        let field: Text = PlainText::new("field").into();
+        let mut fields = vec![field; 11];
+        fields[4] = PlainText::new("x".repeat(2001)).into();
+        let section =
+            SectionBuilder::new(Text::builder(FormattingType::Markdown, "x".repeat(3001)).build())
+                .set_fields(fields)
+                .build();
+        let errors: Vec<String> = section
+            .validate()
+            .unwrap_err()
+            .errors()
+            .iter()
+            .map(|e| e.to_string())
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "text: must be at most 3000 characters long, but is 3001",
+                "fields: must have at most 10 items, but has 11",
+                "fields[4]: must be at most 2000 characters long, but is 2001",
+            ]
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use crate::limits::MAX_BLOCK_ID_LENGTH;
+use crate::objects::PlainText;
+use crate::validation::{Validate, Validator};
+use crate::Error;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+const MAX_TITLE_LENGTH: usize = 200;
+const MAX_AUTHOR_NAME_LENGTH: usize = 50;
+const MAX_DESCRIPTION_LENGTH: usize = 200;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "Video", transform = crate::schema::tagged("video"))
+)]
+#[serde(tag = "type", rename = "video")]
+pub struct Video {
+    pub(crate) alt_text: String,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_TITLE_LENGTH))
+    )]
+    pub(crate) title: PlainText,
+    pub(crate) thumbnail_url: Url,
+    pub(crate) video_url: Url,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title_url: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_AUTHOR_NAME_LENGTH)))]
+    author_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_DESCRIPTION_LENGTH))
+    )]
+    pub(crate) description: Option<PlainText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    provider_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    provider_icon_url: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_BLOCK_ID_LENGTH)))]
+    block_id: Option<String>,
+}
+
+impl Video {
+    pub fn builder<S: Into<String>>(
+        alt_text: S,
+        title: PlainText,
+        thumbnail_url: Url,
+        video_url: Url,
+    ) -> VideoBuilder {
+        VideoBuilder::new(alt_text, title, thumbnail_url, video_url)
+    }
+}
+
+pub struct VideoBuilder {
+    alt_text: String,
+    title: PlainText,
+    thumbnail_url: Url,
+    video_url: Url,
+    title_url: Option<Url>,
+    author_name: Option<String>,
+    description: Option<PlainText>,
+    provider_name: Option<String>,
+    provider_icon_url: Option<Url>,
+    block_id: Option<String>,
+}
+
+impl VideoBuilder {
+    pub fn new<S: Into<String>>(
+        alt_text: S,
+        title: PlainText,
+        thumbnail_url: Url,
+        video_url: Url,
+    ) -> Self {
+        Self {
+            alt_text: alt_text.into(),
+            title,
+            thumbnail_url,
+            video_url,
+            title_url: None,
+            author_name: None,
+            description: None,
+            provider_name: None,
+            provider_icon_url: None,
+            block_id: None,
+        }
+    }
+
+    pub fn set_title_url(mut self, url: Url) -> Self {
+        self.title_url = Some(url);
+        self
+    }
+
+    pub fn set_author_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.author_name = Some(name.into());
+        self
+    }
+
+    pub fn set_description(mut self, description: PlainText) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn set_provider_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.provider_name = Some(name.into());
+        self
+    }
+
+    pub fn set_provider_icon_url(mut self, url: Url) -> Self {
+        self.provider_icon_url = Some(url);
+        self
+    }
+
+    pub fn set_block_id<S: Into<String>>(mut self, block_id: S) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    pub fn build(self) -> Video {
+        Video {
+            alt_text: self.alt_text,
+            title: self.title,
+            thumbnail_url: self.thumbnail_url,
+            video_url: self.video_url,
+            title_url: self.title_url,
+            author_name: self.author_name,
+            description: self.description,
+            provider_name: self.provider_name,
+            provider_icon_url: self.provider_icon_url,
+            block_id: self.block_id,
+        }
+    }
+
+    pub fn try_build(self) -> Result<Video, Error> {
+        let video = self.build();
+        video.validate()?;
+        Ok(video)
+    }
+}
+
+impl Validate for Video {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_text_length("title", &self.title, MAX_TITLE_LENGTH);
+        v.optional_max_length("author_name", &self.author_name, MAX_AUTHOR_NAME_LENGTH);
+        v.optional_max_text_length("description", &self.description, MAX_DESCRIPTION_LENGTH);
+        v.optional_max_length("block_id", &self.block_id, MAX_BLOCK_ID_LENGTH);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn video() {
+        let video = Video::builder(
+            "How to use Slack?",
+            PlainText::new("How to use Slack."),
+            "https://i.ytimg.com/vi/RRxQQxiM7AA/hqdefault.jpg"
+                .parse()
+                .unwrap(),
+            "https://www.youtube.com/embed/RRxQQxiM7AA?feature=oembed&autoplay=1"
+                .parse()
+                .unwrap(),
+        )
+        .set_title_url(
+            "https://www.youtube.com/watch?v=RRxQQxiM7AA"
+                .parse()
+                .unwrap(),
+        )
+        .set_author_name("Arcado Buendia")
+        .set_provider_name("YouTube")
+        .build();
+        let json = serde_json::to_string(&video).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"video","alt_text":"How to use Slack?","title":{"type":"plain_text","text":"How to use Slack."},"thumbnail_url":"https://i.ytimg.com/vi/RRxQQxiM7AA/hqdefault.jpg","video_url":"https://www.youtube.com/embed/RRxQQxiM7AA?feature=oembed&autoplay=1","title_url":"https://www.youtube.com/watch?v=RRxQQxiM7AA","author_name":"Arcado Buendia","provider_name":"YouTube"}"#
+        );
+        let value: Video = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, video);
+    }
+}
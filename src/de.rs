@@ -0,0 +1,73 @@
+// Helpers for decoding the enums that wrap blocks and elements. Slack
+// identifies each of these by its "type" field, but the wrapped structs
+// already emit that tag themselves, so the enums can't use serde's
+// internally-tagged representation directly.
+//
+// Slack keeps adding block and element types, and a payload that contains
+// one still has to parse, so the enums keep a type they don't know as JSON in
+// an `Unknown` variant instead of rejecting it.
+use serde::de::DeserializeOwned;
+pub(crate) use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+/// Buffers a tagged object, returning its "type" along with the full value.
+pub(crate) fn tagged<'de, D>(deserializer: D) -> Result<(String, Value), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Value::deserialize(deserializer)?;
+    let kind = match value.get("type") {
+        Some(Value::String(kind)) => kind.clone(),
+        Some(_) => return Err(D::Error::custom("\"type\" must be a string")),
+        None => return Err(D::Error::missing_field("type")),
+    };
+    Ok((kind, value))
+}
+
+/// Buffers a tagged object, returning `Ok` if its "type" is one of `known`, or
+/// `Err` with the value to keep as-is if not.
+pub(crate) fn known<'de, D>(
+    deserializer: D,
+    known: &[&str],
+) -> Result<Result<Value, Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let (kind, value) = tagged(deserializer)?;
+    if known.contains(&kind.as_str()) {
+        Ok(Ok(value))
+    } else {
+        Ok(Err(value))
+    }
+}
+
+/// Decodes a value buffered by `tagged` into the variant it names.
+pub(crate) fn from_value<T, E>(value: Value) -> Result<T, E>
+where
+    T: DeserializeOwned,
+    E: Error,
+{
+    T::deserialize(value).map_err(E::custom)
+}
+
+/// The fallback for a "type" the enum being decoded has no variant for: an
+/// error if it's one the crate models but that isn't allowed here, e.g. an
+/// input block in a home tab, or the value to keep as `Unknown` if it's new.
+pub(crate) fn unknown<E: Error>(
+    kind: &str,
+    context: &str,
+    modelled: bool,
+    value: Value,
+) -> Result<Value, E> {
+    if modelled {
+        Err(unknown_type(kind, context))
+    } else {
+        Ok(value)
+    }
+}
+
+/// The error for a "type" that isn't valid in the enum being decoded.
+pub(crate) fn unknown_type<E: Error>(kind: &str, context: &str) -> E {
+    E::custom(format_args!("unknown {} type `{}`", context, kind))
+}
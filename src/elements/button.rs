@@ -1,41 +1,52 @@
-use crate::objects::{ConfirmationDialog, Text};
+use crate::limits::{MAX_ACTION_ID_LENGTH, MAX_URL_LENGTH};
+use crate::objects::{ConfirmationDialog, PlainText};
+use crate::validation::{Validate, Validator};
+use crate::Error;
 
-pub use chrono::{Date, DateTime, Utc};
-
-use serde::ser::SerializeMap;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Serialize};
 use url::Url;
 
+const MAX_TEXT_LENGTH: usize = 75;
+const MAX_VALUE_LENGTH: usize = 2000;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
 pub enum ButtonStyle {
     Danger,
     Default,
     Primary,
 }
 
-impl Serialize for ButtonStyle {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_str(match self {
-            ButtonStyle::Danger => "danger",
-            ButtonStyle::Default => "default",
-            ButtonStyle::Primary => "primary",
-        })
-    }
-}
-
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "Button", transform = crate::schema::tagged("button"))
+)]
+#[serde(tag = "type", rename = "button")]
 pub struct Button {
-    text: Text,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_TEXT_LENGTH))
+    )]
+    pub(crate) text: PlainText,
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ACTION_ID_LENGTH)))]
     action_id: String,
-    url: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_URL_LENGTH)))]
+    pub(crate) url: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_VALUE_LENGTH)))]
     value: Option<String>,
-    style: Option<ButtonStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) style: Option<ButtonStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     confirm: Option<ConfirmationDialog>,
 }
 
 pub struct ButtonBuilder {
-    text: Text,
+    text: PlainText,
     action_id: String,
     url: Option<Url>,
     value: Option<String>,
@@ -44,7 +55,7 @@ pub struct ButtonBuilder {
 }
 
 impl ButtonBuilder {
-    pub fn new<S: Into<String>>(action_id: S, text: Text) -> Self {
+    pub fn new<S: Into<String>>(action_id: S, text: PlainText) -> Self {
         Self {
             action_id: action_id.into(),
             text,
@@ -60,8 +71,8 @@ impl ButtonBuilder {
         self
     }
 
-    pub fn set_value(mut self, value: String) -> Self {
-        self.value = Some(value);
+    pub fn set_value<S: Into<String>>(mut self, value: S) -> Self {
+        self.value = Some(value.into());
         self
     }
 
@@ -85,47 +96,80 @@ impl ButtonBuilder {
             confirm: self.confirm,
         }
     }
+
+    pub fn try_build(self) -> Result<Button, Error> {
+        let button = self.build();
+        button.validate()?;
+        Ok(button)
+    }
 }
 
-impl Serialize for Button {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let mut size = 2;
-        if self.url.is_some() {
-            size += 1;
-        }
+impl Validate for Button {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_text_length("text", &self.text, MAX_TEXT_LENGTH);
+        v.max_length("action_id", &self.action_id, MAX_ACTION_ID_LENGTH);
+        v.optional_max_length("url", &self.url, MAX_URL_LENGTH);
+        v.optional_max_length("value", &self.value, MAX_VALUE_LENGTH);
+        v.optional_field("confirm", &self.confirm);
+    }
+}
 
-        if self.value.is_some() {
-            size += 1;
-        }
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::objects::{FormattingType, Text};
+
+    #[test]
+    fn basic() {
+        let button = ButtonBuilder::new("button", PlainText::new("Click Me"))
+            .set_value("click_me_123".to_string())
+            .build();
+        let json = serde_json::to_string(&button).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"button","text":{"type":"plain_text","text":"Click Me"},"action_id":"button","value":"click_me_123"}"#
+        );
+    }
 
-        if self.style.is_some() {
-            size += 1;
-        }
+    #[test]
+    fn all() {
+        let button = ButtonBuilder::new("button", PlainText::new("Link Button"))
+            .set_url("https://api.slack.com/block-kit".parse().unwrap())
+            .set_style(ButtonStyle::Primary)
+            .set_confirm(ConfirmationDialog::new(
+                "Are you sure?",
+                Text::builder(
+                    FormattingType::Markdown,
+                    "Wouldn't you prefer a good game of _chess_?",
+                )
+                .build(),
+                "Do it",
+                "Stop, I've changed my mind!",
+            ))
+            .build();
+        let json = serde_json::to_string(&button).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"button","text":{"type":"plain_text","text":"Link Button"},"action_id":"button","url":"https://api.slack.com/block-kit","style":"primary","confirm":{"title":{"type":"plain_text","text":"Are you sure?"},"text":{"type":"mrkdwn","text":"Wouldn't you prefer a good game of _chess_?"},"confirm":{"type":"plain_text","text":"Do it"},"deny":{"type":"plain_text","text":"Stop, I've changed my mind!"}}}"#
+        );
+    }
 
-        if self.confirm.is_some() {
-            size += 1;
-        }
-        let mut map = serializer.serialize_map(Some(size))?;
+    #[test]
+    fn round_trip() {
+        let json = r#"{"type":"button","text":{"type":"plain_text","text":"Link Button"},"action_id":"button","url":"https://api.slack.com/block-kit","style":"danger","confirm":{"title":{"type":"plain_text","text":"Are you sure?"},"text":{"type":"mrkdwn","text":"Really?"},"confirm":{"type":"plain_text","text":"Do it"},"deny":{"type":"plain_text","text":"Stop"}}}"#;
+        let value: Button = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap().as_str(), json);
+    }
 
-        map.serialize_entry("action_id", &self.action_id)?;
-        map.serialize_entry("text", &self.text)?;
-        if let Some(u) = &self.url {
-            map.serialize_entry("url", u.as_str())?;
-        }
-        if let Some(v) = &self.value {
-            map.serialize_entry("value", &v)?;
-        }
-        if let Some(s) = &self.style {
-            map.serialize_entry("style", &s)?;
-        }
-        if let Some(c) = &self.confirm {
-            map.serialize_entry("confirm", &c)?;
-        }
-        map.end()
+    #[test]
+    fn try_build_value_too_long() {
+        let err = ButtonBuilder::new("button", PlainText::new("Click Me"))
+            .set_value("x".repeat(2001))
+            .try_build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid payload: value: must be at most 2000 characters long, but is 2001"
+        );
     }
 }
-
-
@@ -1,15 +1,32 @@
-use crate::objects::{ConfirmationDialog, Text, FormattingType};
+use crate::limits::{MAX_ACTION_ID_LENGTH, MAX_PLACEHOLDER_LENGTH};
+use crate::objects::{ConfirmationDialog, PlainText};
+use crate::validation::{Validate, Validator};
+use crate::Error;
 
-use serde::ser::SerializeMap;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Serialize};
 
 use chrono::NaiveDate;
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "DatePicker", transform = crate::schema::tagged("datepicker"))
+)]
+#[serde(tag = "type", rename = "datepicker")]
 pub struct DatePicker {
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ACTION_ID_LENGTH)))]
     pub action_id: String,
-    pub placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_PLACEHOLDER_LENGTH))
+    )]
+    pub placeholder: Option<PlainText>,
     // TODO: Should we allow timezones here?
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub initial_date: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub confirm: Option<ConfirmationDialog>,
 }
 
@@ -26,7 +43,7 @@ impl DatePicker {
 
 pub struct DatePickerBuilder {
     action_id: String,
-    placeholder: Option<Text>,
+    placeholder: Option<PlainText>,
     initial_date: Option<NaiveDate>,
     confirm: Option<ConfirmationDialog>,
 }
@@ -41,8 +58,8 @@ impl DatePickerBuilder {
         }
     }
 
-    pub fn set_placeholder<S: Into<String>>(mut self, ph: S) -> Self {
-        self.placeholder = Some(Text::builder(FormattingType::PlainText, ph).build());
+    pub fn set_placeholder<P: Into<PlainText>>(mut self, ph: P) -> Self {
+        self.placeholder = Some(ph.into());
         self
     }
 
@@ -64,41 +81,19 @@ impl DatePickerBuilder {
             confirm: self.confirm,
         }
     }
-}
-
-impl Serialize for DatePicker {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let mut size = 1;
-        if self.placeholder.is_some() {
-            size += 1;
-        }
-
-        if self.initial_date.is_some() {
-            size += 1;
-        }
 
-        if self.confirm.is_some() {
-            size += 1;
-        }
-
-        let mut map = serializer.serialize_map(Some(size))?;
-        map.serialize_entry("type", "datepicker")?;
-        map.serialize_entry("action_id", &self.action_id)?;
-        if let Some(p) = &self.placeholder {
-            map.serialize_entry("placeholder", &p)?;
-        }
+    pub fn try_build(self) -> Result<DatePicker, Error> {
+        let date_picker = self.build();
+        date_picker.validate()?;
+        Ok(date_picker)
+    }
+}
 
-        if let Some(d) = &self.initial_date {
-            let date_str = d.format("%Y-%m-%d").to_string();
-            map.serialize_entry("initial_date", &date_str)?;
-        }
-        if let Some(c) = &self.confirm {
-            map.serialize_entry("confirm", &c)?;
-        }
-        map.end()
+impl Validate for DatePicker {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_length("action_id", &self.action_id, MAX_ACTION_ID_LENGTH);
+        v.optional_max_text_length("placeholder", &self.placeholder, MAX_PLACEHOLDER_LENGTH);
+        v.optional_field("confirm", &self.confirm);
     }
 }
 
@@ -118,7 +113,7 @@ mod test {
 
     #[test]
     fn full() {
-        let date = NaiveDate::from_ymd(2020, 01, 01);
+        let date = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
         let picker = DatePicker::builder("action_id")
             .set_initial_date(date)
             .set_placeholder("placeholder")
@@ -129,4 +124,15 @@ mod test {
             r#"{"type":"datepicker","action_id":"action_id","placeholder":{"type":"plain_text","text":"placeholder"},"initial_date":"2020-01-01"}"#
         );
     }
+
+    #[test]
+    fn round_trip() {
+        let json = r#"{"type":"datepicker","action_id":"action_id","placeholder":{"type":"plain_text","text":"placeholder"},"initial_date":"2020-01-01"}"#;
+        let picker: DatePicker = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            picker.initial_date,
+            Some(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+        );
+        assert_eq!(serde_json::to_string(&picker).unwrap().as_str(), json);
+    }
 }
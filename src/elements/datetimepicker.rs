@@ -0,0 +1,102 @@
+use crate::limits::MAX_ACTION_ID_LENGTH;
+use crate::objects::ConfirmationDialog;
+use crate::validation::{Validate, Validator};
+use crate::Error;
+
+use serde::{Deserialize, Serialize};
+
+use chrono::{DateTime, Utc};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "DateTimePicker", transform = crate::schema::tagged("datetimepicker"))
+)]
+#[serde(tag = "type", rename = "datetimepicker")]
+pub struct DateTimePicker {
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ACTION_ID_LENGTH)))]
+    pub action_id: String,
+    // Slack takes this as a UNIX timestamp, in seconds.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "chrono::serde::ts_seconds_option"
+    )]
+    #[cfg_attr(feature = "schema", schemars(with = "Option<i64>"))]
+    pub initial_date_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl DateTimePicker {
+    pub fn builder<S: Into<String>>(action_id: S) -> DateTimePickerBuilder {
+        DateTimePickerBuilder::new(action_id)
+    }
+}
+
+pub struct DateTimePickerBuilder {
+    action_id: String,
+    initial_date_time: Option<DateTime<Utc>>,
+    confirm: Option<ConfirmationDialog>,
+}
+
+impl DateTimePickerBuilder {
+    pub fn new<S: Into<String>>(action_id: S) -> Self {
+        Self {
+            action_id: action_id.into(),
+            initial_date_time: None,
+            confirm: None,
+        }
+    }
+
+    pub fn set_initial_date_time(mut self, init_date_time: DateTime<Utc>) -> Self {
+        self.initial_date_time = Some(init_date_time);
+        self
+    }
+
+    pub fn set_confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    pub fn build(self) -> DateTimePicker {
+        DateTimePicker {
+            action_id: self.action_id,
+            initial_date_time: self.initial_date_time,
+            confirm: self.confirm,
+        }
+    }
+
+    pub fn try_build(self) -> Result<DateTimePicker, Error> {
+        let date_time_picker = self.build();
+        date_time_picker.validate()?;
+        Ok(date_time_picker)
+    }
+}
+
+impl Validate for DateTimePicker {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_length("action_id", &self.action_id, MAX_ACTION_ID_LENGTH);
+        v.optional_field("confirm", &self.confirm);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn full() {
+        let picker = DateTimePicker::builder("action_id")
+            .set_initial_date_time(DateTime::from_timestamp(1628633820, 0).unwrap())
+            .build();
+        let json = serde_json::to_string(&picker).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"datetimepicker","action_id":"action_id","initial_date_time":1628633820}"#
+        );
+        let value: DateTimePicker = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, picker);
+    }
+}
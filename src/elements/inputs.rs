@@ -0,0 +1,500 @@
+use crate::blocks::RichText;
+use crate::limits::{MAX_ACTION_ID_LENGTH, MAX_PLACEHOLDER_LENGTH};
+use crate::objects::PlainText;
+use crate::validation::{Validate, Validator};
+use crate::Error;
+
+use serde::{Deserialize, Serialize};
+
+const MAX_FILES: u32 = 10;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "NumberInput", transform = crate::schema::tagged("number_input"))
+)]
+#[serde(tag = "type", rename = "number_input")]
+pub struct NumberInput {
+    pub(crate) is_decimal_allowed: bool,
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ACTION_ID_LENGTH)))]
+    action_id: String,
+    // Slack takes all of these as strings, so decimals keep their precision.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_PLACEHOLDER_LENGTH))
+    )]
+    pub(crate) placeholder: Option<PlainText>,
+}
+
+impl NumberInput {
+    /// An input that only accepts whole numbers.
+    pub fn integer<S: Into<String>>(action_id: S) -> NumberInputBuilder {
+        NumberInputBuilder::new(action_id, false)
+    }
+
+    /// An input that accepts decimals as well as whole numbers.
+    pub fn decimal<S: Into<String>>(action_id: S) -> NumberInputBuilder {
+        NumberInputBuilder::new(action_id, true)
+    }
+}
+
+pub struct NumberInputBuilder {
+    is_decimal_allowed: bool,
+    action_id: String,
+    initial_value: Option<String>,
+    min_value: Option<String>,
+    max_value: Option<String>,
+    placeholder: Option<PlainText>,
+}
+
+impl NumberInputBuilder {
+    pub fn new<S: Into<String>>(action_id: S, is_decimal_allowed: bool) -> Self {
+        Self {
+            is_decimal_allowed,
+            action_id: action_id.into(),
+            initial_value: None,
+            min_value: None,
+            max_value: None,
+            placeholder: None,
+        }
+    }
+
+    pub fn set_initial_value<N: ToString>(mut self, value: N) -> Self {
+        self.initial_value = Some(value.to_string());
+        self
+    }
+
+    pub fn set_min_value<N: ToString>(mut self, min: N) -> Self {
+        self.min_value = Some(min.to_string());
+        self
+    }
+
+    pub fn set_max_value<N: ToString>(mut self, max: N) -> Self {
+        self.max_value = Some(max.to_string());
+        self
+    }
+
+    pub fn set_placeholder(mut self, ph: PlainText) -> Self {
+        self.placeholder = Some(ph);
+        self
+    }
+
+    pub fn build(self) -> NumberInput {
+        NumberInput {
+            is_decimal_allowed: self.is_decimal_allowed,
+            action_id: self.action_id,
+            initial_value: self.initial_value,
+            min_value: self.min_value,
+            max_value: self.max_value,
+            placeholder: self.placeholder,
+        }
+    }
+
+    pub fn try_build(self) -> Result<NumberInput, Error> {
+        let number_input = self.build();
+        number_input.validate()?;
+        Ok(number_input)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "EmailInput", transform = crate::schema::tagged("email_text_input"))
+)]
+#[serde(tag = "type", rename = "email_text_input")]
+pub struct EmailInput {
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ACTION_ID_LENGTH)))]
+    action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_PLACEHOLDER_LENGTH))
+    )]
+    pub(crate) placeholder: Option<PlainText>,
+}
+
+impl EmailInput {
+    pub fn builder<S: Into<String>>(action_id: S) -> EmailInputBuilder {
+        EmailInputBuilder::new(action_id)
+    }
+}
+
+pub struct EmailInputBuilder {
+    action_id: String,
+    initial_value: Option<String>,
+    placeholder: Option<PlainText>,
+}
+
+impl EmailInputBuilder {
+    pub fn new<S: Into<String>>(action_id: S) -> Self {
+        Self {
+            action_id: action_id.into(),
+            initial_value: None,
+            placeholder: None,
+        }
+    }
+
+    pub fn set_initial_value<S: Into<String>>(mut self, value: S) -> Self {
+        self.initial_value = Some(value.into());
+        self
+    }
+
+    pub fn set_placeholder(mut self, ph: PlainText) -> Self {
+        self.placeholder = Some(ph);
+        self
+    }
+
+    pub fn build(self) -> EmailInput {
+        EmailInput {
+            action_id: self.action_id,
+            initial_value: self.initial_value,
+            placeholder: self.placeholder,
+        }
+    }
+
+    pub fn try_build(self) -> Result<EmailInput, Error> {
+        let email_input = self.build();
+        email_input.validate()?;
+        Ok(email_input)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "UrlInput", transform = crate::schema::tagged("url_text_input"))
+)]
+#[serde(tag = "type", rename = "url_text_input")]
+pub struct UrlInput {
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ACTION_ID_LENGTH)))]
+    action_id: String,
+    // Not a `Url`, since it can be a partial address for the user to finish.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_PLACEHOLDER_LENGTH))
+    )]
+    pub(crate) placeholder: Option<PlainText>,
+}
+
+impl UrlInput {
+    pub fn builder<S: Into<String>>(action_id: S) -> UrlInputBuilder {
+        UrlInputBuilder::new(action_id)
+    }
+}
+
+pub struct UrlInputBuilder {
+    action_id: String,
+    initial_value: Option<String>,
+    placeholder: Option<PlainText>,
+}
+
+impl UrlInputBuilder {
+    pub fn new<S: Into<String>>(action_id: S) -> Self {
+        Self {
+            action_id: action_id.into(),
+            initial_value: None,
+            placeholder: None,
+        }
+    }
+
+    pub fn set_initial_value<S: Into<String>>(mut self, value: S) -> Self {
+        self.initial_value = Some(value.into());
+        self
+    }
+
+    pub fn set_placeholder(mut self, ph: PlainText) -> Self {
+        self.placeholder = Some(ph);
+        self
+    }
+
+    pub fn build(self) -> UrlInput {
+        UrlInput {
+            action_id: self.action_id,
+            initial_value: self.initial_value,
+            placeholder: self.placeholder,
+        }
+    }
+
+    pub fn try_build(self) -> Result<UrlInput, Error> {
+        let url_input = self.build();
+        url_input.validate()?;
+        Ok(url_input)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "FileInput", transform = crate::schema::tagged("file_input"))
+)]
+#[serde(tag = "type", rename = "file_input")]
+pub struct FileInput {
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ACTION_ID_LENGTH)))]
+    action_id: String,
+    // File extensions, e.g. "pdf". Any type is accepted when this is empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) filetypes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(range(min = 1, max = MAX_FILES)))]
+    pub(crate) max_files: Option<u32>,
+}
+
+impl FileInput {
+    pub fn builder<S: Into<String>>(action_id: S) -> FileInputBuilder {
+        FileInputBuilder::new(action_id)
+    }
+}
+
+pub struct FileInputBuilder {
+    action_id: String,
+    filetypes: Vec<String>,
+    max_files: Option<u32>,
+}
+
+impl FileInputBuilder {
+    pub fn new<S: Into<String>>(action_id: S) -> Self {
+        Self {
+            action_id: action_id.into(),
+            filetypes: Vec::new(),
+            max_files: None,
+        }
+    }
+
+    pub fn set_filetypes<S: Into<String>, I: IntoIterator<Item = S>>(mut self, types: I) -> Self {
+        self.filetypes = types.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn set_max_files(mut self, max: u32) -> Self {
+        self.max_files = Some(max);
+        self
+    }
+
+    pub fn build(self) -> FileInput {
+        FileInput {
+            action_id: self.action_id,
+            filetypes: self.filetypes,
+            max_files: self.max_files,
+        }
+    }
+
+    pub fn try_build(self) -> Result<FileInput, Error> {
+        let file_input = self.build();
+        file_input.validate()?;
+        Ok(file_input)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "RichTextInput", transform = crate::schema::tagged("rich_text_input"))
+)]
+#[serde(tag = "type", rename = "rich_text_input")]
+pub struct RichTextInput {
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ACTION_ID_LENGTH)))]
+    action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial_value: Option<RichText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_PLACEHOLDER_LENGTH))
+    )]
+    pub(crate) placeholder: Option<PlainText>,
+}
+
+impl RichTextInput {
+    pub fn builder<S: Into<String>>(action_id: S) -> RichTextInputBuilder {
+        RichTextInputBuilder::new(action_id)
+    }
+}
+
+pub struct RichTextInputBuilder {
+    action_id: String,
+    initial_value: Option<RichText>,
+    placeholder: Option<PlainText>,
+}
+
+impl RichTextInputBuilder {
+    pub fn new<S: Into<String>>(action_id: S) -> Self {
+        Self {
+            action_id: action_id.into(),
+            initial_value: None,
+            placeholder: None,
+        }
+    }
+
+    pub fn set_initial_value(mut self, value: RichText) -> Self {
+        self.initial_value = Some(value);
+        self
+    }
+
+    pub fn set_placeholder(mut self, ph: PlainText) -> Self {
+        self.placeholder = Some(ph);
+        self
+    }
+
+    pub fn build(self) -> RichTextInput {
+        RichTextInput {
+            action_id: self.action_id,
+            initial_value: self.initial_value,
+            placeholder: self.placeholder,
+        }
+    }
+
+    pub fn try_build(self) -> Result<RichTextInput, Error> {
+        let rich_text_input = self.build();
+        rich_text_input.validate()?;
+        Ok(rich_text_input)
+    }
+}
+
+impl Validate for NumberInput {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_length("action_id", &self.action_id, MAX_ACTION_ID_LENGTH);
+        let decimal = self.is_decimal_allowed;
+        let initial = v.number("initial_value", &self.initial_value, decimal);
+        let min = v.number("min_value", &self.min_value, decimal);
+        let max = v.number("max_value", &self.max_value, decimal);
+        v.not_greater_than("min_value", min, "max_value", max);
+        v.not_greater_than("min_value", min, "initial_value", initial);
+        v.not_greater_than("initial_value", initial, "max_value", max);
+        v.optional_max_text_length("placeholder", &self.placeholder, MAX_PLACEHOLDER_LENGTH);
+    }
+}
+
+impl Validate for EmailInput {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_length("action_id", &self.action_id, MAX_ACTION_ID_LENGTH);
+        v.optional_max_text_length("placeholder", &self.placeholder, MAX_PLACEHOLDER_LENGTH);
+    }
+}
+
+impl Validate for UrlInput {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_length("action_id", &self.action_id, MAX_ACTION_ID_LENGTH);
+        v.optional_max_text_length("placeholder", &self.placeholder, MAX_PLACEHOLDER_LENGTH);
+    }
+}
+
+impl Validate for RichTextInput {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_length("action_id", &self.action_id, MAX_ACTION_ID_LENGTH);
+        v.optional_field("initial_value", &self.initial_value);
+        v.optional_max_text_length("placeholder", &self.placeholder, MAX_PLACEHOLDER_LENGTH);
+    }
+}
+
+impl Validate for FileInput {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_length("action_id", &self.action_id, MAX_ACTION_ID_LENGTH);
+        v.range("max_files", self.max_files, 1, MAX_FILES);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn number_input() {
+        let input = NumberInput::decimal("amount")
+            .set_min_value(0)
+            .set_max_value(99.5)
+            .set_initial_value(10.25)
+            .build();
+        let json = serde_json::to_string(&input).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"number_input","is_decimal_allowed":true,"action_id":"amount","initial_value":"10.25","min_value":"0","max_value":"99.5"}"#
+        );
+        let value: NumberInput = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, input);
+    }
+
+    #[test]
+    fn validate_number_input() {
+        let err = NumberInput::integer("count")
+            .set_min_value(10)
+            .set_max_value(5)
+            .set_initial_value(2.5)
+            .try_build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid payload: initial_value: must be an integer; min_value: must not be greater than max_value"
+        );
+    }
+
+    #[test]
+    fn text_inputs() {
+        let email = EmailInput::builder("email")
+            .set_placeholder(PlainText::new("you@example.com"))
+            .build();
+        assert_eq!(
+            serde_json::to_string(&email).unwrap().as_str(),
+            r#"{"type":"email_text_input","action_id":"email","placeholder":{"type":"plain_text","text":"you@example.com"}}"#
+        );
+        let url = UrlInput::builder("url")
+            .set_initial_value("https://")
+            .build();
+        assert_eq!(
+            serde_json::to_string(&url).unwrap().as_str(),
+            r#"{"type":"url_text_input","action_id":"url","initial_value":"https://"}"#
+        );
+    }
+
+    #[test]
+    fn file_input() {
+        let input = FileInput::builder("upload")
+            .set_filetypes(vec!["pdf", "png"])
+            .set_max_files(11)
+            .build();
+        assert_eq!(
+            serde_json::to_string(&input).unwrap().as_str(),
+            r#"{"type":"file_input","action_id":"upload","filetypes":["pdf","png"],"max_files":11}"#
+        );
+        assert_eq!(
+            input.validate().unwrap_err().to_string(),
+            "max_files: must be between 1 and 10, but is 11"
+        );
+    }
+
+    #[test]
+    fn rich_text_input() {
+        use crate::objects::rich_text::{RichTextInline, RichTextSection};
+
+        let input = RichTextInput::builder("notes")
+            .set_initial_value(RichText::new(vec![RichTextSection::new(vec![
+                RichTextInline::text("Draft").italic(),
+            ])
+            .into()]))
+            .build();
+        let json = serde_json::to_string(&input).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"rich_text_input","action_id":"notes","initial_value":{"type":"rich_text","elements":[{"type":"rich_text_section","elements":[{"type":"text","text":"Draft","style":{"italic":true}}]}]}}"#
+        );
+        let value: RichTextInput = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, input);
+    }
+}
@@ -2,26 +2,54 @@ mod multi_select;
 mod overflow;
 mod select;
 
-pub use overflow::{OverflowMenu, OverflowMenuBuilder};
 pub use multi_select::*;
+pub use overflow::{OverflowMenu, OverflowMenuBuilder};
 pub use select::*;
 
-use crate::objects::{OptionInput, OptionInputGroup};
-use serde::{Serialize, Serializer};
+use crate::limits::MAX_MENU_OPTIONS;
+use crate::objects::{OptionInput, OptionInputGroup, PlainText};
+use crate::validation::{Validate, Validator};
+use serde::{Deserialize, Serialize};
 
+// Static menus take either "options" or "option_groups", so this is
+// flattened into the menu and the variant picks the key.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum OptionNestingType {
-    Flat(Vec<OptionInput>),
-    Groups(Vec<OptionInputGroup>),
+    #[serde(rename = "options")]
+    Flat(
+        #[cfg_attr(feature = "schema", schemars(length(min = 1, max = MAX_MENU_OPTIONS)))]
+        Vec<OptionInput<PlainText>>,
+    ),
+    #[serde(rename = "option_groups")]
+    Groups(
+        #[cfg_attr(feature = "schema", schemars(length(min = 1, max = MAX_MENU_OPTIONS)))]
+        Vec<OptionInputGroup>,
+    ),
+}
+
+impl OptionNestingType {
+    pub fn contains(&self, option: &OptionInput<PlainText>) -> bool {
+        match self {
+            OptionNestingType::Flat(options) => options.contains(option),
+            OptionNestingType::Groups(groups) => {
+                groups.iter().any(|g| g.options().contains(option))
+            }
+        }
+    }
 }
 
-impl Serialize for OptionNestingType {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
+impl Validate for OptionNestingType {
+    fn validate_into(&self, v: &mut Validator) {
         match self {
-            OptionNestingType::Flat(e) => e.serialize(serializer),
-            OptionNestingType::Groups(e) => e.serialize(serializer),
+            OptionNestingType::Flat(options) => {
+                v.item_count("options", options.len(), 1, MAX_MENU_OPTIONS);
+                v.items("options", options);
+            }
+            OptionNestingType::Groups(groups) => {
+                v.item_count("option_groups", groups.len(), 1, MAX_MENU_OPTIONS);
+                v.items("option_groups", groups);
+            }
         }
     }
 }
@@ -1,95 +1,177 @@
 use crate::elements::menus::OptionNestingType;
-use crate::objects::{OptionInput, Text};
+use crate::limits::{MAX_ACTION_ID_LENGTH, MAX_PLACEHOLDER_LENGTH};
+use crate::objects::{ConfirmationDialog, OptionInput, PlainText};
+use crate::validation::{Validate, Validator};
+use crate::Error;
 
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct MultiSelectMenu {
+    // The menu type determines the "type" tag and the fields specific to
+    // it, so it's flattened in first.
+    #[serde(flatten)]
+    menu_type: MultiSelectMenuType,
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ACTION_ID_LENGTH)))]
     action_id: String,
-    placeholder: Text,
-    options: MultiSelectMenuType,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_PLACEHOLDER_LENGTH))
+    )]
+    pub(crate) placeholder: PlainText,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_selected_items: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confirm: Option<ConfirmationDialog>,
 }
 
 impl MultiSelectMenu {
-    pub fn new<S: Into<String>>(action_id: S, placeholder: Text, options: MultiSelectMenuType) -> Self {
+    pub fn new<S: Into<String>>(
+        action_id: S,
+        placeholder: PlainText,
+        menu_type: MultiSelectMenuType,
+    ) -> Self {
+        MultiSelectMenuBuilder::new(action_id, placeholder, menu_type).build()
+    }
+
+    pub fn builder<S: Into<String>>(
+        action_id: S,
+        placeholder: PlainText,
+        menu_type: MultiSelectMenuType,
+    ) -> MultiSelectMenuBuilder {
+        MultiSelectMenuBuilder::new(action_id, placeholder, menu_type)
+    }
+}
+
+pub struct MultiSelectMenuBuilder {
+    menu_type: MultiSelectMenuType,
+    action_id: String,
+    placeholder: PlainText,
+    max_selected_items: Option<u32>,
+    confirm: Option<ConfirmationDialog>,
+}
+
+impl MultiSelectMenuBuilder {
+    pub fn new<S: Into<String>>(
+        action_id: S,
+        placeholder: PlainText,
+        menu_type: MultiSelectMenuType,
+    ) -> Self {
         Self {
+            menu_type,
             action_id: action_id.into(),
             placeholder,
-            options,
+            max_selected_items: None,
+            confirm: None,
         }
     }
+
+    pub fn set_max_selected_items(mut self, max: u32) -> Self {
+        self.max_selected_items = Some(max);
+        self
+    }
+
+    pub fn set_confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    pub fn build(self) -> MultiSelectMenu {
+        MultiSelectMenu {
+            menu_type: self.menu_type,
+            action_id: self.action_id,
+            placeholder: self.placeholder,
+            max_selected_items: self.max_selected_items,
+            confirm: self.confirm,
+        }
+    }
+
+    pub fn try_build(self) -> Result<MultiSelectMenu, Error> {
+        let multi_select_menu = self.build();
+        multi_select_menu.validate()?;
+        Ok(multi_select_menu)
+    }
 }
 
+/// Every "type" a multi-select menu can be sent with.
+pub(crate) const MULTI_SELECT_MENU_TYPES: &[&str] = &[
+    "multi_static_select",
+    "multi_external_select",
+    "multi_users_select",
+    "multi_conversations_select",
+    "multi_channels_select",
+];
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
 pub enum MultiSelectMenuType {
+    #[serde(rename = "multi_static_select")]
     Static(StaticMultiMenu),
+    #[serde(rename = "multi_external_select")]
     External(ExternalMultiMenu),
+    #[serde(rename = "multi_users_select")]
     User(UserMultiMenu),
+    #[serde(rename = "multi_conversations_select")]
     Conversation(ConversationMultiMenu),
+    #[serde(rename = "multi_channels_select")]
     Channel(ChannelMultiMenu),
 }
 
-impl Serialize for MultiSelectMenuType {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        match self {
-            MultiSelectMenuType::Static(e) => e.serialize(serializer),
-            MultiSelectMenuType::External(e) => e.serialize(serializer),
-            MultiSelectMenuType::User(e) => e.serialize(serializer),
-            MultiSelectMenuType::Conversation(e) => e.serialize(serializer),
-            MultiSelectMenuType::Channel(e) => e.serialize(serializer),
-        }
-    }
-}
-
-#[derive(Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct StaticMultiMenu {
+    #[serde(flatten)]
     options: OptionNestingType,
     #[serde(skip_serializing_if = "Option::is_none")]
-    initial_option: Option<Vec<OptionInput>>,
+    initial_options: Option<Vec<OptionInput<PlainText>>>,
 }
 
 impl StaticMultiMenu {
     pub fn new(options: OptionNestingType) -> Self {
         Self {
             options,
-            initial_option: None,
+            initial_options: None,
         }
     }
 
-    pub fn new_with_initial(options: OptionNestingType, init_option: Vec<OptionInput>) -> Self {
+    pub fn new_with_initial(
+        options: OptionNestingType,
+        init_options: Vec<OptionInput<PlainText>>,
+    ) -> Self {
         Self {
             options,
-            initial_option: Some(init_option),
+            initial_options: Some(init_options),
         }
     }
 }
 
-impl Into<MultiSelectMenuType> for StaticMultiMenu {
-    fn into(self) -> MultiSelectMenuType {
-        MultiSelectMenuType::Static(self)
+impl From<StaticMultiMenu> for MultiSelectMenuType {
+    fn from(e: StaticMultiMenu) -> Self {
+        MultiSelectMenuType::Static(e)
     }
 }
 
-#[derive(Default, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ExternalMultiMenu {
     #[serde(skip_serializing_if = "Option::is_none")]
-    initial_option: Option<Vec<OptionInput>>,
+    initial_options: Option<Vec<OptionInput<PlainText>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    min_query_length: Option<Vec<u32>>,
+    min_query_length: Option<u32>,
 }
 
-impl Into<MultiSelectMenuType> for ExternalMultiMenu {
-    fn into(self) -> MultiSelectMenuType {
-        MultiSelectMenuType::External(self)
+impl From<ExternalMultiMenu> for MultiSelectMenuType {
+    fn from(e: ExternalMultiMenu) -> Self {
+        MultiSelectMenuType::External(e)
     }
 }
 
 #[derive(Default)]
 pub struct ExternalMultiMenuBuilder {
-    initial_option: Option<Vec<OptionInput>>,
-    min_query_length: Option<Vec<u32>>,
+    initial_options: Option<Vec<OptionInput<PlainText>>>,
+    min_query_length: Option<u32>,
 }
 
 impl ExternalMultiMenuBuilder {
@@ -97,33 +179,40 @@ impl ExternalMultiMenuBuilder {
         Self::default()
     }
 
-    pub fn set_initial_option(mut self, option: Vec<OptionInput>) -> Self{
-        self.initial_option = Some(option);
+    pub fn set_initial_options(mut self, options: Vec<OptionInput<PlainText>>) -> Self {
+        self.initial_options = Some(options);
         self
     }
 
-    pub fn set_query_length(mut self, min_len: Vec<u32>) -> Self {
+    pub fn set_query_length(mut self, min_len: u32) -> Self {
         self.min_query_length = Some(min_len);
         self
     }
 
     pub fn build(self) -> ExternalMultiMenu {
         ExternalMultiMenu {
-            initial_option: self.initial_option,
+            initial_options: self.initial_options,
             min_query_length: self.min_query_length,
         }
     }
+
+    pub fn try_build(self) -> Result<ExternalMultiMenu, Error> {
+        let menu = self.build();
+        menu.validate()?;
+        Ok(menu)
+    }
 }
 
-#[derive(Default, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UserMultiMenu {
     #[serde(skip_serializing_if = "Option::is_none")]
-    initial_user: Option<Vec<String>>,
+    initial_users: Option<Vec<String>>,
 }
 
-impl Into<MultiSelectMenuType> for UserMultiMenu {
-    fn into(self) -> MultiSelectMenuType {
-        MultiSelectMenuType::User(self)
+impl From<UserMultiMenu> for MultiSelectMenuType {
+    fn from(e: UserMultiMenu) -> Self {
+        MultiSelectMenuType::User(e)
     }
 }
 
@@ -132,22 +221,23 @@ impl UserMultiMenu {
         Self::default()
     }
 
-    pub fn new_with_initial<S: Into<Vec<String>>>(init_user: S) -> Self {
+    pub fn new_with_initial<S: Into<Vec<String>>>(init_users: S) -> Self {
         Self {
-            initial_user: Some(init_user.into()),
+            initial_users: Some(init_users.into()),
         }
     }
 }
 
-#[derive(Default, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ConversationMultiMenu {
     #[serde(skip_serializing_if = "Option::is_none")]
-    initial_conversation: Option<Vec<String>>,
+    initial_conversations: Option<Vec<String>>,
 }
 
-impl Into<MultiSelectMenuType> for ConversationMultiMenu {
-    fn into(self) -> MultiSelectMenuType {
-        MultiSelectMenuType::Conversation(self)
+impl From<ConversationMultiMenu> for MultiSelectMenuType {
+    fn from(e: ConversationMultiMenu) -> Self {
+        MultiSelectMenuType::Conversation(e)
     }
 }
 
@@ -156,38 +246,161 @@ impl ConversationMultiMenu {
         Self::default()
     }
 
-    pub fn new_with_initial<S: Into<Vec<String>>>(init_conversation: S) -> Self {
+    pub fn new_with_initial<S: Into<Vec<String>>>(init_conversations: S) -> Self {
         Self {
-            initial_conversation: Some(init_conversation.into()),
+            initial_conversations: Some(init_conversations.into()),
         }
     }
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ChannelMultiMenu {
-    options: OptionNestingType,
     #[serde(skip_serializing_if = "Option::is_none")]
-    initial_channel: Option<Vec<String>>,
+    initial_channels: Option<Vec<String>>,
 }
 
-impl Into<MultiSelectMenuType> for ChannelMultiMenu {
-    fn into(self) -> MultiSelectMenuType {
-        MultiSelectMenuType::Channel(self)
+impl From<ChannelMultiMenu> for MultiSelectMenuType {
+    fn from(e: ChannelMultiMenu) -> Self {
+        MultiSelectMenuType::Channel(e)
     }
 }
 
 impl ChannelMultiMenu {
-    pub fn new(options: OptionNestingType) -> Self {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn new_with_initial<S: Into<Vec<String>>>(init_channels: S) -> Self {
         Self {
-            options,
-            initial_channel: None,
+            initial_channels: Some(init_channels.into()),
         }
     }
+}
 
-    pub fn new_with_initial<S: Into<Vec<String>>>(options: OptionNestingType, init_channel: S) -> Self {
-        Self {
-            options,
-            initial_channel: Some(init_channel.into()),
+impl Validate for MultiSelectMenu {
+    fn validate_into(&self, v: &mut Validator) {
+        // The menu type is flattened, so its fields share our path.
+        self.menu_type.validate_into(v);
+        v.max_length("action_id", &self.action_id, MAX_ACTION_ID_LENGTH);
+        v.max_text_length("placeholder", &self.placeholder, MAX_PLACEHOLDER_LENGTH);
+        v.optional_field("confirm", &self.confirm);
+    }
+}
+
+impl Validate for MultiSelectMenuType {
+    fn validate_into(&self, v: &mut Validator) {
+        let initial = match self {
+            MultiSelectMenuType::Static(e) => {
+                e.options.validate_into(v);
+                if let Some(initial) = &e.initial_options {
+                    v.known_options("initial_options", initial, |o| e.options.contains(o));
+                }
+                &e.initial_options
+            }
+            MultiSelectMenuType::External(e) => return e.validate_into(v),
+            MultiSelectMenuType::User(_)
+            | MultiSelectMenuType::Conversation(_)
+            | MultiSelectMenuType::Channel(_) => return,
+        };
+        if let Some(initial) = initial {
+            v.items("initial_options", initial);
+        }
+    }
+}
+
+impl Validate for ExternalMultiMenu {
+    fn validate_into(&self, v: &mut Validator) {
+        if let Some(initial) = &self.initial_options {
+            v.items("initial_options", initial);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::objects::OptionInputGroup;
+
+    fn option(text: &str, value: &str) -> OptionInput<PlainText> {
+        OptionInput::builder(PlainText::new(text), value).build()
+    }
+
+    #[test]
+    fn conversations_select() {
+        let menu = MultiSelectMenu::new(
+            "text1234",
+            PlainText::new("Select conversations"),
+            ConversationMultiMenu::new().into(),
+        );
+        let json = serde_json::to_string(&menu).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"multi_conversations_select","action_id":"text1234","placeholder":{"type":"plain_text","text":"Select conversations"}}"#
+        );
+    }
+
+    #[test]
+    fn static_select_groups() {
+        let groups = vec![OptionInputGroup::new(
+            PlainText::new("Group 1"),
+            vec![option("Option 1", "value-0"), option("Option 2", "value-1")],
+        )];
+        let menu = MultiSelectMenu::builder(
+            "text1234",
+            PlainText::new("Select items"),
+            StaticMultiMenu::new_with_initial(
+                OptionNestingType::Groups(groups),
+                vec![option("Option 1", "value-0")],
+            )
+            .into(),
+        )
+        .set_max_selected_items(2)
+        .build();
+        let json = serde_json::to_string(&menu).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"multi_static_select","option_groups":[{"label":{"type":"plain_text","text":"Group 1"},"options":[{"text":{"type":"plain_text","text":"Option 1"},"value":"value-0"},{"text":{"type":"plain_text","text":"Option 2"},"value":"value-1"}]}],"initial_options":[{"text":{"type":"plain_text","text":"Option 1"},"value":"value-0"}],"action_id":"text1234","placeholder":{"type":"plain_text","text":"Select items"},"max_selected_items":2}"#
+        );
+    }
+
+    #[test]
+    fn external_select() {
+        let menu = MultiSelectMenu::new(
+            "text1234",
+            PlainText::new("Select items"),
+            ExternalMultiMenuBuilder::new()
+                .set_query_length(3)
+                .build()
+                .into(),
+        );
+        let json = serde_json::to_string(&menu).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"multi_external_select","min_query_length":3,"action_id":"text1234","placeholder":{"type":"plain_text","text":"Select items"}}"#
+        );
+    }
+
+    #[test]
+    fn external_try_build() {
+        let result = ExternalMultiMenuBuilder::new()
+            .set_initial_options(vec![
+                option("Option 1", "value-0"),
+                option(&"x".repeat(76), "value-1"),
+            ])
+            .try_build();
+        assert_eq!(result.unwrap_err().to_string(), "invalid payload: initial_options[1].text: must be at most 75 characters long, but is 76");
+    }
+
+    #[test]
+    fn round_trip() {
+        for json in &[
+            r#"{"type":"multi_users_select","initial_users":["U12345","U67890"],"action_id":"text1234","placeholder":{"type":"plain_text","text":"Select users"}}"#,
+            r#"{"type":"multi_channels_select","initial_channels":["C12345"],"action_id":"text1234","placeholder":{"type":"plain_text","text":"Select channels"},"max_selected_items":5}"#,
+            r#"{"type":"multi_static_select","options":[{"text":{"type":"plain_text","text":"Option 1"},"value":"value-0"}],"action_id":"text1234","placeholder":{"type":"plain_text","text":"Select items"}}"#,
+        ] {
+            let menu: MultiSelectMenu = serde_json::from_str(json).unwrap();
+            assert_eq!(&serde_json::to_string(&menu).unwrap().as_str(), json);
         }
     }
 }
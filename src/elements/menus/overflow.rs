@@ -1,28 +1,45 @@
-use crate::objects::{ConfirmationDialog, OptionInput};
-use serde::Serialize;
+use crate::limits::MAX_ACTION_ID_LENGTH;
+use crate::objects::{ConfirmationDialog, OptionInput, PlainText};
+use crate::validation::{Validate, Validator};
+use crate::Error;
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize)]
+const MIN_OPTIONS: usize = 2;
+const MAX_OPTIONS: usize = 5;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "OverflowMenu", transform = crate::schema::tagged("overflow"))
+)]
+#[serde(tag = "type", rename = "overflow")]
 pub struct OverflowMenu {
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ACTION_ID_LENGTH)))]
     action_id: String,
-    options: Vec<OptionInput>,
+    #[cfg_attr(feature = "schema", schemars(length(min = MIN_OPTIONS, max = MAX_OPTIONS)))]
+    options: Vec<OptionInput<PlainText>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     confirm: Option<ConfirmationDialog>,
 }
 
 impl OverflowMenu {
-    pub fn builder<S: Into<String>>(action_id: S, options: Vec<OptionInput>) -> OverflowMenuBuilder {
+    pub fn builder<S: Into<String>>(
+        action_id: S,
+        options: Vec<OptionInput<PlainText>>,
+    ) -> OverflowMenuBuilder {
         OverflowMenuBuilder::new(action_id, options)
     }
 }
 
-pub struct OverflowMenuBuilder  {
+pub struct OverflowMenuBuilder {
     action_id: String,
-    options: Vec<OptionInput>,
+    options: Vec<OptionInput<PlainText>>,
     confirm: Option<ConfirmationDialog>,
 }
 
 impl OverflowMenuBuilder {
-    pub fn new<S: Into<String>>(action_id: S, options: Vec<OptionInput>) -> Self {
+    pub fn new<S: Into<String>>(action_id: S, options: Vec<OptionInput<PlainText>>) -> Self {
         Self {
             action_id: action_id.into(),
             options,
@@ -42,4 +59,76 @@ impl OverflowMenuBuilder {
             confirm: self.confirm,
         }
     }
+
+    pub fn try_build(self) -> Result<OverflowMenu, Error> {
+        let overflow_menu = self.build();
+        overflow_menu.validate()?;
+        Ok(overflow_menu)
+    }
+}
+
+impl Validate for OverflowMenu {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_length("action_id", &self.action_id, MAX_ACTION_ID_LENGTH);
+        v.item_count("options", self.options.len(), MIN_OPTIONS, MAX_OPTIONS);
+        v.items("options", &self.options);
+        v.optional_field("confirm", &self.confirm);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validation::Validate;
+
+    #[test]
+    fn basic() {
+        let options = (1..=2)
+            .map(|i| {
+                OptionInput::builder(
+                    PlainText::new(format!("Option {}", i)),
+                    format!("value-{}", i),
+                )
+                .build()
+            })
+            .collect();
+        let menu = OverflowMenu::builder("overflow", options).build();
+        let json = serde_json::to_string(&menu).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"overflow","action_id":"overflow","options":[{"text":{"type":"plain_text","text":"Option 1"},"value":"value-1"},{"text":{"type":"plain_text","text":"Option 2"},"value":"value-2"}]}"#
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let json = r#"{"type":"overflow","action_id":"overflow","options":[{"text":{"type":"plain_text","text":"Option 1"},"value":"value-1"},{"text":{"type":"plain_text","text":"Option 2"},"value":"value-2","url":"https://example.com/"}]}"#;
+        let value: OverflowMenu = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn validate_option_count() {
+        let option = |i: usize| {
+            OptionInput::builder(
+                PlainText::new(format!("Option {}", i)),
+                format!("value-{}", i),
+            )
+            .build()
+        };
+        let menu = OverflowMenu::builder("overflow", vec![option(1)]).build();
+        assert_eq!(
+            menu.validate().unwrap_err().to_string(),
+            "options: must have at least 2 items, but has 1"
+        );
+
+        let menu = OverflowMenu::builder("overflow", (0..6).map(option).collect()).build();
+        assert_eq!(
+            menu.validate().unwrap_err().to_string(),
+            "options: must have at most 5 items, but has 6"
+        );
+
+        let menu = OverflowMenu::builder("overflow", (0..5).map(option).collect()).build();
+        assert!(menu.validate().is_ok());
+    }
 }
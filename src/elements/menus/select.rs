@@ -1,53 +1,121 @@
 use crate::elements::menus::OptionNestingType;
-use crate::objects::{OptionInput, Text};
+use crate::limits::{MAX_ACTION_ID_LENGTH, MAX_PLACEHOLDER_LENGTH};
+use crate::objects::{ConfirmationDialog, OptionInput, PlainText};
+use crate::validation::{Validate, Validator};
+use crate::Error;
 
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SelectMenu {
+    // The menu type determines the "type" tag and the fields specific to
+    // it, so it's flattened in first.
+    #[serde(flatten)]
+    menu_type: SelectMenuType,
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ACTION_ID_LENGTH)))]
     action_id: String,
-    placeholder: Text,
-    options: SelectMenuType,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_PLACEHOLDER_LENGTH))
+    )]
+    pub(crate) placeholder: PlainText,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confirm: Option<ConfirmationDialog>,
 }
 
 impl SelectMenu {
-    pub fn new<S: Into<String>>(action_id: S, placeholder: Text, options: SelectMenuType) -> Self {
+    pub fn new<S: Into<String>>(
+        action_id: S,
+        placeholder: PlainText,
+        menu_type: SelectMenuType,
+    ) -> Self {
+        SelectMenuBuilder::new(action_id, placeholder, menu_type).build()
+    }
+
+    pub fn builder<S: Into<String>>(
+        action_id: S,
+        placeholder: PlainText,
+        menu_type: SelectMenuType,
+    ) -> SelectMenuBuilder {
+        SelectMenuBuilder::new(action_id, placeholder, menu_type)
+    }
+}
+
+pub struct SelectMenuBuilder {
+    menu_type: SelectMenuType,
+    action_id: String,
+    placeholder: PlainText,
+    confirm: Option<ConfirmationDialog>,
+}
+
+impl SelectMenuBuilder {
+    pub fn new<S: Into<String>>(
+        action_id: S,
+        placeholder: PlainText,
+        menu_type: SelectMenuType,
+    ) -> Self {
         Self {
+            menu_type,
             action_id: action_id.into(),
             placeholder,
-            options,
+            confirm: None,
+        }
+    }
+
+    pub fn set_confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    pub fn build(self) -> SelectMenu {
+        SelectMenu {
+            menu_type: self.menu_type,
+            action_id: self.action_id,
+            placeholder: self.placeholder,
+            confirm: self.confirm,
         }
     }
+
+    pub fn try_build(self) -> Result<SelectMenu, Error> {
+        let select_menu = self.build();
+        select_menu.validate()?;
+        Ok(select_menu)
+    }
 }
 
+/// Every "type" a select menu can be sent with.
+pub(crate) const SELECT_MENU_TYPES: &[&str] = &[
+    "static_select",
+    "external_select",
+    "users_select",
+    "conversations_select",
+    "channels_select",
+];
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type")]
 pub enum SelectMenuType {
+    #[serde(rename = "static_select")]
     Static(StaticMenu),
+    #[serde(rename = "external_select")]
     External(ExternalMenu),
+    #[serde(rename = "users_select")]
     User(UserMenu),
+    #[serde(rename = "conversations_select")]
     Conversation(ConversationMenu),
+    #[serde(rename = "channels_select")]
     Channel(ChannelMenu),
 }
 
-impl Serialize for SelectMenuType {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        match self {
-            SelectMenuType::Static(e) => e.serialize(serializer),
-            SelectMenuType::External(e) => e.serialize(serializer),
-            SelectMenuType::User(e) => e.serialize(serializer),
-            SelectMenuType::Conversation(e) => e.serialize(serializer),
-            SelectMenuType::Channel(e) => e.serialize(serializer),
-        }
-    }
-}
-
-#[derive(Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct StaticMenu {
+    #[serde(flatten)]
     options: OptionNestingType,
     #[serde(skip_serializing_if = "Option::is_none")]
-    initial_option: Option<OptionInput>,
+    initial_option: Option<OptionInput<PlainText>>,
 }
 
 impl StaticMenu {
@@ -58,7 +126,10 @@ impl StaticMenu {
         }
     }
 
-    pub fn new_with_initial(options: OptionNestingType, init_option: OptionInput) -> Self {
+    pub fn new_with_initial(
+        options: OptionNestingType,
+        init_option: OptionInput<PlainText>,
+    ) -> Self {
         Self {
             options,
             initial_option: Some(init_option),
@@ -66,29 +137,30 @@ impl StaticMenu {
     }
 }
 
-impl Into<SelectMenuType> for StaticMenu {
-    fn into(self) -> SelectMenuType {
-        SelectMenuType::Static(self)
+impl From<StaticMenu> for SelectMenuType {
+    fn from(e: StaticMenu) -> Self {
+        SelectMenuType::Static(e)
     }
 }
 
-#[derive(Default, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ExternalMenu {
     #[serde(skip_serializing_if = "Option::is_none")]
-    initial_option: Option<OptionInput>,
+    initial_option: Option<OptionInput<PlainText>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     min_query_length: Option<u32>,
 }
 
-impl Into<SelectMenuType> for ExternalMenu {
-    fn into(self) -> SelectMenuType {
-        SelectMenuType::External(self)
+impl From<ExternalMenu> for SelectMenuType {
+    fn from(e: ExternalMenu) -> Self {
+        SelectMenuType::External(e)
     }
 }
 
 #[derive(Default)]
 pub struct ExternalMenuBuilder {
-    initial_option: Option<OptionInput>,
+    initial_option: Option<OptionInput<PlainText>>,
     min_query_length: Option<u32>,
 }
 
@@ -97,7 +169,7 @@ impl ExternalMenuBuilder {
         Self::default()
     }
 
-    pub fn set_initial_option(mut self, option: OptionInput) -> Self{
+    pub fn set_initial_option(mut self, option: OptionInput<PlainText>) -> Self {
         self.initial_option = Some(option);
         self
     }
@@ -113,17 +185,24 @@ impl ExternalMenuBuilder {
             min_query_length: self.min_query_length,
         }
     }
+
+    pub fn try_build(self) -> Result<ExternalMenu, Error> {
+        let menu = self.build();
+        menu.validate()?;
+        Ok(menu)
+    }
 }
 
-#[derive(Default, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UserMenu {
     #[serde(skip_serializing_if = "Option::is_none")]
     initial_user: Option<String>,
 }
 
-impl Into<SelectMenuType> for UserMenu {
-    fn into(self) -> SelectMenuType {
-        SelectMenuType::User(self)
+impl From<UserMenu> for SelectMenuType {
+    fn from(e: UserMenu) -> Self {
+        SelectMenuType::User(e)
     }
 }
 
@@ -139,15 +218,16 @@ impl UserMenu {
     }
 }
 
-#[derive(Default, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ConversationMenu {
     #[serde(skip_serializing_if = "Option::is_none")]
     initial_conversation: Option<String>,
 }
 
-impl Into<SelectMenuType> for ConversationMenu {
-    fn into(self) -> SelectMenuType {
-        SelectMenuType::Conversation(self)
+impl From<ConversationMenu> for SelectMenuType {
+    fn from(e: ConversationMenu) -> Self {
+        SelectMenuType::Conversation(e)
     }
 }
 
@@ -163,31 +243,168 @@ impl ConversationMenu {
     }
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ChannelMenu {
-    options: OptionNestingType,
     #[serde(skip_serializing_if = "Option::is_none")]
     initial_channel: Option<String>,
 }
 
-impl Into<SelectMenuType> for ChannelMenu {
-    fn into(self) -> SelectMenuType {
-        SelectMenuType::Channel(self)
+impl From<ChannelMenu> for SelectMenuType {
+    fn from(e: ChannelMenu) -> Self {
+        SelectMenuType::Channel(e)
     }
 }
 
 impl ChannelMenu {
-    pub fn new(options: OptionNestingType) -> Self {
-        Self {
-            options,
-            initial_channel: None,
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    pub fn new_with_initial<S: Into<String>>(options: OptionNestingType, init_channel: S) -> Self {
+    pub fn new_with_initial<S: Into<String>>(init_channel: S) -> Self {
         Self {
-            options,
             initial_channel: Some(init_channel.into()),
         }
     }
 }
+
+impl Validate for SelectMenu {
+    fn validate_into(&self, v: &mut Validator) {
+        // The menu type is flattened, so its fields share our path.
+        self.menu_type.validate_into(v);
+        v.max_length("action_id", &self.action_id, MAX_ACTION_ID_LENGTH);
+        v.max_text_length("placeholder", &self.placeholder, MAX_PLACEHOLDER_LENGTH);
+        v.optional_field("confirm", &self.confirm);
+    }
+}
+
+impl Validate for SelectMenuType {
+    fn validate_into(&self, v: &mut Validator) {
+        match self {
+            SelectMenuType::Static(e) => {
+                e.options.validate_into(v);
+                v.optional_field("initial_option", &e.initial_option);
+                v.known_option("initial_option", &e.initial_option, |o| {
+                    e.options.contains(o)
+                });
+            }
+            SelectMenuType::External(e) => e.validate_into(v),
+            SelectMenuType::User(_)
+            | SelectMenuType::Conversation(_)
+            | SelectMenuType::Channel(_) => {}
+        }
+    }
+}
+
+impl Validate for ExternalMenu {
+    fn validate_into(&self, v: &mut Validator) {
+        v.optional_field("initial_option", &self.initial_option);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::objects::OptionInputGroup;
+
+    fn placeholder() -> PlainText {
+        PlainText::new("Select an item")
+    }
+
+    #[test]
+    fn static_select() {
+        let options =
+            vec![
+                OptionInput::builder(PlainText::new("*this is plain_text text*"), "value-0")
+                    .build(),
+            ];
+        let menu = SelectMenu::new(
+            "text1234",
+            placeholder(),
+            StaticMenu::new(OptionNestingType::Flat(options)).into(),
+        );
+        let json = serde_json::to_string(&menu).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"static_select","options":[{"text":{"type":"plain_text","text":"*this is plain_text text*"},"value":"value-0"}],"action_id":"text1234","placeholder":{"type":"plain_text","text":"Select an item"}}"#
+        );
+    }
+
+    #[test]
+    fn users_select() {
+        let menu = SelectMenu::new(
+            "text1234",
+            placeholder(),
+            UserMenu::new_with_initial("U12345").into(),
+        );
+        let json = serde_json::to_string(&menu).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"users_select","initial_user":"U12345","action_id":"text1234","placeholder":{"type":"plain_text","text":"Select an item"}}"#
+        );
+    }
+
+    #[test]
+    fn external_try_build() {
+        let option = OptionInput::builder(PlainText::new("x".repeat(76)), "value-0").build();
+        let result = ExternalMenuBuilder::new()
+            .set_initial_option(option)
+            .try_build();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid payload: initial_option.text: must be at most 75 characters long, but is 76"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for json in &[
+            r#"{"type":"static_select","options":[{"text":{"type":"plain_text","text":"*this is plain_text text*"},"value":"value-0"}],"action_id":"text1234","placeholder":{"type":"plain_text","text":"Select an item"}}"#,
+            r#"{"type":"external_select","min_query_length":3,"action_id":"text1234","placeholder":{"type":"plain_text","text":"Select an item"}}"#,
+            r#"{"type":"users_select","initial_user":"U12345","action_id":"text1234","placeholder":{"type":"plain_text","text":"Select an item"}}"#,
+            r#"{"type":"conversations_select","action_id":"text1234","placeholder":{"type":"plain_text","text":"Select an item"}}"#,
+        ] {
+            let menu: SelectMenu = serde_json::from_str(json).unwrap();
+            assert_eq!(&serde_json::to_string(&menu).unwrap().as_str(), json);
+        }
+    }
+
+    #[test]
+    fn static_select_groups() {
+        let groups = vec![OptionInputGroup::new(
+            PlainText::new("Group 1"),
+            vec![OptionInput::builder(PlainText::new("Option 1"), "value-0").build()],
+        )];
+        let menu = SelectMenu::new(
+            "text1234",
+            placeholder(),
+            StaticMenu::new(OptionNestingType::Groups(groups)).into(),
+        );
+        let json = serde_json::to_string(&menu).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"static_select","option_groups":[{"label":{"type":"plain_text","text":"Group 1"},"options":[{"text":{"type":"plain_text","text":"Option 1"},"value":"value-0"}]}],"action_id":"text1234","placeholder":{"type":"plain_text","text":"Select an item"}}"#
+        );
+    }
+
+    #[test]
+    fn channels_select() {
+        let menu = SelectMenu::builder(
+            "text1234",
+            placeholder(),
+            ChannelMenu::new_with_initial("C12345").into(),
+        )
+        .set_confirm(ConfirmationDialog::new(
+            "Are you sure?",
+            PlainText::new("Really?").into(),
+            "Yes",
+            "No",
+        ))
+        .build();
+        let json = serde_json::to_string(&menu).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"channels_select","initial_channel":"C12345","action_id":"text1234","placeholder":{"type":"plain_text","text":"Select an item"},"confirm":{"title":{"type":"plain_text","text":"Are you sure?"},"text":{"type":"plain_text","text":"Really?"},"confirm":{"type":"plain_text","text":"Yes"},"deny":{"type":"plain_text","text":"No"}}}"#
+        );
+    }
+}
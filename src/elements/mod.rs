@@ -1,25 +1,75 @@
 // Reference: https://api.slack.com/reference/block-kit/block-elements
 mod button;
 mod datepicker;
+mod datetimepicker;
+mod inputs;
 mod menus;
+mod timepicker;
 
 pub use crate::elements::button::{Button, ButtonBuilder, ButtonStyle};
 pub use crate::elements::datepicker::{DatePicker, DatePickerBuilder};
+pub use crate::elements::datetimepicker::{DateTimePicker, DateTimePickerBuilder};
+pub use crate::elements::inputs::*;
 pub use crate::elements::menus::*;
-use crate::objects::{ConfirmationDialog, OptionInput, Text};
+pub(crate) use crate::elements::timepicker::hh_mm;
+pub use crate::elements::timepicker::{TimePicker, TimePickerBuilder};
+use crate::limits::{
+    MAX_ACTION_ID_LENGTH, MAX_ALT_TEXT_LENGTH, MAX_PLACEHOLDER_LENGTH, MAX_URL_LENGTH,
+};
+use crate::objects::{ConfirmationDialog, OptionInput, PlainText};
+use crate::validation::{Validate, Validator};
+use crate::Error;
 
-pub use chrono::{Date, Utc};
+// `Date` is deprecated in chrono, but stays exported so code importing it from
+// here keeps building.
+#[allow(deprecated)]
+pub use chrono::Date;
+pub use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 
-use serde::ser::SerializeMap;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Serialize};
 use url::Url;
 
-#[derive(Serialize)]
+// For checkboxes and radio buttons.
+const MAX_CHOICES: usize = 10;
+const MAX_INPUT_LENGTH: u32 = 3000;
+
+// Every element the crate models, whichever blocks allow it.
+pub(crate) fn is_element_type(kind: &str) -> bool {
+    const ELEMENT_TYPES: &[&str] = &[
+        "button",
+        "checkboxes",
+        "datepicker",
+        "datetimepicker",
+        "email_text_input",
+        "file_input",
+        "image",
+        "number_input",
+        "overflow",
+        "plain_text_input",
+        "radio_buttons",
+        "rich_text_input",
+        "timepicker",
+        "url_text_input",
+    ];
+    ELEMENT_TYPES.contains(&kind)
+        || SELECT_MENU_TYPES.contains(&kind)
+        || MULTI_SELECT_MENU_TYPES.contains(&kind)
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "Checkboxes", transform = crate::schema::tagged("checkboxes"))
+)]
+#[serde(tag = "type", rename = "checkboxes")]
 pub struct Checkboxes {
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ACTION_ID_LENGTH)))]
     action_id: String,
-    options: Vec<OptionInput>,
+    #[cfg_attr(feature = "schema", schemars(length(min = 1, max = MAX_CHOICES)))]
+    pub(crate) options: Vec<OptionInput>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    initial_options: Option<Vec<OptionInput>>,
+    pub(crate) initial_options: Option<Vec<OptionInput>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     confirm: Option<ConfirmationDialog>,
 }
@@ -59,49 +109,69 @@ impl CheckboxesBuilder {
             confirm: self.confirm,
         }
     }
+
+    pub fn try_build(self) -> Result<Checkboxes, Error> {
+        let checkboxes = self.build();
+        checkboxes.validate()?;
+        Ok(checkboxes)
+    }
 }
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "ImageElement", transform = crate::schema::tagged("image"))
+)]
+#[serde(tag = "type", rename = "image")]
 pub struct Image {
-    url: Url,
-    alt_text: String,
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_URL_LENGTH)))]
+    pub(crate) image_url: Url,
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ALT_TEXT_LENGTH)))]
+    pub(crate) alt_text: String,
 }
 
 impl Image {
-    pub fn new(url: Url, alt_text: String) -> Self {
-        Self { url, alt_text }
-    }
-}
-
-impl Serialize for Image {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let mut map = serializer.serialize_map(Some(2))?;
-        map.serialize_entry("url", self.url.as_str())?;
-        map.serialize_entry("alt_text", &self.alt_text)?;
-        map.end()
+    pub fn new(image_url: Url, alt_text: String) -> Self {
+        Self {
+            image_url,
+            alt_text,
+        }
     }
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "PlainTextInput", transform = crate::schema::tagged("plain_text_input"))
+)]
+#[serde(tag = "type", rename = "plain_text_input")]
 pub struct PlainTextInput {
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ACTION_ID_LENGTH)))]
     action_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    placeholder: Option<Text>,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_PLACEHOLDER_LENGTH))
+    )]
+    pub(crate) placeholder: Option<PlainText>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    initial_value: Option<String>,
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_INPUT_LENGTH)))]
+    pub(crate) initial_value: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    multiline: Option<bool>,
+    pub(crate) multiline: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(range(max = MAX_INPUT_LENGTH)))]
     min_length: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(range(min = 1, max = MAX_INPUT_LENGTH)))]
     max_length: Option<u32>,
 }
 
 pub struct PlainTextInputBuilder {
     action_id: String,
-    placeholder: Option<Text>,
+    placeholder: Option<PlainText>,
     initial_value: Option<String>,
     multiline: Option<bool>,
     min_length: Option<u32>,
@@ -120,7 +190,7 @@ impl PlainTextInputBuilder {
         }
     }
 
-    pub fn set_placeholder(mut self, ph: Text) -> Self {
+    pub fn set_placeholder(mut self, ph: PlainText) -> Self {
         self.placeholder = Some(ph);
         self
     }
@@ -155,14 +225,28 @@ impl PlainTextInputBuilder {
             max_length: self.max_length,
         }
     }
+
+    pub fn try_build(self) -> Result<PlainTextInput, Error> {
+        let plain_text_input = self.build();
+        plain_text_input.validate()?;
+        Ok(plain_text_input)
+    }
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "RadioButtonGroup", transform = crate::schema::tagged("radio_buttons"))
+)]
+#[serde(tag = "type", rename = "radio_buttons")]
 pub struct RadioButtonGroup {
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ACTION_ID_LENGTH)))]
     action_id: String,
-    options: Vec<OptionInput>,
+    #[cfg_attr(feature = "schema", schemars(length(min = 1, max = MAX_CHOICES)))]
+    pub(crate) options: Vec<OptionInput>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    initial_option: Option<OptionInput>,
+    pub(crate) initial_option: Option<OptionInput>,
     #[serde(skip_serializing_if = "Option::is_none")]
     confirm: Option<ConfirmationDialog>,
 }
@@ -202,4 +286,186 @@ impl RadioButtonGroupBuilder {
             confirm: self.confirm,
         }
     }
+
+    pub fn try_build(self) -> Result<RadioButtonGroup, Error> {
+        let radio_button_group = self.build();
+        radio_button_group.validate()?;
+        Ok(radio_button_group)
+    }
+}
+
+impl Validate for Checkboxes {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_length("action_id", &self.action_id, MAX_ACTION_ID_LENGTH);
+        v.item_count("options", self.options.len(), 1, MAX_CHOICES);
+        v.items("options", &self.options);
+        if let Some(initial) = &self.initial_options {
+            v.items("initial_options", initial);
+            v.known_options("initial_options", initial, |o| self.options.contains(o));
+        }
+        v.optional_field("confirm", &self.confirm);
+    }
+}
+
+impl Validate for Image {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_length("image_url", self.image_url.as_str(), MAX_URL_LENGTH);
+        v.max_length("alt_text", &self.alt_text, MAX_ALT_TEXT_LENGTH);
+    }
+}
+
+impl Validate for PlainTextInput {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_length("action_id", &self.action_id, MAX_ACTION_ID_LENGTH);
+        v.optional_max_text_length("placeholder", &self.placeholder, MAX_PLACEHOLDER_LENGTH);
+        v.optional_max_length(
+            "initial_value",
+            &self.initial_value,
+            MAX_INPUT_LENGTH as usize,
+        );
+        v.range("min_length", self.min_length, 0, MAX_INPUT_LENGTH);
+        v.range("max_length", self.max_length, 1, MAX_INPUT_LENGTH);
+        v.not_greater_than("min_length", self.min_length, "max_length", self.max_length);
+    }
+}
+
+impl Validate for RadioButtonGroup {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_length("action_id", &self.action_id, MAX_ACTION_ID_LENGTH);
+        v.item_count("options", self.options.len(), 1, MAX_CHOICES);
+        v.items("options", &self.options);
+        v.optional_field("initial_option", &self.initial_option);
+        v.known_option("initial_option", &self.initial_option, |o| {
+            self.options.contains(o)
+        });
+        v.optional_field("confirm", &self.confirm);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn option(text: &str, value: &str) -> OptionInput {
+        OptionInput::builder(PlainText::new(text).into(), value).build()
+    }
+
+    #[test]
+    fn checkboxes() {
+        let checkboxes = CheckboxesBuilder::new(
+            "this_is_an_action_id".to_string(),
+            vec![option("Option 1", "A1"), option("Option 2", "A2")],
+        )
+        .set_initial_options(vec![option("Option 1", "A1")])
+        .build();
+        let json = serde_json::to_string(&checkboxes).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"checkboxes","action_id":"this_is_an_action_id","options":[{"text":{"type":"plain_text","text":"Option 1"},"value":"A1"},{"text":{"type":"plain_text","text":"Option 2"},"value":"A2"}],"initial_options":[{"text":{"type":"plain_text","text":"Option 1"},"value":"A1"}]}"#
+        );
+    }
+
+    #[test]
+    fn image() {
+        let image = Image::new(
+            "http://placekitten.com/700/500".parse().unwrap(),
+            "Multiple cute kittens".to_string(),
+        );
+        let json = serde_json::to_string(&image).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"image","image_url":"http://placekitten.com/700/500","alt_text":"Multiple cute kittens"}"#
+        );
+    }
+
+    #[test]
+    fn plain_text_input() {
+        let input = PlainTextInputBuilder::new("plain_input")
+            .set_placeholder(PlainText::new("Enter some plain text"))
+            .set_multiline(true)
+            .set_min_length(1)
+            .set_max_length(500)
+            .build();
+        let json = serde_json::to_string(&input).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"plain_text_input","action_id":"plain_input","placeholder":{"type":"plain_text","text":"Enter some plain text"},"multiline":true,"min_length":1,"max_length":500}"#
+        );
+    }
+
+    #[test]
+    fn radio_buttons() {
+        let radio = RadioButtonGroupBuilder::new(
+            "this_is_an_action_id",
+            vec![option("Radio 1", "A1"), option("Radio 2", "A2")],
+        )
+        .set_initial_option(option("Radio 1", "A1"))
+        .build();
+        let json = serde_json::to_string(&radio).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"radio_buttons","action_id":"this_is_an_action_id","options":[{"text":{"type":"plain_text","text":"Radio 1"},"value":"A1"},{"text":{"type":"plain_text","text":"Radio 2"},"value":"A2"}],"initial_option":{"text":{"type":"plain_text","text":"Radio 1"},"value":"A1"}}"#
+        );
+    }
+
+    #[test]
+    fn checkboxes_round_trip() {
+        let json = r#"{"type":"checkboxes","action_id":"this_is_an_action_id","options":[{"text":{"type":"mrkdwn","text":"*Option 1*"},"value":"A1","description":{"type":"mrkdwn","text":"Description"}}]}"#;
+        let value: Checkboxes = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn plain_text_input_round_trip() {
+        let json = r#"{"type":"plain_text_input","action_id":"plain_input","initial_value":"hello","multiline":false,"min_length":1,"max_length":500}"#;
+        let value: PlainTextInput = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn radio_buttons_round_trip() {
+        let json = r#"{"type":"radio_buttons","action_id":"this_is_an_action_id","options":[{"text":{"type":"plain_text","text":"Radio 1"},"value":"A1"}],"initial_option":{"text":{"type":"plain_text","text":"Radio 1"},"value":"A1"}}"#;
+        let value: RadioButtonGroup = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn plain_text_input_try_build() {
+        let err = PlainTextInputBuilder::new("plain_input")
+            .set_min_length(10)
+            .set_max_length(5)
+            .try_build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid payload: min_length: must not be greater than max_length"
+        );
+
+        let err = PlainTextInputBuilder::new("plain_input")
+            .set_max_length(3001)
+            .try_build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid payload: max_length: must be between 1 and 3000, but is 3001"
+        );
+
+        assert!(PlainTextInputBuilder::new("plain_input")
+            .set_min_length(1)
+            .set_max_length(5)
+            .try_build()
+            .is_ok());
+    }
+
+    #[test]
+    fn radio_buttons_unknown_initial_option() {
+        let err = RadioButtonGroupBuilder::new("radio", vec![option("Radio 1", "A1")])
+            .set_initial_option(option("Radio 2", "A2"))
+            .try_build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid payload: initial_option: must be one of the options"
+        );
+    }
 }
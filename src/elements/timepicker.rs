@@ -0,0 +1,170 @@
+use crate::limits::{MAX_ACTION_ID_LENGTH, MAX_PLACEHOLDER_LENGTH};
+use crate::objects::{ConfirmationDialog, PlainText};
+use crate::validation::{Validate, Validator};
+use crate::Error;
+
+use serde::{Deserialize, Serialize};
+
+use chrono::NaiveTime;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "TimePicker", transform = crate::schema::tagged("timepicker"))
+)]
+#[serde(tag = "type", rename = "timepicker")]
+pub struct TimePicker {
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_ACTION_ID_LENGTH)))]
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_PLACEHOLDER_LENGTH))
+    )]
+    pub placeholder: Option<PlainText>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "hh_mm")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(with = "Option<String>", regex(pattern = r"^\d{2}:\d{2}$"))
+    )]
+    pub initial_time: Option<NaiveTime>,
+    // An IANA name, e.g. "America/Los_Angeles". Slack uses the user's own
+    // timezone when this isn't set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl TimePicker {
+    pub fn builder<S: Into<String>>(action_id: S) -> TimePickerBuilder {
+        TimePickerBuilder::new(action_id)
+    }
+}
+
+pub struct TimePickerBuilder {
+    action_id: String,
+    placeholder: Option<PlainText>,
+    initial_time: Option<NaiveTime>,
+    timezone: Option<String>,
+    confirm: Option<ConfirmationDialog>,
+}
+
+impl TimePickerBuilder {
+    pub fn new<S: Into<String>>(action_id: S) -> Self {
+        Self {
+            action_id: action_id.into(),
+            placeholder: None,
+            initial_time: None,
+            timezone: None,
+            confirm: None,
+        }
+    }
+
+    pub fn set_placeholder<P: Into<PlainText>>(mut self, ph: P) -> Self {
+        self.placeholder = Some(ph.into());
+        self
+    }
+
+    pub fn set_initial_time(mut self, init_time: NaiveTime) -> Self {
+        self.initial_time = Some(init_time);
+        self
+    }
+
+    pub fn set_timezone<S: Into<String>>(mut self, timezone: S) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+
+    pub fn set_confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    pub fn build(self) -> TimePicker {
+        TimePicker {
+            action_id: self.action_id,
+            placeholder: self.placeholder,
+            initial_time: self.initial_time,
+            timezone: self.timezone,
+            confirm: self.confirm,
+        }
+    }
+
+    pub fn try_build(self) -> Result<TimePicker, Error> {
+        let time_picker = self.build();
+        time_picker.validate()?;
+        Ok(time_picker)
+    }
+}
+
+impl Validate for TimePicker {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_length("action_id", &self.action_id, MAX_ACTION_ID_LENGTH);
+        v.optional_max_text_length("placeholder", &self.placeholder, MAX_PLACEHOLDER_LENGTH);
+        v.optional_field("confirm", &self.confirm);
+    }
+}
+
+// Slack sends and expects times as "HH:mm", without seconds, which isn't
+// chrono's default format.
+pub(crate) mod hh_mm {
+    use chrono::NaiveTime;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    const FORMAT: &str = "%H:%M";
+
+    pub fn serialize<S>(time: &Option<NaiveTime>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match time {
+            Some(time) => serializer.collect_str(&time.format(FORMAT)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<NaiveTime>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(time) => NaiveTime::parse_from_str(&time, FORMAT)
+                .map(Some)
+                .map_err(D::Error::custom),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn full() {
+        let picker = TimePicker::builder("action_id")
+            .set_initial_time(NaiveTime::from_hms_opt(9, 5, 0).unwrap())
+            .set_placeholder("Select time")
+            .set_timezone("America/Los_Angeles")
+            .build();
+        let json = serde_json::to_string(&picker).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"timepicker","action_id":"action_id","placeholder":{"type":"plain_text","text":"Select time"},"initial_time":"09:05","timezone":"America/Los_Angeles"}"#
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for json in &[
+            r#"{"type":"timepicker","action_id":"action_id"}"#,
+            r#"{"type":"timepicker","action_id":"action_id","initial_time":"23:59"}"#,
+        ] {
+            let picker: TimePicker = serde_json::from_str(json).unwrap();
+            assert_eq!(&serde_json::to_string(&picker).unwrap().as_str(), json);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::block_kit_builder::BlockKitBuilderError;
+use crate::payloads::StateError;
+use crate::validation::ValidationErrors;
+
+use std::error;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The value breaks one or more of Slack's limits. Every builder's
+    /// `try_build` returns this when `Validate` rejects what it built; `build`
+    /// skips the check. Text's limits depend on where it's used, so
+    /// `TextBuilder` and `MrkdwnBuilder` only have `build`.
+    Validation(ValidationErrors),
+    /// A submitted view's state is missing a value, or has the wrong kind.
+    State(StateError),
+    /// A Block Kit Builder link or its JSON couldn't be read.
+    BlockKitBuilder(BlockKitBuilderError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Validation(e) => write!(f, "invalid payload: {}", e),
+            Error::State(e) => write!(f, "invalid view state: {}", e),
+            Error::BlockKitBuilder(e) => write!(f, "invalid Block Kit Builder link: {}", e),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Validation(e) => Some(e),
+            Error::State(e) => Some(e),
+            Error::BlockKitBuilder(e) => Some(e),
+        }
+    }
+}
+
+impl From<ValidationErrors> for Error {
+    fn from(e: ValidationErrors) -> Self {
+        Error::Validation(e)
+    }
+}
+
+impl From<StateError> for Error {
+    fn from(e: StateError) -> Self {
+        Error::State(e)
+    }
+}
+
+impl From<BlockKitBuilderError> for Error {
+    fn from(e: BlockKitBuilderError) -> Self {
+        Error::BlockKitBuilder(e)
+    }
+}
@@ -1,5 +1,26 @@
+#[macro_use]
+mod macros;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+pub mod block_kit_builder;
 pub mod blocks;
+mod de;
 pub mod elements;
+mod error;
+mod limits;
+#[cfg(feature = "markdown")]
+pub mod markdown;
 pub mod objects;
+pub mod payloads;
+pub mod render;
+#[cfg(feature = "schema")]
+pub mod schema;
+mod split;
 pub mod surfaces;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod validation;
 pub mod views;
+
+pub use crate::error::Error;
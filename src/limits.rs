@@ -0,0 +1,11 @@
+// Limits Slack documents for fields that many types share. Limits only one
+// type has live next to it. `Validate` and the JSON Schema both read these, so
+// the two can't disagree.
+pub(crate) const MAX_BLOCK_ID_LENGTH: usize = 255;
+pub(crate) const MAX_ACTION_ID_LENGTH: usize = 255;
+pub(crate) const MAX_PLACEHOLDER_LENGTH: usize = 150;
+pub(crate) const MAX_URL_LENGTH: usize = 3000;
+// For image blocks and image elements.
+pub(crate) const MAX_ALT_TEXT_LENGTH: usize = 2000;
+// For select menus' options and option groups, and each group's options.
+pub(crate) const MAX_MENU_OPTIONS: usize = 100;
@@ -0,0 +1,363 @@
+// Declarative macros for building blocks, which expand to the builders.
+//
+// Blocks and elements are written like calls, with their required fields as
+// arguments, then optionally the builder's setters in braces:
+//
+//     section(mrkdwn("*api* is live")) { accessory: button("logs", "Logs") }
+//
+// Anything that isn't one of the kinds below is passed through as an
+// expression, so other blocks and elements can still be mixed in. Blocks are
+// converted into the surface's block type, so one the surface doesn't allow
+// is a compile error.
+
+/// Builds a list of blocks for any surface, e.g. `Vec<MessageBlock>`.
+///
+/// ```
+/// use slack_blocks::blocks;
+/// use slack_blocks::elements::ButtonStyle;
+/// use slack_blocks::surfaces::MessageBlock;
+///
+/// let blocks: Vec<MessageBlock> = blocks![
+///     header("Deploy finished"),
+///     section(mrkdwn("*api* is live")) {
+///         fields: [mrkdwn("*Version*\n1.2.3"), plain_text("Took 4m")],
+///         accessory: button("logs", "Logs") { style: ButtonStyle::Primary },
+///     },
+///     divider(),
+///     context(mrkdwn("Deployed by <@U123>")),
+///     actions(button("approve", "Approve"), button("deny", "Deny")),
+/// ];
+/// assert_eq!(blocks.len(), 5);
+/// ```
+///
+/// These kinds of blocks are supported:
+///
+/// - `actions(element, ..)`
+/// - `context(element, ..)`
+/// - `divider()`
+/// - `header(text)`
+/// - `image(url, alt_text)`
+/// - `input(label, element)`
+/// - `section(text)`
+///
+/// As well as these elements:
+///
+/// - `button(action_id, text)`
+/// - `datepicker(action_id)`
+/// - `image(url, alt_text)`
+/// - `mrkdwn(text)` and `plain_text(text)`
+/// - `plain_text_input(action_id)`
+/// - `timepicker(action_id)`
+///
+/// The setters in braces take the builder's setter names without `set_`,
+/// e.g. `hint` for `set_hint`.
+#[macro_export]
+macro_rules! blocks {
+    ($($blocks:tt)*) => {
+        $crate::__block_kit!(@args block __list {} block [] $($blocks)*)
+    };
+}
+
+/// Builds a `Modal`, with blocks written as for `blocks!`.
+///
+/// ```
+/// use slack_blocks::modal;
+///
+/// let modal = modal! {
+///     title: "Settings",
+///     submit: "Save",
+///     close: "Cancel",
+///     blocks: [
+///         input("Name", plain_text_input("name")) { hint: "Your full name" },
+///     ],
+/// };
+/// assert_eq!(modal.blocks.len(), 1);
+/// ```
+#[macro_export]
+macro_rules! modal {
+    (
+        title: $title:expr
+        $(, submit: $submit:expr)?
+        $(, close: $close:expr)?
+        , blocks: [$($blocks:tt)*] $(,)?
+    ) => {{
+        let blocks: ::std::vec::Vec<$crate::surfaces::ModalBlock> = $crate::blocks![$($blocks)*];
+        let builder = $crate::surfaces::ModalBuilder::new(($title).into(), blocks);
+        $(let builder = builder.set_submit(($submit).into());)?
+        $(let builder = builder.set_close(($close).into());)?
+        builder.build()
+    }};
+}
+
+/// Builds a `HomeTab`, with blocks written as for `blocks!`.
+///
+/// Blocks that can't go in a home tab are rejected:
+///
+/// ```compile_fail
+/// use slack_blocks::home_tab;
+///
+/// let home = home_tab![input("Name", plain_text_input("name"))];
+/// ```
+#[macro_export]
+macro_rules! home_tab {
+    ($($blocks:tt)*) => {{
+        let blocks: ::std::vec::Vec<$crate::surfaces::HomeTabBlock> = $crate::blocks![$($blocks)*];
+        $crate::surfaces::HomeTab::new(blocks)
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __block_kit {
+    // Munches a list of arguments, building any blocks or elements among
+    // them, then builds `$kind` from them. `$ctx` is where `$kind` goes, and
+    // `$arg_ctx` where its arguments do.
+    (@args $ctx:ident $kind:ident $props:tt $arg_ctx:ident [$($done:expr,)*] $(,)?) => {
+        $crate::__block_kit!(@build $ctx $kind [$($done,)*] $props)
+    };
+    (@args $ctx:ident $kind:ident $props:tt $arg_ctx:ident [$($done:expr,)*]
+        $k:ident ($($a:tt)*) {$($p:tt)*} $(, $($rest:tt)*)?) => {
+        $crate::__block_kit!(@args $ctx $kind $props $arg_ctx [
+            $($done,)* $crate::__block_kit!(@item $arg_ctx $k ($($a)*) {$($p)*}),
+        ] $($($rest)*)?)
+    };
+    (@args $ctx:ident $kind:ident $props:tt $arg_ctx:ident [$($done:expr,)*]
+        $k:ident ($($a:tt)*) $(, $($rest:tt)*)?) => {
+        $crate::__block_kit!(@args $ctx $kind $props $arg_ctx [
+            $($done,)* $crate::__block_kit!(@item $arg_ctx $k ($($a)*) {}),
+        ] $($($rest)*)?)
+    };
+    (@args $ctx:ident $kind:ident $props:tt $arg_ctx:ident [$($done:expr,)*]
+        [$($l:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::__block_kit!(@args $ctx $kind $props $arg_ctx [
+            $($done,)* $crate::__block_kit!(@args element __list {} element [] $($l)*),
+        ] $($($rest)*)?)
+    };
+    (@args $ctx:ident $kind:ident $props:tt $arg_ctx:ident [$($done:expr,)*]
+        $e:expr $(, $($rest:tt)*)?) => {
+        $crate::__block_kit!(@args $ctx $kind $props $arg_ctx [$($done,)* $e,] $($($rest)*)?)
+    };
+
+    (@item $ctx:ident $kind:ident ($($a:tt)*) $props:tt) => {
+        $crate::__block_kit!(@args $ctx $kind $props element [] $($a)*)
+    };
+
+    // Blocks.
+    (@build $ctx:ident __list [$($e:expr,)*] {}) => {
+        ::std::vec![$(($e).into()),*]
+    };
+    (@build block actions [$($e:expr,)*] {}) => {
+        $crate::blocks::Actions::new(::std::vec![$(($e).into()),*])
+    };
+    (@build block actions [$($e:expr,)*] {block_id: $id:expr $(,)?}) => {
+        $crate::blocks::Actions::new_with_id($id, ::std::vec![$(($e).into()),*])
+    };
+    (@build block context [$($e:expr,)*] {}) => {
+        $crate::blocks::Context::new(::std::vec![$(($e).into()),*])
+    };
+    (@build block context [$($e:expr,)*] {block_id: $id:expr $(,)?}) => {
+        $crate::blocks::Context::new_with_id($id, ::std::vec![$(($e).into()),*])
+    };
+    (@build block divider [] {}) => {
+        $crate::blocks::Divider::new()
+    };
+    (@build block divider [] {block_id: $id:expr $(,)?}) => {
+        $crate::blocks::Divider::new_with_id($id)
+    };
+    (@build block header [$text:expr,] $props:tt) => {
+        $crate::__block_kit!(@props ($crate::blocks::HeaderBuilder::new(($text).into())) $props)
+            .build()
+    };
+    (@build block image [$url:expr, $alt_text:expr,] $props:tt) => {
+        $crate::__block_kit!(@props ($crate::blocks::ImageBuilder::new($url, $alt_text)) $props)
+            .build()
+    };
+    (@build block input [$label:expr, $element:expr,] $props:tt) => {
+        $crate::__block_kit!(@props
+            ($crate::blocks::InputBuilder::new(($label).into(), ($element).into()))
+            $props
+        )
+        .build()
+    };
+    (@build block section [$text:expr,] $props:tt) => {
+        $crate::__block_kit!(@props ($crate::blocks::SectionBuilder::new(($text).into())) $props)
+            .build()
+    };
+
+    // Elements, and text, which goes anywhere.
+    (@build element button [$action_id:expr, $text:expr,] $props:tt) => {
+        $crate::__block_kit!(@props
+            ($crate::elements::ButtonBuilder::new($action_id, ($text).into()))
+            $props
+        )
+        .build()
+    };
+    (@build element datepicker [$action_id:expr,] $props:tt) => {
+        $crate::__block_kit!(@props ($crate::elements::DatePickerBuilder::new($action_id)) $props)
+            .build()
+    };
+    (@build element image [$url:expr, $alt_text:expr,] {}) => {
+        $crate::elements::Image::new($url, ($alt_text).into())
+    };
+    (@build $ctx:ident mrkdwn [$text:expr,] {}) => {
+        $crate::objects::Mrkdwn::new($text)
+    };
+    (@build $ctx:ident plain_text [$text:expr,] {}) => {
+        $crate::objects::PlainText::new($text)
+    };
+    (@build element plain_text_input [$action_id:expr,] $props:tt) => {
+        $crate::__block_kit!(@props
+            ($crate::elements::PlainTextInputBuilder::new($action_id))
+            $props
+        )
+        .build()
+    };
+    (@build element timepicker [$action_id:expr,] $props:tt) => {
+        $crate::__block_kit!(@props ($crate::elements::TimePickerBuilder::new($action_id)) $props)
+            .build()
+    };
+
+    // Anything else is called as a function.
+    (@build $ctx:ident $kind:ident [$($e:expr,)*] {}) => {
+        $kind($($e),*)
+    };
+
+    // Applies the setters in braces to a builder.
+    (@props ($b:expr) {$(,)?}) => {
+        $b
+    };
+    (@props ($b:expr) {$name:ident: $k:ident ($($a:tt)*) {$($p:tt)*} $(, $($rest:tt)*)?}) => {
+        $crate::__block_kit!(@props
+            ($crate::__block_kit!(@set $b, $name,
+                $crate::__block_kit!(@item element $k ($($a)*) {$($p)*})))
+            {$($($rest)*)?}
+        )
+    };
+    (@props ($b:expr) {$name:ident: $k:ident ($($a:tt)*) $(, $($rest:tt)*)?}) => {
+        $crate::__block_kit!(@props
+            ($crate::__block_kit!(@set $b, $name,
+                $crate::__block_kit!(@item element $k ($($a)*) {})))
+            {$($($rest)*)?}
+        )
+    };
+    (@props ($b:expr) {$name:ident: [$($l:tt)*] $(, $($rest:tt)*)?}) => {
+        $crate::__block_kit!(@props
+            ($crate::__block_kit!(@set $b, $name,
+                $crate::__block_kit!(@args element __list {} element [] $($l)*)))
+            {$($($rest)*)?}
+        )
+    };
+    (@props ($b:expr) {$name:ident: $v:expr $(, $($rest:tt)*)?}) => {
+        $crate::__block_kit!(@props
+            ($crate::__block_kit!(@set $b, $name, $v))
+            {$($($rest)*)?}
+        )
+    };
+
+    // Setters that take text accept strings too, and those that take
+    // elements accept the elements they convert from.
+    (@set $b:expr, accessory, $v:expr) => { $b.set_accessory(($v).into()) };
+    (@set $b:expr, block_id, $v:expr) => { $b.set_block_id($v) };
+    (@set $b:expr, confirm, $v:expr) => { $b.set_confirm($v) };
+    (@set $b:expr, fields, $v:expr) => { $b.set_fields($v) };
+    (@set $b:expr, hint, $v:expr) => { $b.set_hint($crate::objects::PlainText::from($v)) };
+    (@set $b:expr, initial_date, $v:expr) => { $b.set_initial_date($v) };
+    (@set $b:expr, initial_time, $v:expr) => { $b.set_initial_time($v) };
+    (@set $b:expr, initial_value, $v:expr) => { $b.set_initial_value($v) };
+    (@set $b:expr, max_length, $v:expr) => { $b.set_max_length($v) };
+    (@set $b:expr, min_length, $v:expr) => { $b.set_min_length($v) };
+    (@set $b:expr, multiline, $v:expr) => { $b.set_multiline($v) };
+    (@set $b:expr, optional, $v:expr) => { $b.set_optional($v) };
+    (@set $b:expr, placeholder, $v:expr) => {
+        $b.set_placeholder($crate::objects::PlainText::from($v))
+    };
+    (@set $b:expr, style, $v:expr) => { $b.set_style($v) };
+    (@set $b:expr, title, $v:expr) => { $b.set_title($crate::objects::PlainText::from($v)) };
+    (@set $b:expr, url, $v:expr) => { $b.set_url($v) };
+    (@set $b:expr, value, $v:expr) => { $b.set_value($v) };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::blocks::*;
+    use crate::elements::{ButtonBuilder, ButtonStyle, PlainTextInputBuilder};
+    use crate::objects::{Mrkdwn, PlainText};
+    use crate::surfaces::{HomeTab, HomeTabBlock, MessageBlock, ModalBuilder};
+
+    #[test]
+    fn blocks() {
+        let expected: Vec<MessageBlock> = vec![
+            Header::new(PlainText::new("Deploy finished")).into(),
+            SectionBuilder::new(Mrkdwn::new("*api* is live").into())
+                .set_block_id("status")
+                .set_fields(vec![PlainText::new("Took 4m").into()])
+                .set_accessory(
+                    ButtonBuilder::new("logs", PlainText::new("Logs"))
+                        .set_style(ButtonStyle::Primary)
+                        .set_value("api")
+                        .build()
+                        .into(),
+                )
+                .build()
+                .into(),
+            Divider::new().into(),
+            Context::new(vec![Mrkdwn::new("By <@U123>").into()]).into(),
+            Actions::new_with_id(
+                "deploy",
+                vec![ButtonBuilder::new("undo", PlainText::new("Undo"))
+                    .build()
+                    .into()],
+            )
+            .into(),
+        ];
+        let blocks: Vec<MessageBlock> = blocks![
+            header("Deploy finished"),
+            section(mrkdwn("*api* is live")) {
+                block_id: "status",
+                fields: [plain_text("Took 4m")],
+                accessory: button("logs", "Logs") { style: ButtonStyle::Primary, value: "api" },
+            },
+            divider(),
+            context(mrkdwn("By <@U123>")),
+            actions(button("undo", "Undo")) { block_id: "deploy" },
+        ];
+        assert_eq!(blocks, expected);
+    }
+
+    #[test]
+    fn surfaces() {
+        let name = PlainTextInputBuilder::new("name")
+            .set_placeholder(PlainText::new("Ada"))
+            .build();
+        let expected = ModalBuilder::new(
+            PlainText::new("Settings"),
+            vec![InputBuilder::new(PlainText::new("Name"), name.into())
+                .set_hint(PlainText::new("Your full name"))
+                .build()
+                .into()],
+        )
+        .set_submit(PlainText::new("Save"))
+        .build();
+        let modal = modal! {
+            title: "Settings",
+            submit: "Save",
+            blocks: [
+                input("Name", plain_text_input("name") { placeholder: "Ada" }) {
+                    hint: "Your full name",
+                },
+            ],
+        };
+        assert_eq!(modal, expected);
+
+        // Expressions are passed through, converted into the surface's blocks.
+        let divider = Divider::new_with_id("top");
+        let home = home_tab![divider.clone(), header(format!("Hi {}", "Ada"))];
+        assert_eq!(
+            home,
+            HomeTab::new(vec![
+                HomeTabBlock::Divider(divider),
+                Header::new(PlainText::new("Hi Ada")).into(),
+            ])
+        );
+    }
+}
@@ -0,0 +1,397 @@
+// Converts CommonMark into blocks, e.g. for posting runbooks or release notes
+// that are kept as Markdown.
+//
+// Slack's mrkdwn covers most of what Markdown can express, so text is
+// gathered into mrkdwn sections, and only headings, images and thematic
+// breaks get blocks of their own.
+use crate::blocks::{
+    Divider, Header, Image, ImageBuilder, Section, SectionBuilder, MAX_HEADER_LENGTH,
+    MAX_SECTION_TEXT_LENGTH,
+};
+use crate::objects::{Mrkdwn, MrkdwnBuilder, PlainText};
+use crate::split::split_mrkdwn;
+
+use pulldown_cmark::{Event, HeadingLevel, LinkType, Options, Parser, Tag, TagEnd};
+use url::Url;
+
+/// Converts CommonMark into blocks for any surface, e.g. `Vec<MessageBlock>`.
+///
+/// Top-level headings become `Header` blocks and lower ones bold text.
+/// Paragraphs, lists, code and quotes become mrkdwn `Section`s, split so none
+/// is over Slack's 3000 character limit. Images become `Image` blocks and
+/// thematic breaks `Divider`s.
+pub fn to_blocks<B>(markdown: &str) -> Vec<B>
+where
+    B: From<Divider> + From<Header> + From<Image> + From<Section>,
+{
+    let options = Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut converter = Converter::default();
+    for event in Parser::new_ext(markdown, options) {
+        converter.event(event);
+    }
+    converter.flush();
+    converter
+        .blocks
+        .into_iter()
+        .map(Block::into_block)
+        .collect()
+}
+
+// The blocks this produces, before they're wrapped in a surface's enum.
+#[allow(clippy::large_enum_variant)]
+enum Block {
+    Divider(Divider),
+    Header(Header),
+    Image(Image),
+    Section(Section),
+}
+
+impl Block {
+    fn into_block<B>(self) -> B
+    where
+        B: From<Divider> + From<Header> + From<Image> + From<Section>,
+    {
+        match self {
+            Block::Divider(e) => e.into(),
+            Block::Header(e) => e.into(),
+            Block::Image(e) => e.into(),
+            Block::Section(e) => e.into(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Converter {
+    blocks: Vec<Block>,
+    // Top-level pieces of text, e.g. a paragraph or a whole list. Sections
+    // are made of as many of these as fit.
+    chunks: Vec<String>,
+    // The mrkdwn for the chunk being built, along with the text alone for
+    // headings, since headers can't be formatted.
+    mrkdwn: String,
+    plain: String,
+    // How many block-level tags we're inside of.
+    depth: usize,
+    // The next number for each list we're in, or `None` for bulleted lists.
+    lists: Vec<Option<u64>>,
+    in_code_block: bool,
+    at_item_start: bool,
+    // The URL, title and alt text of an image being read.
+    image: Option<(String, String, String)>,
+    // The URL of each link we're in.
+    links: Vec<String>,
+}
+
+impl Converter {
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.text(&text),
+            Event::Code(code) => {
+                self.plain.push_str(&code);
+                self.push(&format!("`{}`", MrkdwnBuilder::escape(&code)));
+            }
+            Event::Html(html) | Event::InlineHtml(html) => self.text(&html),
+            Event::SoftBreak => self.text(" "),
+            Event::HardBreak => self.push("\n"),
+            Event::Rule => {
+                self.flush();
+                self.blocks.push(Block::Divider(Divider::new()));
+            }
+            Event::TaskListMarker(done) => self.push(if done { "☑ " } else { "☐ " }),
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph
+            | Tag::Heading { .. }
+            | Tag::BlockQuote(_)
+            | Tag::CodeBlock(_)
+            | Tag::HtmlBlock
+            | Tag::List(_)
+            | Tag::Item => self.start_block(tag),
+            Tag::Emphasis => self.push("_"),
+            Tag::Strong => self.push("*"),
+            Tag::Strikethrough => self.push("~"),
+            Tag::Link {
+                link_type,
+                dest_url,
+                ..
+            } => {
+                let url = match link_type {
+                    LinkType::Email => format!("mailto:{}", dest_url),
+                    _ => dest_url.to_string(),
+                };
+                // A "|" in the URL would be read as the start of the label.
+                let url = MrkdwnBuilder::escape(&url).replace('|', "%7C");
+                self.push(&format!("<{}|", url));
+                self.links.push(url);
+            }
+            Tag::Image {
+                dest_url, title, ..
+            } => self.image = Some((dest_url.to_string(), title.to_string(), String::new())),
+            _ => {}
+        }
+    }
+
+    fn start_block(&mut self, tag: Tag) {
+        if self.depth == 0 {
+            self.mrkdwn.clear();
+            self.plain.clear();
+        } else if !self.mrkdwn.is_empty() && !self.mrkdwn.ends_with('\n') && !self.at_item_start {
+            // e.g. the second paragraph of a list item, or a nested list.
+            self.mrkdwn.push('\n');
+        }
+        self.depth += 1;
+        match tag {
+            // mrkdwn has no syntax highlighting, so the language is dropped.
+            Tag::CodeBlock(_) => {
+                self.in_code_block = true;
+                self.mrkdwn.push_str("```\n");
+            }
+            Tag::List(start) => self.lists.push(start),
+            Tag::Item => {
+                let indent = "    ".repeat(self.lists.len().saturating_sub(1));
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => "• ".to_string(),
+                };
+                self.mrkdwn.push_str(&indent);
+                self.mrkdwn.push_str(&marker);
+                self.at_item_start = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph
+            | TagEnd::Heading(_)
+            | TagEnd::BlockQuote(_)
+            | TagEnd::CodeBlock
+            | TagEnd::HtmlBlock
+            | TagEnd::List(_)
+            | TagEnd::Item => self.end_block(tag),
+            TagEnd::Emphasis => self.push("_"),
+            TagEnd::Strong => self.push("*"),
+            TagEnd::Strikethrough => self.push("~"),
+            TagEnd::Link => {
+                // Links need a label, so bare ones are labelled with their URL.
+                let url = self.links.pop().unwrap_or_default();
+                if self.mrkdwn.ends_with('|') {
+                    self.mrkdwn.push_str(&url);
+                }
+                self.mrkdwn.push('>');
+            }
+            TagEnd::Image => self.end_image(),
+            _ => {}
+        }
+    }
+
+    fn end_block(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::CodeBlock => {
+                self.in_code_block = false;
+                let trimmed = self.mrkdwn.trim_end_matches('\n').len();
+                self.mrkdwn.truncate(trimmed);
+                self.mrkdwn.push_str("\n```");
+            }
+            TagEnd::List(_) => {
+                self.lists.pop();
+            }
+            _ => {}
+        }
+        self.depth -= 1;
+        if self.depth > 0 {
+            return;
+        }
+        let text = std::mem::take(&mut self.mrkdwn);
+        let text = text.trim_end().to_string();
+        match tag {
+            // e.g. a lone "#", which would make an empty header.
+            TagEnd::Heading(_) if self.plain.trim().is_empty() => {}
+            TagEnd::Heading(level) => {
+                let plain = std::mem::take(&mut self.plain);
+                let plain = plain.trim();
+                if level <= HeadingLevel::H2 && plain.chars().count() <= MAX_HEADER_LENGTH {
+                    self.flush();
+                    self.blocks
+                        .push(Block::Header(Header::new(PlainText::new(plain))));
+                } else if !text.is_empty() {
+                    self.chunk(format!("*{}*", text));
+                }
+            }
+            TagEnd::BlockQuote(_) => {
+                let quoted: Vec<String> = text.lines().map(|e| format!(">{}", e)).collect();
+                self.chunk(quoted.join("\n"));
+            }
+            _ => self.chunk(text),
+        }
+    }
+
+    fn end_image(&mut self) {
+        let (url, title, alt_text) = match self.image.take() {
+            Some(image) => image,
+            None => return,
+        };
+        // Blocks can't go inside a link, so an image there stands in as its
+        // alt text, or leaves the link to be labelled with its URL.
+        if !self.links.is_empty() {
+            self.plain.push_str(&alt_text);
+            self.push(&MrkdwnBuilder::escape(&alt_text));
+            return;
+        }
+        match Url::parse(&url) {
+            Ok(url) => {
+                // Images get blocks of their own, so whatever text came
+                // before is finished.
+                let text = std::mem::take(&mut self.mrkdwn);
+                self.chunk(text.trim_end().to_string());
+                self.flush();
+                let mut image = ImageBuilder::new(url, alt_text);
+                if !title.is_empty() {
+                    image = image.set_title(PlainText::new(title));
+                }
+                self.blocks.push(Block::Image(image.build()));
+            }
+            // Slack can't fetch relative URLs, so these are left as links.
+            Err(_) => {
+                let url = MrkdwnBuilder::escape(&url).replace('|', "%7C");
+                let label = MrkdwnBuilder::escape(&alt_text);
+                self.push(&format!("<{}|{}>", url, label));
+            }
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if let Some((_, _, alt_text)) = &mut self.image {
+            alt_text.push_str(text);
+            return;
+        }
+        self.plain.push_str(text);
+        self.push(&MrkdwnBuilder::escape(text));
+    }
+
+    fn push(&mut self, mrkdwn: &str) {
+        if self.image.is_some() {
+            return;
+        }
+        // Top-level text outside any block, e.g. after an image, starts a
+        // paragraph of its own.
+        if self.depth == 0 && mrkdwn.trim().is_empty() {
+            return;
+        }
+        self.mrkdwn.push_str(mrkdwn);
+        if !self.in_code_block {
+            self.at_item_start = false;
+        }
+    }
+
+    fn chunk(&mut self, text: String) {
+        if !text.trim().is_empty() {
+            self.chunks.push(text);
+        }
+    }
+
+    // Turns the chunks gathered so far into as few sections as will fit.
+    fn flush(&mut self) {
+        let mut section = String::new();
+        for chunk in self.chunks.drain(..) {
+            for piece in split_mrkdwn(&chunk, MAX_SECTION_TEXT_LENGTH) {
+                let length = section.chars().count() + 2 + piece.chars().count();
+                if !section.is_empty() && length > MAX_SECTION_TEXT_LENGTH {
+                    self.blocks
+                        .push(section_block(std::mem::take(&mut section)));
+                }
+                if !section.is_empty() {
+                    section.push_str("\n\n");
+                }
+                section.push_str(&piece);
+            }
+        }
+        if !section.is_empty() {
+            self.blocks.push(section_block(section));
+        }
+    }
+}
+
+fn section_block(text: String) -> Block {
+    Block::Section(SectionBuilder::new(Mrkdwn::new(text).into()).build())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::surfaces::MessageBlock;
+
+    fn json(markdown: &str) -> String {
+        serde_json::to_string(&to_blocks::<MessageBlock>(markdown)).unwrap()
+    }
+
+    #[test]
+    fn blocks() {
+        let markdown = "# Release notes\n\nThis *fixes* `a < b`, see [the issue](https://example.com/1).\n\n---\n\n![Graph](https://example.com/graph.png \"Latency\")\n";
+        assert_eq!(
+            json(markdown),
+            r#"[{"type":"header","text":{"type":"plain_text","text":"Release notes"}},{"type":"section","text":{"type":"mrkdwn","text":"This _fixes_ `a &lt; b`, see <https://example.com/1|the issue>."}},{"type":"divider"},{"type":"image","image_url":"https://example.com/graph.png","alt_text":"Graph","title":{"type":"plain_text","text":"Latency"}}]"#
+        );
+    }
+
+    #[test]
+    fn mrkdwn() {
+        let markdown = "### Steps\n\n1. Drain **all** nodes\n2. Restart\n   - api\n   - worker\n\n> Don't skip\n> this\n\n```sh\ncargo run\n```\n";
+        let blocks = to_blocks::<MessageBlock>(markdown);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(
+            serde_json::to_value(&blocks[0]).unwrap()["text"]["text"],
+            "*Steps*\n\n1. Drain *all* nodes\n2. Restart\n    • api\n    • worker\n\n>Don't skip this\n\n```\ncargo run\n```"
+        );
+    }
+
+    #[test]
+    fn images_in_links() {
+        assert_eq!(
+            json("See [![logo](https://e.com/l.png)](https://e.com) and [![](https://e.com/l.png)](https://e.com)."),
+            r#"[{"type":"section","text":{"type":"mrkdwn","text":"See <https://e.com|logo> and <https://e.com|https://e.com>."}}]"#
+        );
+    }
+
+    #[test]
+    fn empty_headings() {
+        assert_eq!(
+            json(
+                "#
+
+Text
+
+###"
+            ),
+            r#"[{"type":"section","text":{"type":"mrkdwn","text":"Text"}}]"#
+        );
+    }
+
+    #[test]
+    fn long_sections_split() {
+        let paragraph = "word ".repeat(500);
+        let markdown = [paragraph.trim(); 3].join("\n\n");
+        let blocks = to_blocks::<MessageBlock>(&markdown);
+        assert_eq!(blocks.len(), 3);
+
+        let code = format!("```\n{}```\n", "let x = 1;\n".repeat(400));
+        let blocks = to_blocks::<MessageBlock>(&code);
+        assert_eq!(blocks.len(), 2);
+        for block in &blocks {
+            let text = serde_json::to_value(block).unwrap()["text"]["text"].clone();
+            let text = text.as_str().unwrap();
+            assert!(text.chars().count() <= MAX_SECTION_TEXT_LENGTH);
+            assert!(text.starts_with("```\n") && text.ends_with("\n```"));
+        }
+    }
+}
@@ -1,19 +1,45 @@
-mod text;
+mod mrkdwn;
 mod option;
+pub mod rich_text;
+mod text;
 
-pub use crate::objects::text::{FormattingType, Text, TextBuilder};
+pub use crate::objects::mrkdwn::MrkdwnBuilder;
 pub use crate::objects::option::{OptionInput, OptionInputBuilder, OptionInputGroup};
+pub use crate::objects::text::{FormattingType, Mrkdwn, PlainText, Text, TextBuilder};
+use crate::validation::{Validate, Validator};
+
+use serde::{Deserialize, Serialize, Serializer};
 
-use serde::{Serialize, Serializer};
+// A confirmation dialog's limits.
+const MAX_TITLE_LENGTH: usize = 100;
+const MAX_TEXT_LENGTH: usize = 300;
+const MAX_BUTTON_LENGTH: usize = 30;
 
 // Reference: https://api.slack.com/reference/block-kit/composition-objects#text
 
-#[derive(Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ConfirmationDialog {
-    title: Text,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_TITLE_LENGTH))
+    )]
+    title: PlainText,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_TEXT_LENGTH))
+    )]
     text: Text,
-    confirm: Text,
-    deny: Text,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_BUTTON_LENGTH))
+    )]
+    confirm: PlainText,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_BUTTON_LENGTH))
+    )]
+    deny: PlainText,
 }
 
 impl ConfirmationDialog {
@@ -24,14 +50,19 @@ impl ConfirmationDialog {
         U: Into<String>,
     {
         Self {
-            title: Text::builder(FormattingType::PlainText, title_text).build(),
+            title: PlainText::new(title_text),
             text,
-            confirm: Text::builder(FormattingType::PlainText, confirm_text).build(),
-            deny: Text::builder(FormattingType::PlainText, deny_text).build(),
+            confirm: PlainText::new(confirm_text),
+            deny: PlainText::new(deny_text),
         }
     }
 }
 
+// Composition objects carry no "type" of their own, so they're told apart by
+// the fields they require.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
 pub enum Object {
     Text(Text),
     ConfirmationDialog(ConfirmationDialog),
@@ -39,27 +70,27 @@ pub enum Object {
     OptionInputGroup(OptionInputGroup),
 }
 
-impl Into<Object> for Text {
-    fn into(self) -> Object {
-        Object::Text(self)
+impl From<Text> for Object {
+    fn from(e: Text) -> Self {
+        Object::Text(e)
     }
 }
 
-impl Into<Object> for ConfirmationDialog {
-    fn into(self) -> Object {
-        Object::ConfirmationDialog(self)
+impl From<ConfirmationDialog> for Object {
+    fn from(e: ConfirmationDialog) -> Self {
+        Object::ConfirmationDialog(e)
     }
 }
 
-impl Into<Object> for OptionInput {
-    fn into(self) -> Object {
-        Object::Option(self)
+impl From<OptionInput> for Object {
+    fn from(e: OptionInput) -> Self {
+        Object::Option(e)
     }
 }
 
-impl Into<Object> for OptionInputGroup {
-    fn into(self) -> Object {
-        Object::OptionInputGroup(self)
+impl From<OptionInputGroup> for Object {
+    fn from(e: OptionInputGroup) -> Self {
+        Object::OptionInputGroup(e)
     }
 }
 
@@ -77,6 +108,28 @@ impl Serialize for Object {
     }
 }
 
+impl Validate for ConfirmationDialog {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_text_length("title", &self.title, MAX_TITLE_LENGTH);
+        v.max_text_length("text", &self.text, MAX_TEXT_LENGTH);
+        v.max_text_length("confirm", &self.confirm, MAX_BUTTON_LENGTH);
+        v.max_text_length("deny", &self.deny, MAX_BUTTON_LENGTH);
+    }
+}
+
+impl Validate for Object {
+    fn validate_into(&self, v: &mut Validator) {
+        match self {
+            // Text limits depend on where it's used, so there's nothing to
+            // check on its own.
+            Object::Text(_) => {}
+            Object::ConfirmationDialog(e) => e.validate_into(v),
+            Object::Option(e) => e.validate_into(v),
+            Object::OptionInputGroup(e) => e.validate_into(v),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -103,7 +156,8 @@ mod test {
             Text::builder(FormattingType::Markdown, "testing").build(),
             "Yes",
             "No",
-        ).into();
+        )
+        .into();
 
         let json = serde_json::to_string(&dialog).unwrap();
         assert_eq!(
@@ -111,4 +165,28 @@ mod test {
             r#"{"title":{"type":"plain_text","text":"Confirm?"},"text":{"type":"mrkdwn","text":"testing"},"confirm":{"type":"plain_text","text":"Yes"},"deny":{"type":"plain_text","text":"No"}}"#
         );
     }
+
+    #[test]
+    fn object_deserialize() {
+        let json = r#"{"title":{"type":"plain_text","text":"Confirm?"},"text":{"type":"mrkdwn","text":"testing"},"confirm":{"type":"plain_text","text":"Yes"},"deny":{"type":"plain_text","text":"No"}}"#;
+        let object: Object = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            object,
+            Object::ConfirmationDialog(ConfirmationDialog::new(
+                "Confirm?",
+                Text::builder(FormattingType::Markdown, "testing").build(),
+                "Yes",
+                "No",
+            ))
+        );
+
+        let object: Object =
+            serde_json::from_str(r#"{"text":{"type":"plain_text","text":"Maru"},"value":"maru"}"#)
+                .unwrap();
+        assert!(matches!(object, Object::Option(_)));
+
+        let object: Object =
+            serde_json::from_str(r#"{"type":"plain_text","text":"Maru"}"#).unwrap();
+        assert!(matches!(object, Object::Text(_)));
+    }
 }
@@ -0,0 +1,292 @@
+// Reference: https://api.slack.com/reference/surfaces/formatting
+use crate::objects::{Mrkdwn, Text};
+
+/// Builds mrkdwn text a piece at a time, escaping anything interpolated into
+/// it so user input can't break the formatting or add mentions. Only `<`, `>`
+/// and `&` have escapes; `code`, `code_block` and the mentions also drop or
+/// break up the characters that would end them early.
+///
+/// Plain strings passed to any method are escaped; pass another builder to
+/// nest formatting, e.g. `Mrkdwn::builder().bold(Mrkdwn::builder().italic("both"))`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MrkdwnBuilder {
+    text: String,
+    verbatim: bool,
+}
+
+impl MrkdwnBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Escapes the characters Slack treats as control sequences.
+    ///
+    /// Slack has no escape for `*`, `_`, `~` or `` ` ``, so those are left as
+    /// they are.
+    pub fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                c => escaped.push(c),
+            }
+        }
+        escaped
+    }
+
+    /// Appends text as-is, without escaping it. Only use this for mrkdwn
+    /// that's already been checked.
+    pub fn raw<S: AsRef<str>>(mut self, mrkdwn: S) -> Self {
+        self.text.push_str(mrkdwn.as_ref());
+        self
+    }
+
+    pub fn text<M: Into<MrkdwnBuilder>>(self, text: M) -> Self {
+        self.raw(text.into().text)
+    }
+
+    fn wrap<M: Into<MrkdwnBuilder>>(self, marker: &str, text: M) -> Self {
+        let inner = text.into().text;
+        self.raw(marker).raw(inner).raw(marker)
+    }
+
+    pub fn bold<M: Into<MrkdwnBuilder>>(self, text: M) -> Self {
+        self.wrap("*", text)
+    }
+
+    pub fn italic<M: Into<MrkdwnBuilder>>(self, text: M) -> Self {
+        self.wrap("_", text)
+    }
+
+    pub fn strike<M: Into<MrkdwnBuilder>>(self, text: M) -> Self {
+        self.wrap("~", text)
+    }
+
+    // Formatting isn't applied inside code, so these always take plain text.
+    // Any "`" would close inline code, so those are dropped.
+    pub fn code<S: AsRef<str>>(self, code: S) -> Self {
+        let code = MrkdwnBuilder::escape(code.as_ref()).replace('`', "");
+        self.raw("`").raw(code).raw("`")
+    }
+
+    // Only "```" closes a code block, so a zero-width space goes between
+    // backticks that would otherwise run together.
+    pub fn code_block<S: AsRef<str>>(self, code: S) -> Self {
+        let mut broken = String::with_capacity(code.as_ref().len());
+        for c in MrkdwnBuilder::escape(code.as_ref()).chars() {
+            if c == '`' && broken.ends_with('`') {
+                broken.push('\u{200B}');
+            }
+            broken.push(c);
+        }
+        self.start_line().raw("```\n").raw(broken).raw("\n```\n")
+    }
+
+    /// Starts a new line, unless we're already at the start of one.
+    fn start_line(self) -> Self {
+        if self.text.is_empty() || self.text.ends_with('\n') {
+            self
+        } else {
+            self.raw("\n")
+        }
+    }
+
+    pub fn newline(self) -> Self {
+        self.raw("\n")
+    }
+
+    /// Appends a block quote on its own lines.
+    pub fn quote<M: Into<MrkdwnBuilder>>(self, text: M) -> Self {
+        let inner = text.into().text;
+        let mut out = self.start_line();
+        for line in inner.lines() {
+            out = out.raw(">").raw(line).raw("\n");
+        }
+        out
+    }
+
+    pub fn link<S: AsRef<str>, M: Into<MrkdwnBuilder>>(self, url: S, label: M) -> Self {
+        // A "|" in the URL would be read as the start of the label.
+        let url = MrkdwnBuilder::escape(url.as_ref()).replace('|', "%7C");
+        let label = label.into().text;
+        self.raw("<").raw(url).raw("|").raw(label).raw(">")
+    }
+
+    // Ids never contain "|", which would start a label, so it's dropped like
+    // it is from date formats.
+    pub fn user<S: AsRef<str>>(self, user_id: S) -> Self {
+        let id = MrkdwnBuilder::escape(user_id.as_ref()).replace('|', "");
+        self.raw("<@").raw(id).raw(">")
+    }
+
+    pub fn channel<S: AsRef<str>>(self, channel_id: S) -> Self {
+        let id = MrkdwnBuilder::escape(channel_id.as_ref()).replace('|', "");
+        self.raw("<#").raw(id).raw(">")
+    }
+
+    pub fn usergroup<S: AsRef<str>>(self, usergroup_id: S) -> Self {
+        let id = MrkdwnBuilder::escape(usergroup_id.as_ref()).replace('|', "");
+        self.raw("<!subteam^").raw(id).raw(">")
+    }
+
+    /// A date shown in the reader's timezone. `format` uses Slack's tokens,
+    /// e.g. "{date_short} at {time}", and `fallback` is shown to clients that
+    /// can't render it.
+    pub fn date<S: AsRef<str>, T: AsRef<str>>(
+        self,
+        timestamp: i64,
+        format: S,
+        fallback: T,
+    ) -> Self {
+        let format = MrkdwnBuilder::escape(format.as_ref()).replace('|', "");
+        let fallback = MrkdwnBuilder::escape(fallback.as_ref());
+        self.raw(format!("<!date^{}^{}|{}>", timestamp, format, fallback))
+    }
+
+    /// Appends a bulleted list, one item per line.
+    pub fn bullets<I, M>(self, items: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+        M: Into<MrkdwnBuilder>,
+    {
+        let mut out = self.start_line();
+        for item in items {
+            out = out.raw("• ").raw(item.into().text).raw("\n");
+        }
+        out
+    }
+
+    /// Appends a numbered list, one item per line.
+    pub fn numbered<I, M>(self, items: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+        M: Into<MrkdwnBuilder>,
+    {
+        let mut out = self.start_line();
+        for (idx, item) in items.into_iter().enumerate() {
+            out = out
+                .raw(format!("{}. ", idx + 1))
+                .raw(item.into().text)
+                .raw("\n");
+        }
+        out
+    }
+
+    /// Shows URLs, channel names and mentions exactly as written, rather
+    /// than auto-linking them.
+    pub fn set_verbatim(mut self, verbatim: bool) -> Self {
+        self.verbatim = verbatim;
+        self
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn build(self) -> Mrkdwn {
+        if self.verbatim {
+            Mrkdwn::new_verbatim(self.text)
+        } else {
+            Mrkdwn::new(self.text)
+        }
+    }
+}
+
+impl From<&str> for MrkdwnBuilder {
+    fn from(text: &str) -> Self {
+        MrkdwnBuilder {
+            text: MrkdwnBuilder::escape(text),
+            verbatim: false,
+        }
+    }
+}
+
+impl From<String> for MrkdwnBuilder {
+    fn from(text: String) -> Self {
+        MrkdwnBuilder::from(text.as_str())
+    }
+}
+
+impl From<MrkdwnBuilder> for Mrkdwn {
+    fn from(e: MrkdwnBuilder) -> Self {
+        e.build()
+    }
+}
+
+impl From<MrkdwnBuilder> for Text {
+    fn from(e: MrkdwnBuilder) -> Self {
+        Text::Mrkdwn(e.build())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn escaping() {
+        let text = Mrkdwn::builder()
+            .text("Hi ")
+            .user("U12345")
+            .text(", <!channel> & friends")
+            .build();
+        assert_eq!(
+            serde_json::to_string(&text).unwrap().as_str(),
+            r#"{"type":"mrkdwn","text":"Hi <@U12345>, &lt;!channel&gt; &amp; friends"}"#
+        );
+    }
+
+    #[test]
+    fn formatting() {
+        let text = Mrkdwn::builder()
+            .bold(Mrkdwn::builder().italic("both"))
+            .text(" ")
+            .strike("gone")
+            .text(" ")
+            .code("a<b")
+            .text(" ")
+            .link("https://example.com/?a=1&b=2", "a <link>")
+            .text(" ")
+            .channel("C12345")
+            .text(" ")
+            .usergroup("S12345")
+            .text(" ")
+            .date(1392734382, "{date_short} at {time}", "Feb 18, 2014");
+        assert_eq!(
+            text.as_str(),
+            "*_both_* ~gone~ `a&lt;b` <https://example.com/?a=1&amp;b=2|a &lt;link&gt;> <#C12345> <!subteam^S12345> <!date^1392734382^{date_short} at {time}|Feb 18, 2014>"
+        );
+    }
+
+    #[test]
+    fn blocks() {
+        let text = Mrkdwn::builder()
+            .text("Release notes:")
+            .bullets(vec!["Faster", "Smaller"])
+            .quote("one\ntwo")
+            .numbered(vec![Mrkdwn::builder().bold("first")])
+            .code_block("let x = 1;");
+        assert_eq!(
+            text.as_str(),
+            "Release notes:\n• Faster\n• Smaller\n>one\n>two\n1. *first*\n```\nlet x = 1;\n```\n"
+        );
+    }
+
+    #[test]
+    fn closing_markers() {
+        let text = Mrkdwn::builder()
+            .code("a`b")
+            .code_block("```\nrun ````")
+            .user("U1|admin")
+            .text(" ")
+            .channel("C1|general")
+            .text(" ")
+            .usergroup("S1|team");
+        assert_eq!(
+            text.as_str(),
+            "`ab`\n```\n`\u{200B}`\u{200B}`\nrun `\u{200B}`\u{200B}`\u{200B}`\n```\n<@U1admin> <#C1general> <!subteam^S1team>"
+        );
+    }
+}
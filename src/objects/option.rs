@@ -1,33 +1,80 @@
-use serde::ser::SerializeMap;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Serialize};
 pub use url::Url;
 
-pub use crate::objects::{FormattingType, Text, TextBuilder};
-//
+use crate::limits::{MAX_MENU_OPTIONS, MAX_URL_LENGTH};
+use crate::objects::{PlainText, Text};
+use crate::validation::{Validate, Validator};
+use crate::Error;
+
+// For an option's text and description, and a group's label.
+const MAX_TEXT_LENGTH: usize = 75;
+const MAX_VALUE_LENGTH: usize = 150;
+
 // TODO: This is only available in overflow menus, is there something we can
 // to do make this compile-time safe?
-pub struct OptionInput {
-    text: Text,
+//
+// Select, multi-select and overflow menus only accept plain text options, so
+// they take an `OptionInput<PlainText>`. Checkboxes and radio buttons also
+// accept mrkdwn, so they take the default `OptionInput<Text>`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "{T}Option")
+)]
+pub struct OptionInput<T = Text> {
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_TEXT_LENGTH))
+    )]
+    text: T,
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_VALUE_LENGTH)))]
     value: String,
-    description: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_TEXT_LENGTH))
+    )]
+    description: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_URL_LENGTH)))]
     url: Option<Url>,
 }
 
-impl OptionInput {
-    pub fn builder<S: Into<String>>(text: Text, value: S) -> OptionInputBuilder {
+impl<T> OptionInput<T> {
+    pub fn builder<S: Into<String>>(text: T, value: S) -> OptionInputBuilder<T> {
         OptionInputBuilder::new(text, value)
     }
+
+    pub fn text(&self) -> &T {
+        &self.text
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl From<OptionInput<PlainText>> for OptionInput {
+    fn from(e: OptionInput<PlainText>) -> Self {
+        OptionInput {
+            text: e.text.into(),
+            value: e.value,
+            description: e.description.map(Into::into),
+            url: e.url,
+        }
+    }
 }
 
-pub struct OptionInputBuilder {
-    text: Text,
+pub struct OptionInputBuilder<T = Text> {
+    text: T,
     value: String,
-    description: Option<Text>,
+    description: Option<T>,
     url: Option<Url>,
 }
 
-impl OptionInputBuilder {
-    pub fn new<S: Into<String>>(text: Text, value: S) -> Self {
+impl<T> OptionInputBuilder<T> {
+    pub fn new<S: Into<String>>(text: T, value: S) -> Self {
         Self {
             text,
             value: value.into(),
@@ -36,7 +83,7 @@ impl OptionInputBuilder {
         }
     }
 
-    pub fn set_description(mut self, desc: Text) -> Self {
+    pub fn set_description(mut self, desc: T) -> Self {
         self.description = Some(desc);
         self
     }
@@ -46,7 +93,7 @@ impl OptionInputBuilder {
         self
     }
 
-    pub fn build(self) -> OptionInput {
+    pub fn build(self) -> OptionInput<T> {
         OptionInput {
             text: self.text,
             value: self.value,
@@ -54,56 +101,69 @@ impl OptionInputBuilder {
             url: self.url,
         }
     }
-}
 
-impl Serialize for OptionInput {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    pub fn try_build(self) -> Result<OptionInput<T>, Error>
     where
-        S: Serializer,
+        T: AsRef<str>,
     {
-        let mut size = 1;
-        if self.description.is_some() {
-            size += 1;
-        }
+        let option_input = self.build();
+        option_input.validate()?;
+        Ok(option_input)
+    }
+}
 
-        if self.url.is_some() {
-            size += 1;
-        }
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OptionInputGroup {
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_TEXT_LENGTH))
+    )]
+    label: PlainText,
+    #[cfg_attr(feature = "schema", schemars(length(min = 1, max = MAX_MENU_OPTIONS)))]
+    options: Vec<OptionInput<PlainText>>,
+}
 
-        let mut map = serializer.serialize_map(Some(size))?;
+impl OptionInputGroup {
+    pub fn new(label: PlainText, options: Vec<OptionInput<PlainText>>) -> Self {
+        Self { label, options }
+    }
 
-        map.serialize_entry("text", &self.text)?;
-        map.serialize_entry("value", &self.value)?;
+    pub fn label(&self) -> &PlainText {
+        &self.label
+    }
 
-        if let Some(p) = &self.description {
-            map.serialize_entry("description", &p)?;
-        }
-        if let Some(u) = &self.url {
-            map.serialize_entry("url", u.as_str())?;
-        }
-        map.end()
+    pub fn options(&self) -> &[OptionInput<PlainText>] {
+        &self.options
     }
 }
 
-#[derive(Serialize)]
-pub struct OptionInputGroup {
-    label: Text,
-    options: Vec<OptionInput>,
+impl<T: AsRef<str>> Validate for OptionInput<T> {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_text_length("text", &self.text, MAX_TEXT_LENGTH);
+        v.max_length("value", &self.value, MAX_VALUE_LENGTH);
+        v.optional_max_text_length("description", &self.description, MAX_TEXT_LENGTH);
+        v.optional_max_length("url", &self.url, MAX_URL_LENGTH);
+    }
 }
 
-impl OptionInputGroup {
-    pub fn new(label: Text, options: Vec<OptionInput>) -> Self {
-        Self { label, options }
+impl Validate for OptionInputGroup {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_text_length("label", &self.label, MAX_TEXT_LENGTH);
+        v.item_count("options", self.options.len(), 1, MAX_MENU_OPTIONS);
+        v.items("options", &self.options);
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::objects::FormattingType;
+    use crate::validation::Validate;
 
     #[test]
     fn basic() {
-        let text = Text::builder(FormattingType::PlainText, "Maru").build();
+        let text = PlainText::new("Maru");
         let option = OptionInput::builder(text, "maru").build();
         let json = serde_json::to_string(&option).unwrap();
         assert_eq!(
@@ -115,7 +175,7 @@ mod test {
     #[test]
     fn all() {
         let text = Text::builder(FormattingType::Markdown, "Maru").build();
-        let desc = Text::builder(FormattingType::PlainText, "A test option").build();
+        let desc = PlainText::new("A test option").into();
         let option = OptionInput::builder(text, "maru")
             .set_url(
                 "https://slack.example.com/redirect?code=123"
@@ -130,4 +190,35 @@ mod test {
             r#"{"text":{"type":"mrkdwn","text":"Maru"},"value":"maru","description":{"type":"plain_text","text":"A test option"},"url":"https://slack.example.com/redirect?code=123"}"#
         );
     }
+
+    #[test]
+    fn round_trip() {
+        let json = r#"{"text":{"type":"mrkdwn","text":"Maru"},"value":"maru","description":{"type":"plain_text","text":"A test option"},"url":"https://slack.example.com/redirect?code=123"}"#;
+        let option: OptionInput = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&option).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn group_round_trip() {
+        let json = r#"{"label":{"type":"plain_text","text":"Group 1"},"options":[{"text":{"type":"plain_text","text":"Maru"},"value":"maru"}]}"#;
+        let group: OptionInputGroup = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            group,
+            OptionInputGroup::new(
+                PlainText::new("Group 1"),
+                vec![OptionInput::builder(PlainText::new("Maru"), "maru").build()],
+            )
+        );
+        assert_eq!(serde_json::to_string(&group).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn validate_text_length() {
+        let option = OptionInput::builder(PlainText::new("x".repeat(76)), "value").build();
+        let errors = option.validate().unwrap_err();
+        assert_eq!(
+            errors.to_string(),
+            "text: must be at most 75 characters long, but is 76"
+        );
+    }
 }
@@ -0,0 +1,546 @@
+// Reference: https://api.slack.com/reference/block-kit/blocks#rich_text
+//
+// The tree of formatted text used by `RichText` blocks and `RichTextInput`
+// elements. This is also what Slack sends back for messages written in the
+// composer, so every node round-trips.
+use crate::de;
+use crate::validation::{Validate, Validator};
+use crate::Error;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "RichTextSection", transform = crate::schema::tagged("rich_text_section"))
+)]
+#[serde(tag = "type", rename = "rich_text_section")]
+pub struct RichTextSection {
+    pub elements: Vec<RichTextInline>,
+}
+
+impl RichTextSection {
+    pub fn new(elements: Vec<RichTextInline>) -> Self {
+        Self { elements }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "RichTextList", transform = crate::schema::tagged("rich_text_list"))
+)]
+#[serde(tag = "type", rename = "rich_text_list")]
+pub struct RichTextList {
+    pub style: ListStyle,
+    // Each section is one item in the list.
+    pub elements: Vec<RichTextSection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border: Option<u32>,
+}
+
+impl RichTextList {
+    pub fn new(style: ListStyle, elements: Vec<RichTextSection>) -> Self {
+        RichTextListBuilder::new(style).set_items(elements).build()
+    }
+
+    pub fn builder(style: ListStyle) -> RichTextListBuilder {
+        RichTextListBuilder::new(style)
+    }
+}
+
+pub struct RichTextListBuilder {
+    style: ListStyle,
+    elements: Vec<RichTextSection>,
+    indent: Option<u32>,
+    offset: Option<u32>,
+    border: Option<u32>,
+}
+
+impl RichTextListBuilder {
+    pub fn new(style: ListStyle) -> Self {
+        Self {
+            style,
+            elements: Vec::new(),
+            indent: None,
+            offset: None,
+            border: None,
+        }
+    }
+
+    pub fn add_item(mut self, item: Vec<RichTextInline>) -> Self {
+        self.elements.push(RichTextSection::new(item));
+        self
+    }
+
+    pub fn set_items(mut self, items: Vec<RichTextSection>) -> Self {
+        self.elements = items;
+        self
+    }
+
+    pub fn set_indent(mut self, indent: u32) -> Self {
+        self.indent = Some(indent);
+        self
+    }
+
+    pub fn set_offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn set_border(mut self, border: u32) -> Self {
+        self.border = Some(border);
+        self
+    }
+
+    pub fn build(self) -> RichTextList {
+        RichTextList {
+            style: self.style,
+            elements: self.elements,
+            indent: self.indent,
+            offset: self.offset,
+            border: self.border,
+        }
+    }
+
+    pub fn try_build(self) -> Result<RichTextList, Error> {
+        let list = self.build();
+        list.validate()?;
+        Ok(list)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ListStyle {
+    Bullet,
+    Ordered,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "RichTextQuote", transform = crate::schema::tagged("rich_text_quote"))
+)]
+#[serde(tag = "type", rename = "rich_text_quote")]
+pub struct RichTextQuote {
+    pub elements: Vec<RichTextInline>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border: Option<u32>,
+}
+
+impl RichTextQuote {
+    pub fn new(elements: Vec<RichTextInline>) -> Self {
+        Self {
+            elements,
+            border: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(
+        rename = "RichTextPreformatted",
+        transform = crate::schema::tagged("rich_text_preformatted")
+    )
+)]
+#[serde(tag = "type", rename = "rich_text_preformatted")]
+pub struct RichTextPreformatted {
+    pub elements: Vec<RichTextInline>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border: Option<u32>,
+}
+
+impl RichTextPreformatted {
+    pub fn new(elements: Vec<RichTextInline>) -> Self {
+        Self {
+            elements,
+            border: None,
+        }
+    }
+}
+
+impl From<RichTextSection> for RichTextElement {
+    fn from(e: RichTextSection) -> Self {
+        RichTextElement::Section(e)
+    }
+}
+
+impl From<RichTextList> for RichTextElement {
+    fn from(e: RichTextList) -> Self {
+        RichTextElement::List(e)
+    }
+}
+
+impl From<RichTextQuote> for RichTextElement {
+    fn from(e: RichTextQuote) -> Self {
+        RichTextElement::Quote(e)
+    }
+}
+
+impl From<RichTextPreformatted> for RichTextElement {
+    fn from(e: RichTextPreformatted) -> Self {
+        RichTextElement::Preformatted(e)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema), schemars(untagged))]
+pub enum RichTextElement {
+    Section(RichTextSection),
+    List(RichTextList),
+    Quote(RichTextQuote),
+    Preformatted(RichTextPreformatted),
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    Unknown(Value),
+}
+
+impl Serialize for RichTextElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            RichTextElement::Section(e) => e.serialize(serializer),
+            RichTextElement::List(e) => e.serialize(serializer),
+            RichTextElement::Quote(e) => e.serialize(serializer),
+            RichTextElement::Preformatted(e) => e.serialize(serializer),
+            RichTextElement::Unknown(e) => e.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RichTextElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (kind, value) = de::tagged(deserializer)?;
+        match kind.as_str() {
+            "rich_text_section" => de::from_value(value).map(RichTextElement::Section),
+            "rich_text_list" => de::from_value(value).map(RichTextElement::List),
+            "rich_text_quote" => de::from_value(value).map(RichTextElement::Quote),
+            "rich_text_preformatted" => de::from_value(value).map(RichTextElement::Preformatted),
+            _ => Ok(RichTextElement::Unknown(value)),
+        }
+    }
+}
+
+/// The text and entities that make up a section, quote or preformatted block.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case", remote = "Self")]
+pub enum RichTextInline {
+    Text {
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    Link {
+        url: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+        #[serde(rename = "unsafe", skip_serializing_if = "Option::is_none")]
+        is_unsafe: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    Emoji {
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        unicode: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        skin_tone: Option<u8>,
+    },
+    User {
+        user_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    Channel {
+        channel_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    Usergroup {
+        usergroup_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    Team {
+        team_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    Broadcast {
+        range: BroadcastRange,
+    },
+    Date {
+        timestamp: i64,
+        format: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        fallback: Option<String>,
+    },
+    Color {
+        value: String,
+    },
+    #[serde(skip)]
+    Unknown(Value),
+}
+
+const RICH_TEXT_INLINE_TYPES: &[&str] = &[
+    "text",
+    "link",
+    "emoji",
+    "user",
+    "channel",
+    "usergroup",
+    "team",
+    "broadcast",
+    "date",
+    "color",
+];
+
+// The derived impls, which `remote = "Self"` makes inherent, handle every
+// type but `Unknown`.
+impl Serialize for RichTextInline {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            RichTextInline::Unknown(e) => e.serialize(serializer),
+            known => RichTextInline::serialize(known, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RichTextInline {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match de::known(deserializer, RICH_TEXT_INLINE_TYPES)? {
+            Ok(value) => RichTextInline::deserialize(value).map_err(de::Error::custom),
+            Err(value) => Ok(RichTextInline::Unknown(value)),
+        }
+    }
+}
+
+impl RichTextInline {
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        RichTextInline::Text {
+            text: text.into(),
+            style: None,
+        }
+    }
+
+    pub fn link<S: Into<String>>(url: S) -> Self {
+        RichTextInline::Link {
+            url: url.into(),
+            text: None,
+            is_unsafe: None,
+            style: None,
+        }
+    }
+
+    pub fn named_link<S: Into<String>, T: Into<String>>(url: S, text: T) -> Self {
+        RichTextInline::Link {
+            url: url.into(),
+            text: Some(text.into()),
+            is_unsafe: None,
+            style: None,
+        }
+    }
+
+    pub fn emoji<S: Into<String>>(name: S) -> Self {
+        RichTextInline::Emoji {
+            name: name.into(),
+            unicode: None,
+            skin_tone: None,
+        }
+    }
+
+    pub fn user<S: Into<String>>(user_id: S) -> Self {
+        RichTextInline::User {
+            user_id: user_id.into(),
+            style: None,
+        }
+    }
+
+    pub fn channel<S: Into<String>>(channel_id: S) -> Self {
+        RichTextInline::Channel {
+            channel_id: channel_id.into(),
+            style: None,
+        }
+    }
+
+    pub fn usergroup<S: Into<String>>(usergroup_id: S) -> Self {
+        RichTextInline::Usergroup {
+            usergroup_id: usergroup_id.into(),
+            style: None,
+        }
+    }
+
+    pub fn broadcast(range: BroadcastRange) -> Self {
+        RichTextInline::Broadcast { range }
+    }
+
+    /// This element's style, if it's a kind that can be styled.
+    pub fn style(&self) -> Option<&RichTextStyle> {
+        match self {
+            RichTextInline::Text { style, .. }
+            | RichTextInline::Link { style, .. }
+            | RichTextInline::User { style, .. }
+            | RichTextInline::Channel { style, .. }
+            | RichTextInline::Usergroup { style, .. }
+            | RichTextInline::Team { style, .. } => style.as_ref(),
+            _ => None,
+        }
+    }
+
+    // Emoji, broadcasts, dates, colors and unknown elements can't be styled,
+    // so these are no-ops for them.
+    fn map_style<F: FnOnce(&mut RichTextStyle)>(mut self, f: F) -> Self {
+        match &mut self {
+            RichTextInline::Text { style, .. }
+            | RichTextInline::Link { style, .. }
+            | RichTextInline::User { style, .. }
+            | RichTextInline::Channel { style, .. }
+            | RichTextInline::Usergroup { style, .. }
+            | RichTextInline::Team { style, .. } => f(style.get_or_insert_with(Default::default)),
+            _ => {}
+        }
+        self
+    }
+
+    pub fn bold(self) -> Self {
+        self.map_style(|s| s.bold = Some(true))
+    }
+
+    pub fn italic(self) -> Self {
+        self.map_style(|s| s.italic = Some(true))
+    }
+
+    pub fn strike(self) -> Self {
+        self.map_style(|s| s.strike = Some(true))
+    }
+
+    pub fn code(self) -> Self {
+        self.map_style(|s| s.code = Some(true))
+    }
+}
+
+impl From<&str> for RichTextInline {
+    fn from(text: &str) -> Self {
+        RichTextInline::text(text)
+    }
+}
+
+impl From<String> for RichTextInline {
+    fn from(text: String) -> Self {
+        RichTextInline::text(text)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RichTextStyle {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bold: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub italic: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strike: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<bool>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum BroadcastRange {
+    Here,
+    Channel,
+    Everyone,
+}
+
+impl Validate for RichTextElement {
+    fn validate_into(&self, v: &mut Validator) {
+        if let RichTextElement::List(e) = self {
+            e.validate_into(v);
+        }
+    }
+}
+
+impl Validate for RichTextList {
+    fn validate_into(&self, v: &mut Validator) {
+        // Slack doesn't limit how long a list can be, only that it isn't empty.
+        v.item_count("elements", self.elements.len(), 1, usize::MAX);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn inline_styles() {
+        let bold_code = RichTextInline::text("cargo test").bold().code();
+        assert_eq!(
+            serde_json::to_string(&bold_code).unwrap().as_str(),
+            r#"{"type":"text","text":"cargo test","style":{"bold":true,"code":true}}"#
+        );
+        let emoji = RichTextInline::emoji("wave").bold();
+        assert_eq!(emoji.style(), None);
+    }
+
+    #[test]
+    fn list_builder() {
+        let list = RichTextList::builder(ListStyle::Ordered)
+            .add_item(vec!["first".into()])
+            .add_item(vec!["second ".into(), RichTextInline::user("U12345")])
+            .set_indent(1)
+            .build();
+        assert_eq!(
+            serde_json::to_string(&list).unwrap().as_str(),
+            r#"{"type":"rich_text_list","style":"ordered","elements":[{"type":"rich_text_section","elements":[{"type":"text","text":"first"}]},{"type":"rich_text_section","elements":[{"type":"text","text":"second "},{"type":"user","user_id":"U12345"}]}],"indent":1}"#
+        );
+    }
+
+    #[test]
+    fn list_try_build() {
+        let result = RichTextList::builder(ListStyle::Bullet).try_build();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid payload: elements: must have at least 1 items, but has 0"
+        );
+        assert!(RichTextList::builder(ListStyle::Bullet)
+            .add_item(vec!["item".into()])
+            .try_build()
+            .is_ok());
+    }
+
+    #[test]
+    fn composer_round_trip() {
+        // As sent back for a message typed into Slack's composer.
+        let json = r#"[{"type":"rich_text_section","elements":[{"type":"text","text":"Hey "},{"type":"usergroup","usergroup_id":"S0614TZR7"},{"type":"text","text":" see "},{"type":"link","url":"https://example.com","text":"this","unsafe":true},{"type":"emoji","name":"+1","unicode":"1f44d","skin_tone":2},{"type":"broadcast","range":"channel"}]},{"type":"rich_text_list","style":"bullet","elements":[{"type":"rich_text_section","elements":[{"type":"text","text":"done","style":{"strike":true}}]}],"indent":0,"border":0},{"type":"rich_text_quote","elements":[{"type":"channel","channel_id":"C12345","style":{"italic":true}}]},{"type":"rich_text_preformatted","elements":[{"type":"text","text":"fn main() {}"}],"border":0}]"#;
+        let elements: Vec<RichTextElement> = serde_json::from_str(json).unwrap();
+        assert_eq!(elements.len(), 4);
+        assert_eq!(serde_json::to_string(&elements).unwrap().as_str(), json);
+    }
+}
@@ -1,20 +1,243 @@
-use serde::{Serialize, Serializer};
+use crate::de;
+use crate::objects::MrkdwnBuilder;
 
-#[derive(Serialize)]
-pub struct Text {
-    #[serde(rename(serialize = "type"))]
-    formatting_type: FormattingType,
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
+
+/// Text shown as-is. Many fields only accept this, e.g. button labels and
+/// modal titles.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename = "plain_text", try_from = "TextFields")]
+pub struct PlainText {
     text: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     emoji: Option<bool>,
+}
+
+impl PlainText {
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Self {
+            text: text.into(),
+            emoji: None,
+        }
+    }
+
+    /// Plain text where `:emoji:` codes are shown as emoji, or explicitly
+    /// left as text.
+    pub fn new_with_emoji<S: Into<String>>(text: S, emoji: bool) -> Self {
+        Self {
+            text: text.into(),
+            emoji: Some(emoji),
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Text formatted with Slack's mrkdwn. See `MrkdwnBuilder` for building it
+/// from untrusted input.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename = "mrkdwn", try_from = "TextFields")]
+pub struct Mrkdwn {
+    text: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     verbatim: Option<bool>,
 }
 
+impl Mrkdwn {
+    /// Mrkdwn from a string that's already formatted. Nothing is escaped.
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Self {
+            text: text.into(),
+            verbatim: None,
+        }
+    }
+
+    /// Mrkdwn where URLs, channel names and mentions are shown exactly as
+    /// written, rather than being auto-linked.
+    pub fn new_verbatim<S: Into<String>>(text: S) -> Self {
+        Self {
+            text: text.into(),
+            verbatim: Some(true),
+        }
+    }
+
+    pub fn builder() -> MrkdwnBuilder {
+        MrkdwnBuilder::new()
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl From<&str> for PlainText {
+    fn from(e: &str) -> Self {
+        PlainText::new(e)
+    }
+}
+
+impl From<String> for PlainText {
+    fn from(e: String) -> Self {
+        PlainText::new(e)
+    }
+}
+
+impl From<PlainText> for Text {
+    fn from(e: PlainText) -> Self {
+        Text::PlainText(e)
+    }
+}
+
+impl From<Mrkdwn> for Text {
+    fn from(e: Mrkdwn) -> Self {
+        Text::Mrkdwn(e)
+    }
+}
+
+/// Either kind of text, for fields that accept both.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema), schemars(untagged))]
+pub enum Text {
+    PlainText(PlainText),
+    Mrkdwn(Mrkdwn),
+}
+
 impl Text {
     pub fn builder<S: Into<String>>(formatting_type: FormattingType, text: S) -> TextBuilder {
         TextBuilder::new(formatting_type, text)
     }
+
+    pub fn formatting_type(&self) -> FormattingType {
+        match self {
+            Text::PlainText(_) => FormattingType::PlainText,
+            Text::Mrkdwn(_) => FormattingType::Markdown,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        match self {
+            Text::PlainText(e) => e.text(),
+            Text::Mrkdwn(e) => e.text(),
+        }
+    }
+
+    // The same kind of text, with the same options, but different content.
+    pub(crate) fn with_text(&self, text: String) -> Text {
+        match self {
+            Text::PlainText(e) => Text::PlainText(PlainText { text, ..e.clone() }),
+            Text::Mrkdwn(e) => Text::Mrkdwn(Mrkdwn { text, ..e.clone() }),
+        }
+    }
+}
+
+impl Serialize for Text {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Text::PlainText(e) => e.serialize(serializer),
+            Text::Mrkdwn(e) => e.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Text {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (kind, value) = de::tagged(deserializer)?;
+        match kind.as_str() {
+            "plain_text" => de::from_value(value).map(Text::PlainText),
+            "mrkdwn" => de::from_value(value).map(Text::Mrkdwn),
+            other => Err(de::unknown_type(other, "text")),
+        }
+    }
+}
+
+// Serde doesn't check a struct's own "type" tag when deserializing, so both
+// kinds go through this to reject the other one.
+#[derive(Deserialize)]
+struct TextFields {
+    #[serde(rename = "type")]
+    kind: FormattingType,
+    text: String,
+    emoji: Option<bool>,
+    verbatim: Option<bool>,
+}
+
+impl TryFrom<TextFields> for PlainText {
+    type Error = String;
+
+    fn try_from(e: TextFields) -> Result<Self, Self::Error> {
+        match e.kind {
+            FormattingType::PlainText => Ok(PlainText {
+                text: e.text,
+                emoji: e.emoji,
+            }),
+            FormattingType::Markdown => Err("expected plain_text, but got mrkdwn".to_string()),
+        }
+    }
+}
+
+impl TryFrom<TextFields> for Mrkdwn {
+    type Error = String;
+
+    fn try_from(e: TextFields) -> Result<Self, Self::Error> {
+        match e.kind {
+            FormattingType::Markdown => Ok(Mrkdwn {
+                text: e.text,
+                verbatim: e.verbatim,
+            }),
+            FormattingType::PlainText => Err("expected mrkdwn, but got plain_text".to_string()),
+        }
+    }
+}
+
+// Derived schemas would describe `TextFields`, which takes either kind.
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for PlainText {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "PlainText".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::schema::text_object("plain_text", "emoji")
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Mrkdwn {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Mrkdwn".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        crate::schema::text_object("mrkdwn", "verbatim")
+    }
+}
+
+// Validation only needs the content of a text object, whatever its kind.
+impl AsRef<str> for PlainText {
+    fn as_ref(&self) -> &str {
+        self.text()
+    }
+}
+
+impl AsRef<str> for Mrkdwn {
+    fn as_ref(&self) -> &str {
+        self.text()
+    }
+}
+
+impl AsRef<str> for Text {
+    fn as_ref(&self) -> &str {
+        self.text()
+    }
 }
 
 pub struct TextBuilder {
@@ -34,43 +257,40 @@ impl TextBuilder {
         }
     }
 
+    /// Only applies to plain text.
     pub fn set_emoji(mut self, v: bool) -> Self {
         self.emoji = Some(v);
         self
     }
 
+    /// Only applies to mrkdwn.
     pub fn set_verbatim(mut self, v: bool) -> Self {
         self.verbatim = Some(v);
         self
     }
 
     pub fn build(self) -> Text {
-        Text {
-            formatting_type: self.formatting_type,
-            text: self.text,
-            emoji: self.emoji,
-            verbatim: self.verbatim,
+        match self.formatting_type {
+            FormattingType::PlainText => Text::PlainText(PlainText {
+                text: self.text,
+                emoji: self.emoji,
+            }),
+            FormattingType::Markdown => Text::Mrkdwn(Mrkdwn {
+                text: self.text,
+                verbatim: self.verbatim,
+            }),
         }
     }
 }
 
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
 pub enum FormattingType {
+    #[serde(rename = "plain_text")]
     PlainText,
+    #[serde(rename = "mrkdwn")]
     Markdown,
 }
 
-impl Serialize for FormattingType {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_str(match self {
-            FormattingType::PlainText => "plain_text",
-            FormattingType::Markdown => "mrkdwn",
-        })
-    }
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -87,6 +307,15 @@ mod test {
 
     #[test]
     fn text_all() {
+        let text = TextBuilder::new(FormattingType::PlainText, "hello, world")
+            .set_emoji(true)
+            .build();
+        let json = serde_json::to_string(&text).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"plain_text","text":"hello, world","emoji":true}"#
+        );
+
         let text = TextBuilder::new(FormattingType::Markdown, "hello, world")
             .set_emoji(true)
             .set_verbatim(false)
@@ -94,7 +323,34 @@ mod test {
         let json = serde_json::to_string(&text).unwrap();
         assert_eq!(
             json.as_str(),
-            r#"{"type":"mrkdwn","text":"hello, world","emoji":true,"verbatim":false}"#
+            r#"{"type":"mrkdwn","text":"hello, world","verbatim":false}"#
+        );
+    }
+
+    #[test]
+    fn text_round_trip() {
+        let json = r#"{"type":"mrkdwn","text":"hello, world","verbatim":false}"#;
+        let text: Text = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            text,
+            TextBuilder::new(FormattingType::Markdown, "hello, world")
+                .set_verbatim(false)
+                .build()
         );
+        assert_eq!(serde_json::to_string(&text).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn text_unknown_type() {
+        let res = serde_json::from_str::<Text>(r#"{"type":"html","text":"<b>hi</b>"}"#);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn plain_text_only() {
+        let res = serde_json::from_str::<PlainText>(r#"{"type":"mrkdwn","text":"*hi*"}"#);
+        assert!(res.is_err());
+        let text: PlainText = serde_json::from_str(r#"{"type":"plain_text","text":"hi"}"#).unwrap();
+        assert_eq!(text, PlainText::new("hi"));
     }
 }
@@ -0,0 +1,414 @@
+use crate::blocks::RichText;
+use crate::de;
+use crate::elements::{hh_mm, ButtonStyle, DateTime, NaiveDate, NaiveTime, Utc};
+use crate::objects::{OptionInput, PlainText};
+use crate::payloads::{Channel, Container, File, Message, Team, User, View};
+
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+/// Sent when a user interacts with an interactive element.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct BlockActions {
+    pub team: Option<Team>,
+    pub user: User,
+    pub api_app_id: String,
+    pub token: Option<String>,
+    pub container: Option<Container>,
+    pub trigger_id: String,
+    pub channel: Option<Channel>,
+    pub message: Option<Message>,
+    pub view: Option<View>,
+    pub response_url: Option<String>,
+    pub actions: Vec<Action>,
+}
+
+/// Sent when an external select menu needs options for what a user has typed.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct BlockSuggestion {
+    pub team: Option<Team>,
+    pub user: User,
+    pub api_app_id: String,
+    pub token: Option<String>,
+    pub container: Option<Container>,
+    pub channel: Option<Channel>,
+    pub message: Option<Message>,
+    pub view: Option<View>,
+    pub action_id: String,
+    pub block_id: String,
+    pub value: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Action {
+    pub action_id: String,
+    pub block_id: String,
+    pub action_ts: Option<String>,
+    // The element type determines the "type" tag and which value was sent,
+    // so it's flattened in.
+    #[serde(flatten)]
+    pub value: ActionValue,
+}
+
+/// The value of an element, as sent in an action or a view's state.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "type", remote = "Self")]
+pub enum ActionValue {
+    #[serde(rename = "button")]
+    Button {
+        text: Option<PlainText>,
+        value: Option<String>,
+        style: Option<ButtonStyle>,
+    },
+    #[serde(rename = "checkboxes")]
+    Checkboxes {
+        #[serde(default)]
+        selected_options: Vec<OptionInput>,
+    },
+    #[serde(rename = "datepicker")]
+    DatePicker { selected_date: Option<NaiveDate> },
+    #[serde(rename = "datetimepicker")]
+    DateTimePicker {
+        #[serde(default, with = "chrono::serde::ts_seconds_option")]
+        selected_date_time: Option<DateTime<Utc>>,
+    },
+    #[serde(rename = "email_text_input")]
+    EmailInput { value: Option<String> },
+    #[serde(rename = "file_input")]
+    FileInput {
+        #[serde(default)]
+        files: Vec<File>,
+    },
+    #[serde(rename = "number_input")]
+    NumberInput { value: Option<String> },
+    #[serde(rename = "overflow")]
+    Overflow {
+        selected_option: Option<OptionInput>,
+    },
+    #[serde(rename = "plain_text_input")]
+    PlainTextInput { value: Option<String> },
+    #[serde(rename = "radio_buttons")]
+    RadioButtons {
+        selected_option: Option<OptionInput>,
+    },
+    #[serde(rename = "rich_text_input")]
+    RichTextInput { rich_text_value: Option<RichText> },
+    #[serde(rename = "timepicker")]
+    TimePicker {
+        #[serde(default, with = "hh_mm")]
+        selected_time: Option<NaiveTime>,
+    },
+    #[serde(rename = "url_text_input")]
+    UrlInput { value: Option<String> },
+    #[serde(rename = "static_select")]
+    StaticSelect {
+        selected_option: Option<OptionInput>,
+    },
+    #[serde(rename = "external_select")]
+    ExternalSelect {
+        selected_option: Option<OptionInput>,
+    },
+    #[serde(rename = "users_select")]
+    UsersSelect { selected_user: Option<String> },
+    #[serde(rename = "conversations_select")]
+    ConversationsSelect {
+        selected_conversation: Option<String>,
+    },
+    #[serde(rename = "channels_select")]
+    ChannelsSelect { selected_channel: Option<String> },
+    #[serde(rename = "multi_static_select")]
+    MultiStaticSelect {
+        #[serde(default)]
+        selected_options: Vec<OptionInput>,
+    },
+    #[serde(rename = "multi_external_select")]
+    MultiExternalSelect {
+        #[serde(default)]
+        selected_options: Vec<OptionInput>,
+    },
+    #[serde(rename = "multi_users_select")]
+    MultiUsersSelect {
+        #[serde(default)]
+        selected_users: Vec<String>,
+    },
+    #[serde(rename = "multi_conversations_select")]
+    MultiConversationsSelect {
+        #[serde(default)]
+        selected_conversations: Vec<String>,
+    },
+    #[serde(rename = "multi_channels_select")]
+    MultiChannelsSelect {
+        #[serde(default)]
+        selected_channels: Vec<String>,
+    },
+    /// An element this crate doesn't model, e.g. a `workflow_button`, with
+    /// every field Slack sent.
+    #[serde(skip)]
+    Unknown(Value),
+}
+
+const ACTION_VALUE_TYPES: &[&str] = &[
+    "button",
+    "checkboxes",
+    "datepicker",
+    "datetimepicker",
+    "email_text_input",
+    "file_input",
+    "number_input",
+    "overflow",
+    "plain_text_input",
+    "radio_buttons",
+    "rich_text_input",
+    "timepicker",
+    "url_text_input",
+    "static_select",
+    "external_select",
+    "users_select",
+    "conversations_select",
+    "channels_select",
+    "multi_static_select",
+    "multi_external_select",
+    "multi_users_select",
+    "multi_conversations_select",
+    "multi_channels_select",
+];
+
+// The derived impl, which `remote = "Self"` makes inherent, handles every type
+// but `Unknown`.
+impl<'de> Deserialize<'de> for ActionValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match de::known(deserializer, ACTION_VALUE_TYPES)? {
+            Ok(value) => ActionValue::deserialize(value).map_err(de::Error::custom),
+            Err(value) => Ok(ActionValue::Unknown(value)),
+        }
+    }
+}
+
+impl ActionValue {
+    /// The "type" of the element this value came from.
+    pub fn kind(&self) -> &str {
+        match self {
+            ActionValue::Button { .. } => "button",
+            ActionValue::Checkboxes { .. } => "checkboxes",
+            ActionValue::DatePicker { .. } => "datepicker",
+            ActionValue::DateTimePicker { .. } => "datetimepicker",
+            ActionValue::EmailInput { .. } => "email_text_input",
+            ActionValue::FileInput { .. } => "file_input",
+            ActionValue::NumberInput { .. } => "number_input",
+            ActionValue::Overflow { .. } => "overflow",
+            ActionValue::PlainTextInput { .. } => "plain_text_input",
+            ActionValue::RadioButtons { .. } => "radio_buttons",
+            ActionValue::RichTextInput { .. } => "rich_text_input",
+            ActionValue::TimePicker { .. } => "timepicker",
+            ActionValue::UrlInput { .. } => "url_text_input",
+            ActionValue::StaticSelect { .. } => "static_select",
+            ActionValue::ExternalSelect { .. } => "external_select",
+            ActionValue::UsersSelect { .. } => "users_select",
+            ActionValue::ConversationsSelect { .. } => "conversations_select",
+            ActionValue::ChannelsSelect { .. } => "channels_select",
+            ActionValue::MultiStaticSelect { .. } => "multi_static_select",
+            ActionValue::MultiExternalSelect { .. } => "multi_external_select",
+            ActionValue::MultiUsersSelect { .. } => "multi_users_select",
+            ActionValue::MultiConversationsSelect { .. } => "multi_conversations_select",
+            ActionValue::MultiChannelsSelect { .. } => "multi_channels_select",
+            ActionValue::Unknown(e) => e["type"].as_str().unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::blocks::ActionsElement;
+    use crate::objects::rich_text::{RichTextElement, RichTextInline};
+    use crate::surfaces::MessageBlock;
+
+    #[test]
+    fn block_actions() {
+        let json = r#"{
+            "type": "block_actions",
+            "team": {"id": "T9TK3CUKW", "domain": "example"},
+            "user": {"id": "UA8RXUSPL", "username": "jtorrance", "team_id": "T9TK3CUKW"},
+            "api_app_id": "AABA1ABCD",
+            "token": "9s8d9as89d8as9d8as989",
+            "container": {"type": "message", "message_ts": "1548261231.000200", "channel_id": "CBR2V3XEX", "is_ephemeral": false},
+            "trigger_id": "12466734323.1395872398",
+            "channel": {"id": "CBR2V3XEX", "name": "review-updates"},
+            "message": {"type": "message", "bot_id": "BAH5CA16Z", "text": "This content can't be displayed.", "ts": "1548261231.000200", "blocks": [{"type": "divider"}]},
+            "response_url": "https://hooks.slack.com/actions/AABA1ABCD/1232321423432/D09sSasdasdAS9091209",
+            "actions": [
+                {"action_id": "approve", "block_id": "approval", "text": {"type": "plain_text", "text": "Approve"}, "value": "approve_1", "style": "primary", "type": "button", "action_ts": "1548426417.840180"},
+                {"action_id": "when", "block_id": "approval", "type": "datepicker", "selected_date": "1990-04-28", "initial_date": "1990-04-28", "action_ts": "1548426417.840180"},
+                {"action_id": "who", "block_id": "approval", "type": "static_select", "selected_option": {"text": {"type": "plain_text", "text": "Option 1"}, "value": "value-0"}, "placeholder": {"type": "plain_text", "text": "Pick one"}, "action_ts": "1548426417.840180"}
+            ]
+        }"#;
+        let payload: BlockActions = serde_json::from_str(json).unwrap();
+        assert_eq!(payload.user.id, "UA8RXUSPL");
+        assert_eq!(
+            payload.container,
+            Some(Container::Message {
+                message_ts: "1548261231.000200".to_string(),
+                channel_id: Some("CBR2V3XEX".to_string()),
+                is_ephemeral: false,
+            })
+        );
+        assert_eq!(payload.message.unwrap().blocks.len(), 1);
+        assert_eq!(
+            payload.actions[0].value,
+            ActionValue::Button {
+                text: Some(PlainText::new("Approve")),
+                value: Some("approve_1".to_string()),
+                style: Some(ButtonStyle::Primary),
+            }
+        );
+        assert_eq!(
+            payload.actions[1].value,
+            ActionValue::DatePicker {
+                selected_date: NaiveDate::from_ymd_opt(1990, 4, 28),
+            }
+        );
+        assert_eq!(
+            payload.actions[2].value,
+            ActionValue::StaticSelect {
+                selected_option: Some(
+                    OptionInput::builder(PlainText::new("Option 1").into(), "value-0",).build()
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn action_values() {
+        let json = r#"[
+            {"type": "plain_text_input", "value": "Hello"},
+            {"type": "plain_text_input", "value": null},
+            {"type": "multi_users_select", "selected_users": ["U12345", "U67890"]},
+            {"type": "checkboxes", "selected_options": []},
+            {"type": "conversations_select", "selected_conversation": "C12345"},
+            {"type": "timepicker", "selected_time": "09:30"},
+            {"type": "datetimepicker", "selected_date_time": 1628633820},
+            {"type": "number_input", "value": "42"}
+        ]"#;
+        let values: Vec<ActionValue> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            values,
+            vec![
+                ActionValue::PlainTextInput {
+                    value: Some("Hello".to_string())
+                },
+                ActionValue::PlainTextInput { value: None },
+                ActionValue::MultiUsersSelect {
+                    selected_users: vec!["U12345".to_string(), "U67890".to_string()]
+                },
+                ActionValue::Checkboxes {
+                    selected_options: vec![]
+                },
+                ActionValue::ConversationsSelect {
+                    selected_conversation: Some("C12345".to_string())
+                },
+                ActionValue::TimePicker {
+                    selected_time: NaiveTime::from_hms_opt(9, 30, 0)
+                },
+                ActionValue::DateTimePicker {
+                    selected_date_time: DateTime::from_timestamp(1628633820, 0)
+                },
+                ActionValue::NumberInput {
+                    value: Some("42".to_string())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn block_suggestion() {
+        let json = r#"{
+            "type": "block_suggestion",
+            "user": {"id": "UA8RXUSPL", "name": "jtorrance", "team_id": "T9TK3CUKW"},
+            "container": {"type": "view", "view_id": "VMHU10V25"},
+            "api_app_id": "AABA1ABCD",
+            "token": "9s8d9as89d8as9d8as989",
+            "action_id": "who",
+            "block_id": "assignee",
+            "value": "jac",
+            "team": {"id": "T9TK3CUKW", "domain": "example"}
+        }"#;
+        let payload: BlockSuggestion = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            payload.container,
+            Some(Container::View {
+                view_id: "VMHU10V25".to_string()
+            })
+        );
+        assert_eq!(payload.value, "jac");
+    }
+
+    #[test]
+    fn unmodelled_types() {
+        let json = r#"{
+            "type": "block_actions",
+            "user": {"id": "UA8RXUSPL"},
+            "api_app_id": "AABA1ABCD",
+            "container": {"type": "message_attachment", "message_ts": "1548261231.000200", "attachment_id": 1, "channel_id": "CBR2V3XEX", "is_ephemeral": false, "is_app_unfurl": false},
+            "trigger_id": "12466734323.1395872398",
+            "message": {"type": "message", "text": "Ready to ship?", "ts": "1548261231.000200", "blocks": [
+                {"type": "markdown", "block_id": "intro", "text": "**Ready** to ship?"},
+                {"type": "actions", "block_id": "ship", "elements": [{"type": "workflow_button", "action_id": "run", "text": {"type": "plain_text", "text": "Ship"}, "workflow": {"trigger": {"url": "https://slack.com/shortcuts/Ft0/abc"}}}]},
+                {"type": "rich_text", "elements": [{"type": "rich_text_section", "elements": [{"type": "text", "text": "Due "}, {"type": "sparkle", "id": "s1"}]}]}
+            ]},
+            "actions": [{"action_id": "run", "block_id": "ship", "type": "workflow_button", "text": {"type": "plain_text", "text": "Ship"}, "action_ts": "1548426417.840180"}]
+        }"#;
+        let payload: BlockActions = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            payload.container,
+            Some(Container::MessageAttachment {
+                message_ts: "1548261231.000200".to_string(),
+                attachment_id: 1,
+                channel_id: Some("CBR2V3XEX".to_string()),
+                is_ephemeral: false,
+                is_app_unfurl: false,
+            })
+        );
+
+        let blocks = payload.message.unwrap().blocks;
+        let markdown = serde_json::json!({"type": "markdown", "block_id": "intro", "text": "**Ready** to ship?"});
+        assert_eq!(blocks[0], MessageBlock::Unknown(markdown.clone()));
+        assert_eq!(serde_json::to_value(&blocks[0]).unwrap(), markdown);
+        match &blocks[1] {
+            MessageBlock::Actions(e) => {
+                assert!(matches!(e.elements[0], ActionsElement::Unknown(_)))
+            }
+            other => panic!("unexpected block {:?}", other),
+        }
+        match &blocks[2] {
+            MessageBlock::RichText(e) => match &e.elements[0] {
+                RichTextElement::Section(e) => assert_eq!(
+                    e.elements[1],
+                    RichTextInline::Unknown(serde_json::json!({"type": "sparkle", "id": "s1"}))
+                ),
+                other => panic!("unexpected element {:?}", other),
+            },
+            other => panic!("unexpected block {:?}", other),
+        }
+
+        let action = &payload.actions[0];
+        assert_eq!(action.action_id, "run");
+        assert_eq!(action.value.kind(), "workflow_button");
+        match &action.value {
+            ActionValue::Unknown(e) => assert_eq!(e["text"]["text"], "Ship"),
+            other => panic!("unexpected value {:?}", other),
+        }
+    }
+
+    #[test]
+    fn known_type_not_allowed() {
+        // A known element in the wrong place is still an error, not unknown.
+        let json = r#"{"type": "actions", "elements": [{"type": "file_input", "action_id": "f"}]}"#;
+        let err = serde_json::from_str::<MessageBlock>(json).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("unknown actions element type `file_input`"));
+    }
+}
@@ -0,0 +1,135 @@
+// Reference: https://api.slack.com/reference/interaction-payloads
+//
+// These are the payloads Slack sends to an app's interactivity request URL.
+// They're only ever received, so unlike the rest of the crate they only
+// implement Deserialize.
+mod actions;
+mod shortcuts;
+mod state;
+mod views;
+
+pub use crate::payloads::actions::*;
+pub use crate::payloads::shortcuts::*;
+pub use crate::payloads::state::*;
+pub use crate::payloads::views::*;
+
+use crate::surfaces::MessageBlock;
+
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum Payload {
+    #[serde(rename = "block_actions")]
+    BlockActions(BlockActions),
+    #[serde(rename = "block_suggestion")]
+    BlockSuggestion(BlockSuggestion),
+    #[serde(rename = "view_submission")]
+    ViewSubmission(ViewSubmission),
+    #[serde(rename = "view_closed")]
+    ViewClosed(ViewClosed),
+    #[serde(rename = "shortcut")]
+    Shortcut(Shortcut),
+    #[serde(rename = "message_action")]
+    MessageAction(MessageAction),
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Team {
+    pub id: String,
+    pub domain: Option<String>,
+    pub enterprise_id: Option<String>,
+    pub enterprise_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct User {
+    pub id: String,
+    pub username: Option<String>,
+    pub name: Option<String>,
+    pub team_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Channel {
+    pub id: String,
+    pub name: Option<String>,
+}
+
+/// Where the interaction happened.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Container {
+    Message {
+        message_ts: String,
+        channel_id: Option<String>,
+        #[serde(default)]
+        is_ephemeral: bool,
+    },
+    View {
+        view_id: String,
+    },
+    // A legacy attachment on a message, or an app's link unfurl.
+    MessageAttachment {
+        message_ts: String,
+        attachment_id: u32,
+        channel_id: Option<String>,
+        #[serde(default)]
+        is_ephemeral: bool,
+        #[serde(default)]
+        is_app_unfurl: bool,
+    },
+}
+
+/// A file uploaded through a `FileInput`.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct File {
+    pub id: String,
+    pub name: Option<String>,
+    pub title: Option<String>,
+    pub mimetype: Option<String>,
+    pub filetype: Option<String>,
+    pub size: Option<u64>,
+    pub url_private: Option<String>,
+}
+
+/// A message the interaction came from, as Slack describes it.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Message {
+    pub ts: String,
+    pub text: Option<String>,
+    pub user: Option<String>,
+    pub bot_id: Option<String>,
+    pub thread_ts: Option<String>,
+    #[serde(default)]
+    pub blocks: Vec<MessageBlock>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn payload_type() {
+        let json = r#"{
+            "type": "shortcut",
+            "token": "XXXXXXXXXXXXX",
+            "action_ts": "1581106241.371594",
+            "team": {"id": "TXXXXXXXX", "domain": "shortcuts-test"},
+            "user": {"id": "UXXXXXXXXX", "username": "aman", "team_id": "TXXXXXXXX"},
+            "callback_id": "shortcut_create_task",
+            "trigger_id": "944799105734.773906753841.38b5894552bdd4a780554ee59d1f3638"
+        }"#;
+        let payload: Payload = serde_json::from_str(json).unwrap();
+        match payload {
+            Payload::Shortcut(s) => assert_eq!(s.callback_id, "shortcut_create_task"),
+            other => panic!("unexpected payload {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unknown_payload_type() {
+        let json = r#"{"type": "dialog_submission", "callback_id": "x"}"#;
+        assert!(serde_json::from_str::<Payload>(json).is_err());
+    }
+}
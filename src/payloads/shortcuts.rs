@@ -0,0 +1,55 @@
+use crate::payloads::{Channel, Message, Team, User};
+
+use serde::Deserialize;
+
+/// Sent when a user invokes a global shortcut.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Shortcut {
+    pub team: Option<Team>,
+    pub user: User,
+    pub token: Option<String>,
+    pub action_ts: String,
+    pub callback_id: String,
+    pub trigger_id: String,
+}
+
+/// Sent when a user invokes a message shortcut.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct MessageAction {
+    pub team: Option<Team>,
+    pub user: User,
+    pub token: Option<String>,
+    pub action_ts: String,
+    pub callback_id: String,
+    pub trigger_id: String,
+    pub channel: Channel,
+    pub message_ts: String,
+    pub message: Message,
+    pub response_url: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn message_action() {
+        let json = r#"{
+            "type": "message_action",
+            "token": "Nj2rfC2hU8mAfgaJLemZgO7H",
+            "action_ts": "1581106241.371594",
+            "team": {"id": "TXXXXXXXX", "domain": "shortcuts-test"},
+            "user": {"id": "UXXXXXXXXX", "name": "aman"},
+            "channel": {"id": "CXXXXXXXX", "name": "general"},
+            "callback_id": "unfurl_link",
+            "trigger_id": "944799105734.773906753841.38b5894552bdd4a780554ee59d1f3638",
+            "message_ts": "1581106241.371594",
+            "message": {"type": "message", "user": "UXXXXXXXXX", "text": "Hello", "ts": "1581106241.371594"},
+            "response_url": "https://hooks.slack.com/app-actions/T0MJR11A4/21974584944/yk1S9ndf35Q1flupVG5JbpM6"
+        }"#;
+        let payload: MessageAction = serde_json::from_str(json).unwrap();
+        assert_eq!(payload.channel.name.as_deref(), Some("general"));
+        assert_eq!(payload.message.text.as_deref(), Some("Hello"));
+        assert!(payload.message.blocks.is_empty());
+    }
+}
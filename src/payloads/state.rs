@@ -0,0 +1,356 @@
+use crate::blocks::RichText;
+use crate::elements::{DateTime, NaiveDate, NaiveTime, Utc};
+use crate::objects::OptionInput;
+use crate::payloads::{ActionValue, File};
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+/// The values of a view's inputs, keyed by `block_id` then `action_id`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct ViewState {
+    pub values: HashMap<String, HashMap<String, ActionValue>>,
+}
+
+impl ViewState {
+    pub fn get(&self, block_id: &str, action_id: &str) -> Option<&ActionValue> {
+        self.values.get(block_id)?.get(action_id)
+    }
+
+    fn value(&self, block_id: &str, action_id: &str) -> Result<&ActionValue, StateError> {
+        self.get(block_id, action_id)
+            .ok_or_else(|| StateError::missing(block_id, action_id))
+    }
+
+    /// The text entered into a required plain text, email, URL or number
+    /// input.
+    pub fn text(&self, block_id: &str, action_id: &str) -> Result<String, StateError> {
+        self.optional_text(block_id, action_id)?
+            .ok_or_else(|| StateError::missing(block_id, action_id))
+    }
+
+    /// The text entered into an optional plain text, email, URL or number
+    /// input, if any.
+    pub fn optional_text(
+        &self,
+        block_id: &str,
+        action_id: &str,
+    ) -> Result<Option<String>, StateError> {
+        match self.value(block_id, action_id)? {
+            ActionValue::PlainTextInput { value }
+            | ActionValue::EmailInput { value }
+            | ActionValue::UrlInput { value }
+            | ActionValue::NumberInput { value } => Ok(value.clone()),
+            other => Err(StateError::wrong_type(
+                block_id,
+                action_id,
+                "text input",
+                other,
+            )),
+        }
+    }
+
+    /// The date picked in a `DatePicker`, if any.
+    pub fn date(&self, block_id: &str, action_id: &str) -> Result<Option<NaiveDate>, StateError> {
+        match self.value(block_id, action_id)? {
+            ActionValue::DatePicker { selected_date } => Ok(*selected_date),
+            other => Err(StateError::wrong_type(
+                block_id,
+                action_id,
+                "datepicker",
+                other,
+            )),
+        }
+    }
+
+    /// The date picked in a required `DatePicker`.
+    pub fn required_date(&self, block_id: &str, action_id: &str) -> Result<NaiveDate, StateError> {
+        self.date(block_id, action_id)?
+            .ok_or_else(|| StateError::missing(block_id, action_id))
+    }
+
+    /// The formatted text entered into a `RichTextInput`, if any.
+    pub fn rich_text(
+        &self,
+        block_id: &str,
+        action_id: &str,
+    ) -> Result<Option<RichText>, StateError> {
+        match self.value(block_id, action_id)? {
+            ActionValue::RichTextInput { rich_text_value } => Ok(rich_text_value.clone()),
+            other => Err(StateError::wrong_type(
+                block_id,
+                action_id,
+                "rich_text_input",
+                other,
+            )),
+        }
+    }
+
+    /// The time picked in a `TimePicker`, if any.
+    pub fn time(&self, block_id: &str, action_id: &str) -> Result<Option<NaiveTime>, StateError> {
+        match self.value(block_id, action_id)? {
+            ActionValue::TimePicker { selected_time } => Ok(*selected_time),
+            other => Err(StateError::wrong_type(
+                block_id,
+                action_id,
+                "timepicker",
+                other,
+            )),
+        }
+    }
+
+    /// The date and time picked in a `DateTimePicker`, if any.
+    pub fn date_time(
+        &self,
+        block_id: &str,
+        action_id: &str,
+    ) -> Result<Option<DateTime<Utc>>, StateError> {
+        match self.value(block_id, action_id)? {
+            ActionValue::DateTimePicker { selected_date_time } => Ok(*selected_date_time),
+            other => Err(StateError::wrong_type(
+                block_id,
+                action_id,
+                "datetimepicker",
+                other,
+            )),
+        }
+    }
+
+    /// The files uploaded through a `FileInput`.
+    pub fn files(&self, block_id: &str, action_id: &str) -> Result<Vec<File>, StateError> {
+        match self.value(block_id, action_id)? {
+            ActionValue::FileInput { files } => Ok(files.clone()),
+            other => Err(StateError::wrong_type(
+                block_id,
+                action_id,
+                "file_input",
+                other,
+            )),
+        }
+    }
+
+    /// The option chosen in a radio button group, overflow menu or static or
+    /// external select menu, if any.
+    pub fn selected_option(
+        &self,
+        block_id: &str,
+        action_id: &str,
+    ) -> Result<Option<OptionInput>, StateError> {
+        match self.value(block_id, action_id)? {
+            ActionValue::RadioButtons { selected_option }
+            | ActionValue::Overflow { selected_option }
+            | ActionValue::StaticSelect { selected_option }
+            | ActionValue::ExternalSelect { selected_option } => Ok(selected_option.clone()),
+            other => Err(StateError::wrong_type(
+                block_id,
+                action_id,
+                "single option input",
+                other,
+            )),
+        }
+    }
+
+    /// The option chosen in a required radio button group or select menu.
+    pub fn required_option(
+        &self,
+        block_id: &str,
+        action_id: &str,
+    ) -> Result<OptionInput, StateError> {
+        self.selected_option(block_id, action_id)?
+            .ok_or_else(|| StateError::missing(block_id, action_id))
+    }
+
+    /// The options chosen in a `Checkboxes` group or a static or external
+    /// multi-select menu.
+    pub fn selected_options(
+        &self,
+        block_id: &str,
+        action_id: &str,
+    ) -> Result<Vec<OptionInput>, StateError> {
+        match self.value(block_id, action_id)? {
+            ActionValue::Checkboxes { selected_options }
+            | ActionValue::MultiStaticSelect { selected_options }
+            | ActionValue::MultiExternalSelect { selected_options } => Ok(selected_options.clone()),
+            other => Err(StateError::wrong_type(
+                block_id,
+                action_id,
+                "multiple option input",
+                other,
+            )),
+        }
+    }
+
+    /// The user, conversation or channel ID chosen in a select menu, if any.
+    pub fn selected_id(
+        &self,
+        block_id: &str,
+        action_id: &str,
+    ) -> Result<Option<String>, StateError> {
+        match self.value(block_id, action_id)? {
+            ActionValue::UsersSelect { selected_user: id }
+            | ActionValue::ConversationsSelect {
+                selected_conversation: id,
+            }
+            | ActionValue::ChannelsSelect {
+                selected_channel: id,
+            } => Ok(id.clone()),
+            other => Err(StateError::wrong_type(
+                block_id,
+                action_id,
+                "user, conversation or channel select",
+                other,
+            )),
+        }
+    }
+
+    /// The user, conversation or channel IDs chosen in a multi-select menu.
+    pub fn selected_ids(&self, block_id: &str, action_id: &str) -> Result<Vec<String>, StateError> {
+        match self.value(block_id, action_id)? {
+            ActionValue::MultiUsersSelect {
+                selected_users: ids,
+            }
+            | ActionValue::MultiConversationsSelect {
+                selected_conversations: ids,
+            }
+            | ActionValue::MultiChannelsSelect {
+                selected_channels: ids,
+            } => Ok(ids.clone()),
+            other => Err(StateError::wrong_type(
+                block_id,
+                action_id,
+                "user, conversation or channel multi-select",
+                other,
+            )),
+        }
+    }
+}
+
+/// Why a value couldn't be read from a view's state.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StateError {
+    /// The input wasn't in the state, or a required input was left empty.
+    Missing { block_id: String, action_id: String },
+    /// The input came from a different kind of element than expected.
+    WrongType {
+        block_id: String,
+        action_id: String,
+        expected: &'static str,
+        actual: String,
+    },
+}
+
+impl StateError {
+    fn missing(block_id: &str, action_id: &str) -> Self {
+        StateError::Missing {
+            block_id: block_id.to_string(),
+            action_id: action_id.to_string(),
+        }
+    }
+
+    fn wrong_type(
+        block_id: &str,
+        action_id: &str,
+        expected: &'static str,
+        actual: &ActionValue,
+    ) -> Self {
+        StateError::WrongType {
+            block_id: block_id.to_string(),
+            action_id: action_id.to_string(),
+            expected,
+            actual: actual.kind().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for StateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StateError::Missing {
+                block_id,
+                action_id,
+            } => write!(f, "no value for input `{}.{}`", block_id, action_id),
+            StateError::WrongType {
+                block_id,
+                action_id,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "expected a {} for input `{}.{}`, but got a {}",
+                expected, block_id, action_id, actual
+            ),
+        }
+    }
+}
+
+impl Error for StateError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::objects::PlainText;
+
+    fn state() -> ViewState {
+        serde_json::from_str(
+            r#"{"values": {
+                "title": {"title_input": {"type": "plain_text_input", "value": "Fix the build"}},
+                "notes": {"notes_input": {"type": "plain_text_input", "value": null}},
+                "due": {"due_date": {"type": "datepicker", "selected_date": "2020-01-01"}},
+                "flags": {"flag_boxes": {"type": "checkboxes", "selected_options": [
+                    {"text": {"type": "plain_text", "text": "Urgent"}, "value": "urgent"}
+                ]}},
+                "owner": {"owner_select": {"type": "users_select", "selected_user": "U12345"}},
+                "body": {"body_input": {"type": "rich_text_input", "rich_text_value": {"type": "rich_text", "elements": [
+                    {"type": "rich_text_section", "elements": [{"type": "text", "text": "Hi "}, {"type": "user", "user_id": "U12345"}]}
+                ]}}}
+            }}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn typed_values() {
+        let state = state();
+        assert_eq!(state.text("title", "title_input").unwrap(), "Fix the build");
+        assert_eq!(state.optional_text("notes", "notes_input").unwrap(), None);
+        assert_eq!(
+            state.date("due", "due_date").unwrap(),
+            NaiveDate::from_ymd_opt(2020, 1, 1)
+        );
+        assert_eq!(
+            state.selected_options("flags", "flag_boxes").unwrap(),
+            vec![OptionInput::builder(PlainText::new("Urgent").into(), "urgent").build()]
+        );
+        assert_eq!(
+            state
+                .selected_id("owner", "owner_select")
+                .unwrap()
+                .as_deref(),
+            Some("U12345")
+        );
+        let body = state.rich_text("body", "body_input").unwrap().unwrap();
+        assert_eq!(body.elements.len(), 1);
+    }
+
+    #[test]
+    fn errors() {
+        let state = state();
+        assert_eq!(
+            state.text("notes", "notes_input").unwrap_err().to_string(),
+            "no value for input `notes.notes_input`"
+        );
+        assert_eq!(
+            state.date("title", "missing").unwrap_err(),
+            StateError::Missing {
+                block_id: "title".to_string(),
+                action_id: "missing".to_string(),
+            }
+        );
+        assert_eq!(
+            state.date("title", "title_input").unwrap_err().to_string(),
+            "expected a datepicker for input `title.title_input`, but got a plain_text_input"
+        );
+    }
+}
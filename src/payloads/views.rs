@@ -0,0 +1,156 @@
+use crate::payloads::{Team, User, ViewState};
+use crate::surfaces::{HomeTab, Modal};
+
+use serde::Deserialize;
+
+/// Sent when a user submits a modal.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ViewSubmission {
+    pub team: Option<Team>,
+    pub user: User,
+    pub api_app_id: String,
+    pub token: Option<String>,
+    pub trigger_id: Option<String>,
+    pub view: View,
+    #[serde(default)]
+    pub response_urls: Vec<ResponseUrl>,
+}
+
+/// Sent when a user closes a modal that set `notify_on_close`.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ViewClosed {
+    pub team: Option<Team>,
+    pub user: User,
+    pub api_app_id: String,
+    pub token: Option<String>,
+    pub view: View,
+    #[serde(default)]
+    pub is_cleared: bool,
+}
+
+/// A view as Slack reports it back, after it's been opened or published.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct View {
+    pub id: String,
+    pub team_id: Option<String>,
+    // The surface determines the "type" tag, title and blocks, so it's
+    // flattened in.
+    #[serde(flatten)]
+    pub surface: ViewSurface,
+    #[serde(default)]
+    pub private_metadata: String,
+    #[serde(default)]
+    pub callback_id: String,
+    pub external_id: Option<String>,
+    pub state: Option<ViewState>,
+    pub hash: Option<String>,
+    pub root_view_id: Option<String>,
+    pub previous_view_id: Option<String>,
+    pub app_id: Option<String>,
+    pub bot_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum ViewSurface {
+    #[serde(rename = "modal")]
+    Modal(Modal),
+    #[serde(rename = "home")]
+    HomeTab(HomeTab),
+}
+
+/// Where to respond for a conversation picked in a modal's input block.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ResponseUrl {
+    pub block_id: String,
+    pub action_id: String,
+    pub channel_id: String,
+    pub response_url: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::elements::NaiveDate;
+    use crate::payloads::ActionValue;
+
+    #[test]
+    fn view_submission() {
+        let json = r#"{
+            "type": "view_submission",
+            "team": {"id": "T9TK3CUKW", "domain": "example"},
+            "user": {"id": "UA8RXUSPL", "username": "jtorrance", "name": "jtorrance", "team_id": "T9TK3CUKW"},
+            "api_app_id": "AABA1ABCD",
+            "token": "9s8d9as89d8as9d8as989",
+            "trigger_id": "12466734323.1395872398",
+            "view": {
+                "id": "VNHU13V36",
+                "team_id": "T9TK3CUKW",
+                "type": "modal",
+                "title": {"type": "plain_text", "text": "Modal title"},
+                "blocks": [{"type": "divider"}],
+                "private_metadata": "shhh-its-secret",
+                "callback_id": "modal-with-inputs",
+                "state": {
+                    "values": {
+                        "multi-line": {"ml-value": {"type": "plain_text_input", "value": "This is my example input"}},
+                        "when": {"date": {"type": "datepicker", "selected_date": "2020-01-01"}}
+                    }
+                },
+                "hash": "156663117.cd33ad1f",
+                "clear_on_close": false,
+                "notify_on_close": false,
+                "root_view_id": "VNHU13V36",
+                "previous_view_id": null,
+                "app_id": "AABA1ABCD",
+                "external_id": "",
+                "bot_id": "BA13894H"
+            },
+            "response_urls": [{"block_id": "channel", "action_id": "pick", "channel_id": "C12345", "response_url": "https://hooks.slack.com/app/T9TK3CUKW/1/abc"}]
+        }"#;
+        let payload: ViewSubmission = serde_json::from_str(json).unwrap();
+        assert_eq!(payload.view.callback_id, "modal-with-inputs");
+        match &payload.view.surface {
+            ViewSurface::Modal(modal) => assert_eq!(modal.blocks.len(), 1),
+            other => panic!("unexpected surface {:?}", other),
+        }
+        let values = &payload.view.state.unwrap().values;
+        assert_eq!(
+            values["multi-line"]["ml-value"],
+            ActionValue::PlainTextInput {
+                value: Some("This is my example input".to_string())
+            }
+        );
+        assert_eq!(
+            values["when"]["date"],
+            ActionValue::DatePicker {
+                selected_date: NaiveDate::from_ymd_opt(2020, 1, 1)
+            }
+        );
+        assert_eq!(payload.response_urls[0].channel_id, "C12345");
+    }
+
+    #[test]
+    fn view_closed() {
+        let json = r#"{
+            "type": "view_closed",
+            "team": {"id": "T9TK3CUKW", "domain": "example"},
+            "user": {"id": "UA8RXUSPL"},
+            "api_app_id": "AABA1ABCD",
+            "view": {
+                "id": "VNHU13V36",
+                "type": "home",
+                "blocks": [],
+                "callback_id": "home"
+            },
+            "is_cleared": true
+        }"#;
+        let payload: ViewClosed = serde_json::from_str(json).unwrap();
+        assert!(payload.is_cleared);
+        assert_eq!(payload.view.state, None);
+        assert_eq!(
+            payload.view.surface,
+            ViewSurface::HomeTab(HomeTab::new(vec![]))
+        );
+    }
+}
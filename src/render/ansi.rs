@@ -0,0 +1,381 @@
+// Renders blocks for a terminal, using ANSI escape codes for styling and
+// box-drawing characters for layout.
+use crate::blocks::*;
+use crate::elements::{Button, ButtonStyle};
+use crate::objects::rich_text::*;
+use crate::objects::Text;
+use crate::render::mrkdwn::{self, Node};
+use crate::render::{inline_text, list_item_prefix, trimmed_inlines, AnyBlock, Block};
+
+// Leaves room for a section's box within 80 columns.
+const WIDTH: usize = 76;
+
+// Each style's codes, to turn it on and back off again.
+const BOLD: (&str, &str) = ("\x1b[1m", "\x1b[22m");
+const DIM: (&str, &str) = ("\x1b[2m", "\x1b[22m");
+const ITALIC: (&str, &str) = ("\x1b[3m", "\x1b[23m");
+const UNDERLINE: (&str, &str) = ("\x1b[4m", "\x1b[24m");
+const STRIKE: (&str, &str) = ("\x1b[9m", "\x1b[29m");
+const RED: (&str, &str) = ("\x1b[31m", "\x1b[39m");
+const GREEN: (&str, &str) = ("\x1b[32m", "\x1b[39m");
+const BLUE: (&str, &str) = ("\x1b[34m", "\x1b[39m");
+const CYAN: (&str, &str) = ("\x1b[36m", "\x1b[39m");
+const STYLES: &[(&str, &str)] = &[BOLD, DIM, ITALIC, UNDERLINE, STRIKE, RED, GREEN, BLUE, CYAN];
+
+/// Renders blocks as text for a terminal, styled with ANSI escape codes.
+///
+/// Sections are boxed, with their fields in two columns. Buttons are shown
+/// as `[Label]` and images by their alt text.
+pub fn ansi_text<B: Block>(blocks: &[B]) -> String {
+    blocks
+        .iter()
+        .map(|e| block(e.as_any()))
+        .filter(|e| !e.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn block(block: AnyBlock) -> String {
+    match block {
+        AnyBlock::Actions(e) => e
+            .elements
+            .iter()
+            .filter_map(|e| match e {
+                ActionsElement::Button(e) => Some(button(e)),
+                ActionsElement::OverflowMenu(_) => Some("[⋯]".to_string()),
+                ActionsElement::SelectMenu(e) => {
+                    Some(format!("[{} ▾]", clean(e.placeholder.text())))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join(" "),
+        AnyBlock::Call => style("[Call]", DIM),
+        AnyBlock::Context(e) => {
+            let text = e
+                .elements
+                .iter()
+                .filter_map(|e| match e {
+                    ContextElement::Image(e) => Some(image(&e.alt_text)),
+                    ContextElement::Text(e) => Some(text(e)),
+                    ContextElement::Unknown(_) => None,
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            style(&text, DIM)
+        }
+        AnyBlock::Divider => style(&"─".repeat(WIDTH + 4), DIM),
+        AnyBlock::File => style("[File]", DIM),
+        AnyBlock::Header(e) => style(&style(&clean(e.text.text()), BOLD), UNDERLINE),
+        AnyBlock::Image(e) => image(&e.alt_text),
+        AnyBlock::Input(e) => style(&clean(e.label.text()), BOLD),
+        AnyBlock::RichText(e) => e
+            .elements
+            .iter()
+            .map(rich_text)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        AnyBlock::Section(e) => {
+            let mut lines = match &e.text {
+                Some(e) => wrap(&text(e), WIDTH),
+                None => Vec::new(),
+            };
+            if let Some(fields) = &e.fields {
+                let fields: Vec<String> = fields.iter().map(text).collect();
+                lines.extend(columns(&fields));
+            }
+            match &e.accessory {
+                Some(SectionElement::Button(e)) => lines.push(button(e)),
+                Some(SectionElement::Image(e)) => lines.push(image(&e.alt_text)),
+                _ => {}
+            }
+            boxed(&lines)
+        }
+        AnyBlock::Video(e) => style(&format!("[Video: {}]", clean(e.title.text())), DIM),
+        AnyBlock::Unknown => style("[Unsupported block]", DIM),
+    }
+}
+
+// Styles that end with the same code, e.g. bold and dim, would turn this one
+// off early, so it's turned back on after each of them inside.
+fn style(text: &str, (on, off): (&str, &str)) -> String {
+    let text = text.replace(off, &format!("{}{}", off, on));
+    format!("{}{}{}", on, text, off)
+}
+
+// Drops control characters, so text from users can't move the cursor, set the
+// window title or style itself. Line breaks are kept.
+fn clean(text: &str) -> String {
+    text.chars()
+        .filter(|&c| c == '\n' || !c.is_control())
+        .collect()
+}
+
+fn button(button: &Button) -> String {
+    let label = format!("[{}]", clean(button.text.text()));
+    match button.style {
+        Some(ButtonStyle::Primary) => style(&style(&label, GREEN), BOLD),
+        Some(ButtonStyle::Danger) => style(&style(&label, RED), BOLD),
+        _ => style(&label, BOLD),
+    }
+}
+
+fn image(alt_text: &str) -> String {
+    style(&format!("[Image: {}]", clean(alt_text)), DIM)
+}
+
+fn text(text: &Text) -> String {
+    match text {
+        Text::PlainText(e) => clean(e.text()),
+        Text::Mrkdwn(e) => nodes(&mrkdwn::parse(&clean(e.text()))),
+    }
+}
+
+fn nodes(nodes: &[Node]) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        match node {
+            Node::Text(e) => out.push_str(e),
+            Node::Bold(e) => out.push_str(&style(&self::nodes(e), BOLD)),
+            Node::Italic(e) => out.push_str(&style(&self::nodes(e), ITALIC)),
+            Node::Strike(e) => out.push_str(&style(&self::nodes(e), STRIKE)),
+            Node::Code(e) => out.push_str(&style(e, CYAN)),
+            Node::CodeBlock(e) => {
+                let lines: Vec<String> = e.lines().map(|e| style(e, CYAN)).collect();
+                out.push_str(&lines.join("\n"));
+            }
+            Node::Quote(e) => {
+                out.push_str(&style("│ ", DIM));
+                out.push_str(&self::nodes(e));
+            }
+            Node::Link { url, label } => match label {
+                Some(label) => {
+                    out.push_str(&style(label, UNDERLINE));
+                    out.push_str(&style(&format!(" ({})", url), DIM));
+                }
+                None => out.push_str(&style(url, UNDERLINE)),
+            },
+            Node::Entity(e) => out.push_str(&style(e, BLUE)),
+        }
+    }
+    out
+}
+
+fn rich_text(element: &RichTextElement) -> String {
+    match element {
+        RichTextElement::Section(e) => inlines(&e.elements),
+        RichTextElement::List(e) => e
+            .elements
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| format!("{}{}", list_item_prefix(e, idx), inlines(&item.elements)))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        RichTextElement::Quote(e) => inlines(&e.elements)
+            .lines()
+            .map(|e| format!("{}{}", style("│ ", DIM), e))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        RichTextElement::Preformatted(e) => {
+            let text: String = trimmed_inlines(&e.elements)
+                .iter()
+                .map(inline_text)
+                .collect();
+            style(&clean(&text), CYAN)
+        }
+        RichTextElement::Unknown(_) => String::new(),
+    }
+}
+
+fn inlines(elements: &[RichTextInline]) -> String {
+    trimmed_inlines(elements).iter().map(inline).collect()
+}
+
+fn inline(element: &RichTextInline) -> String {
+    let mut text = clean(&inline_text(element));
+    match element {
+        RichTextInline::Link { .. } => text = style(&text, UNDERLINE),
+        RichTextInline::User { .. }
+        | RichTextInline::Channel { .. }
+        | RichTextInline::Usergroup { .. }
+        | RichTextInline::Team { .. }
+        | RichTextInline::Broadcast { .. } => text = style(&text, BLUE),
+        _ => {}
+    }
+    if let Some(e) = element.style() {
+        for (on, codes) in &[
+            (e.bold, BOLD),
+            (e.italic, ITALIC),
+            (e.strike, STRIKE),
+            (e.code, CYAN),
+        ] {
+            if *on == Some(true) {
+                text = style(&text, *codes);
+            }
+        }
+    }
+    text
+}
+
+// Puts fields side by side in pairs, the way Slack lays them out.
+fn columns(fields: &[String]) -> Vec<String> {
+    let column = (WIDTH - 2) / 2;
+    let mut lines = Vec::new();
+    for pair in fields.chunks(2) {
+        let left = wrap(&pair[0], column);
+        let right = pair.get(1).map(|e| wrap(e, column)).unwrap_or_default();
+        for idx in 0..left.len().max(right.len()) {
+            let left = left.get(idx).map(String::as_str).unwrap_or_default();
+            match right.get(idx) {
+                Some(right) => lines.push(format!("{}  {}", pad(left, column), right)),
+                None => lines.push(left.to_string()),
+            }
+        }
+    }
+    lines
+}
+
+fn boxed(lines: &[String]) -> String {
+    let inner = lines.iter().map(|e| width(e)).max().unwrap_or(0);
+    let mut out = vec![format!("┌{}┐", "─".repeat(inner + 2))];
+    for line in lines {
+        out.push(format!("│ {} │", pad(line, inner)));
+    }
+    out.push(format!("└{}┘", "─".repeat(inner + 2)));
+    out.join("\n")
+}
+
+fn pad(text: &str, to: usize) -> String {
+    let fill = to.saturating_sub(width(text));
+    format!("{}{}", text, " ".repeat(fill))
+}
+
+// Wraps at spaces so no line is wider than `max`, where possible. Words that
+// are too long on their own are left whole. Styles are closed at the end of
+// each line and opened again on the next, so they don't run into a box's
+// border.
+fn wrap(text: &str, max: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split(' ') {
+            if !line.is_empty() && width(&line) + 1 + width(word) > max {
+                lines.push(std::mem::take(&mut line));
+            } else if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        lines.push(line);
+    }
+    let mut open: Vec<(&str, &str)> = Vec::new();
+    lines
+        .into_iter()
+        .map(|line| {
+            let mut out: String = open.iter().map(|e| e.0).collect();
+            out.push_str(&line);
+            for code in escapes(&line) {
+                match STYLES.iter().find(|e| e.0 == code) {
+                    Some(style) => open.push(*style),
+                    None => open.retain(|e| e.1 != code),
+                }
+            }
+            out.extend(open.iter().rev().map(|e| e.1));
+            out
+        })
+        .collect()
+}
+
+fn escapes(text: &str) -> Vec<&str> {
+    text.match_indices('\x1b')
+        .filter_map(|(start, _)| {
+            let end = text[start..].find('m')?;
+            Some(&text[start..=start + end])
+        })
+        .collect()
+}
+
+// The number of columns text takes up, skipping escape codes. This counts
+// every character as one column, which is close enough for most text.
+fn width(text: &str) -> usize {
+    let mut width = 0;
+    let mut in_escape = false;
+    for c in text.chars() {
+        match (in_escape, c) {
+            (false, '\x1b') => in_escape = true,
+            (false, _) => width += 1,
+            (true, 'm') => in_escape = false,
+            (true, _) => {}
+        }
+    }
+    width
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::elements::ButtonBuilder;
+    use crate::objects::{Mrkdwn, PlainText};
+    use crate::surfaces::MessageBlock;
+
+    #[test]
+    fn section() {
+        let section = SectionBuilder::new(Mrkdwn::new("*Deploy* _done_").into())
+            .set_fields(vec![
+                PlainText::new("Version").into(),
+                PlainText::new("Region").into(),
+                PlainText::new("1.2.3").into(),
+            ])
+            .set_accessory(
+                ButtonBuilder::new("logs", PlainText::new("Logs"))
+                    .build()
+                    .into(),
+            )
+            .build();
+        // "Version", padded to a full column, then "  Region".
+        let inner = (WIDTH - 2) / 2 + 2 + "Region".len();
+        let line = |text: &str, width: usize| format!("│ {}{} │", text, " ".repeat(inner - width));
+        let expected = [
+            format!("┌{}┐", "─".repeat(inner + 2)),
+            line("\x1b[1mDeploy\x1b[22m \x1b[3mdone\x1b[23m", 11),
+            line(&format!("{:1$}  Region", "Version", (WIDTH - 2) / 2), inner),
+            line("1.2.3", 5),
+            line("\x1b[1m[Logs]\x1b[22m", 6),
+            format!("└{}┘", "─".repeat(inner + 2)),
+        ];
+        assert_eq!(
+            ansi_text(&[MessageBlock::Section(section)]),
+            expected.join("\n")
+        );
+    }
+
+    #[test]
+    fn wrapping() {
+        assert_eq!(
+            wrap("one two three\nfour", 7),
+            vec!["one two", "three", "four"]
+        );
+        assert_eq!(width("\x1b[1mbold\x1b[22m"), 4);
+        assert_eq!(
+            wrap("\x1b[1mone two\x1b[22m", 3),
+            vec!["\x1b[1mone\x1b[22m", "\x1b[1mtwo\x1b[22m"]
+        );
+    }
+
+    #[test]
+    fn bold_in_context() {
+        let context = Context::new(vec![Mrkdwn::new("by *bob* today").into()]);
+        assert_eq!(
+            block(AnyBlock::Context(&context)),
+            "\x1b[2mby \x1b[1mbob\x1b[22m\x1b[2m today\x1b[22m"
+        );
+    }
+
+    #[test]
+    fn control_characters() {
+        let title = PlainText::new("\x1b]0;pwned\x07hi\u{9b}31m\nthere");
+        assert_eq!(text(&title.into()), "]0;pwnedhi31m\nthere");
+        let mrkdwn = Mrkdwn::new("*\x1b[8mhidden*");
+        assert_eq!(text(&mrkdwn.into()), "\x1b[1m[8mhidden\x1b[22m");
+    }
+}
@@ -0,0 +1,573 @@
+// A static approximation of how Slack lays out blocks, for previewing them
+// without Block Kit Builder. Interactive elements are drawn, but do nothing.
+use crate::blocks::*;
+use crate::elements::*;
+use crate::objects::rich_text::*;
+use crate::objects::{OptionInput, PlainText, Text};
+use crate::render::mrkdwn::{self, Node};
+use crate::render::{inline_text, trimmed_inlines, AnyBlock, Block};
+use crate::surfaces::{HomeTab, MessageBlock, Modal};
+
+const STYLE: &str = r#"
+body { margin: 0; padding: 24px; background: #f8f8f8; color: #1d1c1d; font: 15px/1.47 -apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, Helvetica, Arial, sans-serif; }
+.surface { max-width: 600px; margin: 0 auto; padding: 16px 20px; background: #fff; border: 1px solid #ddd; border-radius: 8px; }
+.title { margin-bottom: 12px; font-size: 22px; font-weight: 900; }
+.footer { display: flex; justify-content: flex-end; gap: 8px; margin-top: 16px; }
+.block { margin: 8px 0; }
+.header { font-size: 18px; font-weight: 900; }
+.section { display: flex; justify-content: space-between; gap: 12px; }
+.fields { display: grid; grid-template-columns: 1fr 1fr; gap: 8px 16px; margin-top: 8px; }
+.accessory img { width: 88px; height: 88px; object-fit: cover; border-radius: 4px; }
+.context { display: flex; align-items: center; gap: 6px; color: #616061; font-size: 13px; }
+.context img { width: 20px; height: 20px; border-radius: 4px; }
+.image img, .video img { max-width: 100%; border-radius: 4px; }
+.image-title, .video-title { font-weight: 700; }
+.actions { display: flex; flex-wrap: wrap; gap: 8px; }
+.unsupported { padding: 8px; color: #616061; border: 1px dashed #bbb; border-radius: 4px; }
+hr { margin: 16px 0; border: none; border-top: 1px solid #ddd; }
+button { padding: 4px 12px; color: #1d1c1d; background: #fff; border: 1px solid #bbb; border-radius: 4px; font: inherit; font-size: 14px; font-weight: 700; }
+button.primary { color: #fff; background: #007a5a; border-color: #007a5a; }
+button.danger { color: #fff; background: #e01e5a; border-color: #e01e5a; }
+label { display: block; margin-bottom: 4px; font-weight: 700; }
+.optional, .hint { color: #616061; font-weight: 400; }
+.hint { margin-top: 4px; font-size: 13px; }
+input, select, textarea { box-sizing: border-box; width: 100%; padding: 6px 8px; border: 1px solid #bbb; border-radius: 4px; font: inherit; }
+.choice { display: flex; align-items: center; gap: 6px; font-weight: 400; }
+.choice input { width: auto; }
+.mention { padding: 0 2px; color: #1264a3; background: #e8f5fa; border-radius: 3px; }
+a { color: #1264a3; text-decoration: none; }
+code, pre { font-family: Monaco, Menlo, Consolas, monospace; font-size: 12px; background: #f6f6f6; border: 1px solid #ddd; }
+code { padding: 1px 3px; color: #e01e5a; border-radius: 3px; }
+pre { margin: 4px 0; padding: 8px; white-space: pre-wrap; border-radius: 4px; }
+blockquote { margin: 0; padding-left: 12px; border-left: 4px solid #ddd; }
+"#;
+
+/// Renders a modal as a standalone HTML page.
+pub fn modal_html(modal: &Modal) -> String {
+    let mut body = format!(
+        "<div class=\"title\">{}</div>\n",
+        escape(modal.title.text())
+    );
+    body.push_str(&blocks(&modal.blocks));
+    let buttons: String = [&modal.close, &modal.submit]
+        .iter()
+        .zip(&["", " class=\"primary\""])
+        .filter_map(|(text, class)| {
+            text.as_ref()
+                .map(|e| format!("<button{}>{}</button>", class, escape(e.text())))
+        })
+        .collect();
+    if !buttons.is_empty() {
+        body.push_str(&format!("<div class=\"footer\">{}</div>\n", buttons));
+    }
+    page(modal.title.text(), "modal", &body)
+}
+
+/// Renders a home tab as a standalone HTML page.
+pub fn home_tab_html(home_tab: &HomeTab) -> String {
+    page("Home", "home", &blocks(&home_tab.blocks))
+}
+
+/// Renders a message's blocks as a standalone HTML page.
+pub fn message_html(blocks: &[MessageBlock]) -> String {
+    page("Message", "message", &self::blocks(blocks))
+}
+
+fn page(title: &str, kind: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n<div class=\"surface {}\">\n{}</div>\n</body>\n</html>\n",
+        escape(title),
+        STYLE,
+        kind,
+        body
+    )
+}
+
+fn blocks<B: Block>(blocks: &[B]) -> String {
+    blocks
+        .iter()
+        .map(|e| format!("{}\n", block(e.as_any())))
+        .collect()
+}
+
+fn block(block: AnyBlock) -> String {
+    match block {
+        AnyBlock::Actions(e) => {
+            let elements: String = e
+                .elements
+                .iter()
+                .map(|e| match e {
+                    ActionsElement::Button(e) => button(e),
+                    ActionsElement::Checkboxes(e) => checkboxes(e),
+                    ActionsElement::DatePicker(e) => date_picker(e),
+                    ActionsElement::DateTimePicker(e) => date_time_picker(e),
+                    ActionsElement::OverflowMenu(_) => overflow(),
+                    ActionsElement::PlainTextInput(e) => plain_text_input(e),
+                    ActionsElement::RadioButtonGroup(e) => radio_buttons(e),
+                    ActionsElement::SelectMenu(e) => select(&e.placeholder),
+                    ActionsElement::TimePicker(e) => time_picker(e),
+                    ActionsElement::Unknown(_) => String::new(),
+                })
+                .collect();
+            format!("<div class=\"block actions\">{}</div>", elements)
+        }
+        AnyBlock::Call => "<div class=\"block unsupported\">Call</div>".to_string(),
+        AnyBlock::Context(e) => {
+            let elements: String = e
+                .elements
+                .iter()
+                .map(|e| match e {
+                    ContextElement::Image(e) => image(&e.image_url, &e.alt_text),
+                    ContextElement::Text(e) => format!("<span>{}</span>", text(e)),
+                    ContextElement::Unknown(_) => String::new(),
+                })
+                .collect();
+            format!("<div class=\"block context\">{}</div>", elements)
+        }
+        AnyBlock::Divider => "<hr>".to_string(),
+        AnyBlock::File => "<div class=\"block unsupported\">Remote file</div>".to_string(),
+        AnyBlock::Header(e) => format!(
+            "<div class=\"block header\">{}</div>",
+            escape(e.text.text())
+        ),
+        AnyBlock::Image(e) => {
+            let title = e
+                .title
+                .as_ref()
+                .map(|e| format!("<div class=\"image-title\">{}</div>", escape(e.text())))
+                .unwrap_or_default();
+            format!(
+                "<div class=\"block image\">{}{}</div>",
+                title,
+                image(&e.image_url, &e.alt_text)
+            )
+        }
+        AnyBlock::Input(e) => {
+            let optional = match e.optional {
+                Some(true) => " <span class=\"optional\">(optional)</span>",
+                _ => "",
+            };
+            let hint = e
+                .hint
+                .as_ref()
+                .map(|e| format!("<div class=\"hint\">{}</div>", escape(e.text())))
+                .unwrap_or_default();
+            format!(
+                "<div class=\"block input\"><label>{}{}</label>{}{}</div>",
+                escape(e.label.text()),
+                optional,
+                input_element(&e.element),
+                hint
+            )
+        }
+        AnyBlock::RichText(e) => format!(
+            "<div class=\"block rich-text\">{}</div>",
+            e.elements.iter().map(rich_text).collect::<String>()
+        ),
+        AnyBlock::Section(e) => {
+            let fields = match &e.fields {
+                Some(fields) => format!(
+                    "<div class=\"fields\">{}</div>",
+                    fields
+                        .iter()
+                        .map(|e| format!("<div>{}</div>", text(e)))
+                        .collect::<String>()
+                ),
+                None => String::new(),
+            };
+            let accessory = match &e.accessory {
+                Some(e) => format!("<div class=\"accessory\">{}</div>", section_element(e)),
+                None => String::new(),
+            };
+            format!(
+                "<div class=\"block section\"><div><div>{}</div>{}</div>{}</div>",
+                e.text.as_ref().map(text).unwrap_or_default(),
+                fields,
+                accessory
+            )
+        }
+        AnyBlock::Video(e) => {
+            let description = e
+                .description
+                .as_ref()
+                .map(|e| format!("<div>{}</div>", escape(e.text())))
+                .unwrap_or_default();
+            format!(
+                "<div class=\"block video\"><div class=\"video-title\">{}</div>{}{}</div>",
+                escape(e.title.text()),
+                description,
+                link(e.video_url.as_str(), image(&e.thumbnail_url, &e.alt_text))
+            )
+        }
+        AnyBlock::Unknown => "<div class=\"block unsupported\">Unsupported block</div>".to_string(),
+    }
+}
+
+fn section_element(element: &SectionElement) -> String {
+    match element {
+        SectionElement::Button(e) => button(e),
+        SectionElement::Checkboxes(e) => checkboxes(e),
+        SectionElement::DatePicker(e) => date_picker(e),
+        SectionElement::Image(e) => image(&e.image_url, &e.alt_text),
+        SectionElement::MultiSelectMenu(e) => select(&e.placeholder),
+        SectionElement::OverflowMenu(_) => overflow(),
+        SectionElement::PlainTextInput(e) => plain_text_input(e),
+        SectionElement::RadioButtonGroup(e) => radio_buttons(e),
+        SectionElement::SelectMenu(e) => select(&e.placeholder),
+        SectionElement::TimePicker(e) => time_picker(e),
+        SectionElement::Unknown(_) => String::new(),
+    }
+}
+
+fn input_element(element: &InputElement) -> String {
+    match element {
+        InputElement::Checkboxes(e) => checkboxes(e),
+        InputElement::DatePicker(e) => date_picker(e),
+        InputElement::DateTimePicker(e) => date_time_picker(e),
+        InputElement::EmailInput(e) => input("email", &e.placeholder, &e.initial_value),
+        InputElement::FileInput(e) => {
+            let accept: Vec<String> = e.filetypes.iter().map(|e| format!(".{}", e)).collect();
+            let multiple = match e.max_files {
+                Some(1) => "",
+                _ => " multiple",
+            };
+            format!(
+                "<input type=\"file\" accept=\"{}\"{}>",
+                escape(&accept.join(",")),
+                multiple
+            )
+        }
+        InputElement::MultiSelectMenu(e) => select(&e.placeholder),
+        InputElement::NumberInput(e) => {
+            let kind = if e.is_decimal_allowed {
+                "number\" step=\"any"
+            } else {
+                "number"
+            };
+            input(kind, &e.placeholder, &e.initial_value)
+        }
+        InputElement::PlainTextInput(e) => plain_text_input(e),
+        InputElement::RadioButtonGroup(e) => radio_buttons(e),
+        InputElement::RichTextInput(e) => textarea(&e.placeholder, &None),
+        InputElement::SelectMenu(e) => select(&e.placeholder),
+        InputElement::TimePicker(e) => time_picker(e),
+        InputElement::UrlInput(e) => input("url", &e.placeholder, &e.initial_value),
+        InputElement::Unknown(_) => String::new(),
+    }
+}
+
+fn button(button: &Button) -> String {
+    let class = match button.style {
+        Some(ButtonStyle::Primary) => " class=\"primary\"",
+        Some(ButtonStyle::Danger) => " class=\"danger\"",
+        _ => "",
+    };
+    let label = format!("<button{}>{}</button>", class, escape(button.text.text()));
+    match &button.url {
+        Some(url) => link(url.as_str(), label),
+        None => label,
+    }
+}
+
+fn image(url: &url::Url, alt_text: &str) -> String {
+    format!(
+        "<img src=\"{}\" alt=\"{}\">",
+        escape(url.as_str()),
+        escape(alt_text)
+    )
+}
+
+fn checkboxes(e: &Checkboxes) -> String {
+    let checked = e.initial_options.as_deref().unwrap_or_default();
+    choices("checkbox", &e.options, |option| checked.contains(option))
+}
+
+fn radio_buttons(e: &RadioButtonGroup) -> String {
+    choices("radio", &e.options, |option| {
+        e.initial_option.as_ref() == Some(option)
+    })
+}
+
+fn choices<F>(kind: &str, options: &[OptionInput], is_checked: F) -> String
+where
+    F: Fn(&OptionInput) -> bool,
+{
+    options
+        .iter()
+        .map(|option| {
+            let checked = if is_checked(option) { " checked" } else { "" };
+            format!(
+                "<label class=\"choice\"><input type=\"{}\"{}>{}</label>",
+                kind,
+                checked,
+                text(option.text())
+            )
+        })
+        .collect()
+}
+
+fn date_picker(e: &DatePicker) -> String {
+    let value = e.initial_date.map(|e| e.format("%Y-%m-%d").to_string());
+    input("date", &e.placeholder, &value)
+}
+
+fn date_time_picker(e: &DateTimePicker) -> String {
+    let value = e
+        .initial_date_time
+        .map(|e| e.format("%Y-%m-%dT%H:%M").to_string());
+    input("datetime-local", &None, &value)
+}
+
+fn time_picker(e: &TimePicker) -> String {
+    let value = e.initial_time.map(|e| e.format("%H:%M").to_string());
+    input("time", &e.placeholder, &value)
+}
+
+fn plain_text_input(e: &PlainTextInput) -> String {
+    match e.multiline {
+        Some(true) => textarea(&e.placeholder, &e.initial_value),
+        _ => input("text", &e.placeholder, &e.initial_value),
+    }
+}
+
+fn input(kind: &str, placeholder: &Option<PlainText>, value: &Option<String>) -> String {
+    format!(
+        "<input type=\"{}\"{}{}>",
+        kind,
+        placeholder_attr(placeholder),
+        value
+            .as_ref()
+            .map(|e| format!(" value=\"{}\"", escape(e)))
+            .unwrap_or_default()
+    )
+}
+
+fn textarea(placeholder: &Option<PlainText>, value: &Option<String>) -> String {
+    format!(
+        "<textarea{}>{}</textarea>",
+        placeholder_attr(placeholder),
+        escape(value.as_deref().unwrap_or_default())
+    )
+}
+
+fn placeholder_attr(placeholder: &Option<PlainText>) -> String {
+    placeholder
+        .as_ref()
+        .map(|e| format!(" placeholder=\"{}\"", escape(e.text())))
+        .unwrap_or_default()
+}
+
+fn select(placeholder: &PlainText) -> String {
+    format!(
+        "<select><option>{}</option></select>",
+        escape(placeholder.text())
+    )
+}
+
+fn overflow() -> String {
+    "<button>&#8942;</button>".to_string()
+}
+
+fn text(text: &Text) -> String {
+    match text {
+        Text::PlainText(e) => escape(e.text()).replace('\n', "<br>"),
+        Text::Mrkdwn(e) => nodes(&mrkdwn::parse(e.text())),
+    }
+}
+
+fn nodes(nodes: &[Node]) -> String {
+    let mut out = String::new();
+    let mut after_block = false;
+    for node in nodes {
+        match node {
+            Node::Text(e) => {
+                // Quotes and code blocks already sit on their own lines.
+                let e = if after_block {
+                    e.strip_prefix('\n').unwrap_or(e)
+                } else {
+                    e
+                };
+                out.push_str(&escape(e).replace('\n', "<br>"));
+            }
+            Node::Bold(e) => out.push_str(&format!("<b>{}</b>", self::nodes(e))),
+            Node::Italic(e) => out.push_str(&format!("<i>{}</i>", self::nodes(e))),
+            Node::Strike(e) => out.push_str(&format!("<s>{}</s>", self::nodes(e))),
+            Node::Code(e) => out.push_str(&format!("<code>{}</code>", escape(e))),
+            Node::CodeBlock(e) => out.push_str(&format!("<pre>{}</pre>", escape(e))),
+            Node::Quote(e) => out.push_str(&format!("<blockquote>{}</blockquote>", self::nodes(e))),
+            Node::Link { url, label } => {
+                out.push_str(&link(url, escape(label.as_deref().unwrap_or(url))))
+            }
+            Node::Entity(e) => {
+                out.push_str(&format!("<span class=\"mention\">{}</span>", escape(e)))
+            }
+        }
+        after_block = matches!(node, Node::Quote(_) | Node::CodeBlock(_));
+    }
+    out
+}
+
+fn rich_text(element: &RichTextElement) -> String {
+    match element {
+        RichTextElement::Section(e) => format!("<div>{}</div>", inlines(&e.elements)),
+        RichTextElement::List(e) => {
+            let items: String = e
+                .elements
+                .iter()
+                .map(|item| format!("<li>{}</li>", inlines(&item.elements)))
+                .collect();
+            let indent = e.indent.unwrap_or(0) * 24;
+            match e.style {
+                ListStyle::Bullet => {
+                    format!("<ul style=\"margin-left: {}px\">{}</ul>", indent, items)
+                }
+                ListStyle::Ordered => format!(
+                    "<ol start=\"{}\" style=\"margin-left: {}px\">{}</ol>",
+                    e.offset.unwrap_or(0) + 1,
+                    indent,
+                    items
+                ),
+            }
+        }
+        RichTextElement::Quote(e) => format!("<blockquote>{}</blockquote>", inlines(&e.elements)),
+        RichTextElement::Preformatted(e) => {
+            // Styles don't apply inside preformatted text.
+            let text: String = e.elements.iter().map(|e| escape(&inline_text(e))).collect();
+            format!("<pre>{}</pre>", text)
+        }
+        RichTextElement::Unknown(_) => String::new(),
+    }
+}
+
+fn inlines(elements: &[RichTextInline]) -> String {
+    trimmed_inlines(elements).iter().map(inline).collect()
+}
+
+fn inline(element: &RichTextInline) -> String {
+    let html = match element {
+        RichTextInline::Text { text, .. } => escape(text).replace('\n', "<br>"),
+        RichTextInline::Link { url, .. } => link(url, escape(&inline_text(element))),
+        RichTextInline::User { .. }
+        | RichTextInline::Channel { .. }
+        | RichTextInline::Usergroup { .. }
+        | RichTextInline::Team { .. }
+        | RichTextInline::Broadcast { .. } => format!(
+            "<span class=\"mention\">{}</span>",
+            escape(&inline_text(element))
+        ),
+        _ => escape(&inline_text(element)),
+    };
+    match element.style() {
+        Some(style) => {
+            let mut html = html;
+            for (on, tag) in &[
+                (style.code, "code"),
+                (style.strike, "s"),
+                (style.italic, "i"),
+                (style.bold, "b"),
+            ] {
+                if *on == Some(true) {
+                    html = format!("<{}>{}</{}>", tag, html, tag);
+                }
+            }
+            html
+        }
+        None => html,
+    }
+}
+
+// Links to anything but the web or email, e.g. `javascript:` URLs, could run
+// script in the page, so those only show their label.
+fn link(url: &str, label: String) -> String {
+    let scheme = url.find(':').map(|idx| &url[..idx]).unwrap_or_default();
+    let allowed = ["http", "https", "mailto"]
+        .iter()
+        .any(|e| scheme.eq_ignore_ascii_case(e));
+    if allowed {
+        format!("<a href=\"{}\">{}</a>", escape(url), label)
+    } else {
+        label
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::objects::Mrkdwn;
+    use crate::surfaces::{ModalBlock, ModalBuilder};
+
+    #[test]
+    fn section() {
+        let section =
+            SectionBuilder::new(Mrkdwn::new("*Hi* <https://example.com|there> & co").into())
+                .set_fields(vec![PlainText::new("<b>not bold</b>").into()])
+                .set_accessory(
+                    ButtonBuilder::new("go", PlainText::new("Go"))
+                        .set_style(ButtonStyle::Primary)
+                        .build()
+                        .into(),
+                )
+                .build();
+        assert_eq!(
+            block(AnyBlock::Section(&section)),
+            r#"<div class="block section"><div><div><b>Hi</b> <a href="https://example.com">there</a> &amp; co</div><div class="fields"><div>&lt;b&gt;not bold&lt;/b&gt;</div></div></div><div class="accessory"><button class="primary">Go</button></div></div>"#
+        );
+    }
+
+    #[test]
+    fn unsafe_links() {
+        let section = SectionBuilder::new(
+            Mrkdwn::new("<javascript:alert(1)|click> <mailto:a@example.com|mail>").into(),
+        )
+        .build();
+        assert_eq!(
+            block(AnyBlock::Section(&section)),
+            r#"<div class="block section"><div><div>click <a href="mailto:a@example.com">mail</a></div></div></div>"#
+        );
+        let link = RichTextInline::named_link("JavaScript:alert(1)", "click");
+        assert_eq!(inline(&link), "click");
+    }
+
+    #[test]
+    fn modal() {
+        let input = InputBuilder::new(
+            PlainText::new("Name"),
+            PlainTextInputBuilder::new("name")
+                .set_placeholder(PlainText::new("Your name"))
+                .build()
+                .into(),
+        )
+        .set_hint(PlainText::new("As it appears on your badge"))
+        .set_optional(true)
+        .build();
+        let modal = ModalBuilder::new(PlainText::new("Sign up"), vec![ModalBlock::Input(input)])
+            .set_submit(PlainText::new("Submit"))
+            .build();
+        let html = modal_html(&modal);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(r#"<div class="title">Sign up</div>
+<div class="block input"><label>Name <span class="optional">(optional)</span></label><input type="text" placeholder="Your name"><div class="hint">As it appears on your badge</div></div>
+<div class="footer"><button class="primary">Submit</button></div>"#));
+    }
+
+    #[test]
+    fn rich_text_styles() {
+        let element = RichTextInline::text("a < b").bold().code();
+        assert_eq!(inline(&element), "<b><code>a &lt; b</code></b>");
+    }
+}
@@ -0,0 +1,179 @@
+// Turning blocks into other formats, e.g. the plain text fallback Slack shows
+// in notifications and reads out to screen readers.
+use crate::blocks::*;
+use crate::objects::rich_text::{BroadcastRange, ListStyle, RichTextInline, RichTextList};
+use crate::surfaces::{HomeTabBlock, MessageBlock, ModalBlock};
+
+use chrono::DateTime;
+
+mod ansi;
+#[cfg(feature = "html")]
+mod html;
+mod mrkdwn;
+mod plain;
+
+pub use crate::render::ansi::ansi_text;
+#[cfg(feature = "html")]
+pub use crate::render::html::{home_tab_html, message_html, modal_html};
+pub use crate::render::plain::plain_text;
+
+pub(crate) use crate::render::sealed::AnyBlock;
+
+/// A block from any surface, i.e. a `MessageBlock`, `ModalBlock` or
+/// `HomeTabBlock`.
+pub trait Block: sealed::Sealed {}
+
+impl Block for MessageBlock {}
+impl Block for ModalBlock {}
+impl Block for HomeTabBlock {}
+
+// Used for dates that don't come with a fallback of their own.
+pub(crate) fn format_timestamp(timestamp: i64) -> String {
+    match DateTime::from_timestamp(timestamp, 0) {
+        Some(date) => date.format("%Y-%m-%d %H:%M UTC").to_string(),
+        None => timestamp.to_string(),
+    }
+}
+
+// The text an inline rich text element shows, before any styling.
+pub(crate) fn inline_text(element: &RichTextInline) -> String {
+    match element {
+        RichTextInline::Text { text, .. } => text.clone(),
+        RichTextInline::Link { url, text, .. } => text.clone().unwrap_or_else(|| url.clone()),
+        RichTextInline::Emoji { name, unicode, .. } => unicode
+            .as_deref()
+            .and_then(emoji)
+            .unwrap_or_else(|| format!(":{}:", name)),
+        RichTextInline::User { user_id, .. } => format!("@{}", user_id),
+        RichTextInline::Channel { channel_id, .. } => format!("#{}", channel_id),
+        RichTextInline::Usergroup { usergroup_id, .. } => format!("@{}", usergroup_id),
+        RichTextInline::Team { team_id, .. } => team_id.clone(),
+        RichTextInline::Broadcast { range } => match range {
+            BroadcastRange::Here => "@here".to_string(),
+            BroadcastRange::Channel => "@channel".to_string(),
+            BroadcastRange::Everyone => "@everyone".to_string(),
+        },
+        RichTextInline::Date {
+            timestamp,
+            fallback,
+            ..
+        } => fallback
+            .clone()
+            .unwrap_or_else(|| format_timestamp(*timestamp)),
+        RichTextInline::Color { value } => value.clone(),
+        RichTextInline::Unknown(_) => String::new(),
+    }
+}
+
+// A section's inline elements without the newline that sections Slack sends
+// back usually end with.
+pub(crate) fn trimmed_inlines(elements: &[RichTextInline]) -> Vec<RichTextInline> {
+    let mut elements = elements.to_vec();
+    while let Some(RichTextInline::Text { text, .. }) = elements.last_mut() {
+        let len = text.trim_end_matches('\n').len();
+        if len > 0 {
+            text.truncate(len);
+            break;
+        }
+        elements.pop();
+    }
+    elements
+}
+
+// What goes before a list item in text output: its nesting and its marker,
+// e.g. "  3. ".
+pub(crate) fn list_item_prefix(list: &RichTextList, idx: usize) -> String {
+    let indent = "  ".repeat(list.indent.unwrap_or(0) as usize);
+    match list.style {
+        ListStyle::Bullet => format!("{}• ", indent),
+        ListStyle::Ordered => format!(
+            "{}{}. ",
+            indent,
+            list.offset.unwrap_or(0) as usize + idx + 1
+        ),
+    }
+}
+
+// Slack gives emoji as dash-separated code points, e.g. "1f44b-1f3fd".
+fn emoji(unicode: &str) -> Option<String> {
+    unicode
+        .split('-')
+        .map(|e| u32::from_str_radix(e, 16).ok().and_then(char::from_u32))
+        .collect()
+}
+
+mod sealed {
+    use super::*;
+
+    // Every surface allows a different subset of blocks, so the renderers
+    // work on this instead of handling each surface's enum separately.
+    pub enum AnyBlock<'a> {
+        Actions(&'a Actions),
+        Call,
+        Context(&'a Context),
+        Divider,
+        File,
+        Header(&'a Header),
+        Image(&'a Image),
+        Input(&'a Input),
+        RichText(&'a RichText),
+        Section(&'a Section),
+        Video(&'a Video),
+        Unknown,
+    }
+
+    pub trait Sealed {
+        fn as_any(&self) -> AnyBlock<'_>;
+    }
+
+    impl Sealed for MessageBlock {
+        fn as_any(&self) -> AnyBlock<'_> {
+            match self {
+                MessageBlock::Actions(e) => AnyBlock::Actions(e),
+                MessageBlock::Call(_) => AnyBlock::Call,
+                MessageBlock::Context(e) => AnyBlock::Context(e),
+                MessageBlock::Divider(_) => AnyBlock::Divider,
+                MessageBlock::File(_) => AnyBlock::File,
+                MessageBlock::Header(e) => AnyBlock::Header(e),
+                MessageBlock::Image(e) => AnyBlock::Image(e),
+                MessageBlock::RichText(e) => AnyBlock::RichText(e),
+                MessageBlock::Section(e) => AnyBlock::Section(e),
+                MessageBlock::Video(e) => AnyBlock::Video(e),
+                MessageBlock::Unknown(_) => AnyBlock::Unknown,
+            }
+        }
+    }
+
+    impl Sealed for ModalBlock {
+        fn as_any(&self) -> AnyBlock<'_> {
+            match self {
+                ModalBlock::Actions(e) => AnyBlock::Actions(e),
+                ModalBlock::Context(e) => AnyBlock::Context(e),
+                ModalBlock::Divider(_) => AnyBlock::Divider,
+                ModalBlock::Header(e) => AnyBlock::Header(e),
+                ModalBlock::Image(e) => AnyBlock::Image(e),
+                ModalBlock::Input(e) => AnyBlock::Input(e),
+                ModalBlock::RichText(e) => AnyBlock::RichText(e),
+                ModalBlock::Section(e) => AnyBlock::Section(e),
+                ModalBlock::Video(e) => AnyBlock::Video(e),
+                ModalBlock::Unknown(_) => AnyBlock::Unknown,
+            }
+        }
+    }
+
+    impl Sealed for HomeTabBlock {
+        fn as_any(&self) -> AnyBlock<'_> {
+            match self {
+                HomeTabBlock::Actions(e) => AnyBlock::Actions(e),
+                HomeTabBlock::Context(e) => AnyBlock::Context(e),
+                HomeTabBlock::Divider(_) => AnyBlock::Divider,
+                HomeTabBlock::Header(e) => AnyBlock::Header(e),
+                HomeTabBlock::Image(e) => AnyBlock::Image(e),
+                HomeTabBlock::RichText(e) => AnyBlock::RichText(e),
+                HomeTabBlock::Section(e) => AnyBlock::Section(e),
+                HomeTabBlock::Video(e) => AnyBlock::Video(e),
+                HomeTabBlock::Unknown(_) => AnyBlock::Unknown,
+            }
+        }
+    }
+}
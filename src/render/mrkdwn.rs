@@ -0,0 +1,243 @@
+// Reference: https://api.slack.com/reference/surfaces/formatting
+//
+// A small parser for the mrkdwn Slack documents, shared by the renderers.
+// Anything it doesn't recognise, e.g. an unclosed `*`, is kept as text.
+use crate::render::format_timestamp;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Node {
+    // Already unescaped.
+    Text(String),
+    Bold(Vec<Node>),
+    Italic(Vec<Node>),
+    Strike(Vec<Node>),
+    Code(String),
+    CodeBlock(String),
+    // One quoted line, without its marker.
+    Quote(Vec<Node>),
+    Link { url: String, label: Option<String> },
+    // A mention, broadcast or date, as the text Slack shows for it.
+    Entity(String),
+}
+
+pub(crate) fn parse(text: &str) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    let mut rest = text;
+    while let Some((before, code, after)) = split_code_block(rest) {
+        lines(before, &mut nodes);
+        let code = code.strip_prefix('\n').unwrap_or(code);
+        let code = code.strip_suffix('\n').unwrap_or(code);
+        nodes.push(Node::CodeBlock(unescape(code)));
+        rest = after;
+    }
+    lines(rest, &mut nodes);
+    nodes
+}
+
+fn split_code_block(text: &str) -> Option<(&str, &str, &str)> {
+    let start = text.find("```")?;
+    let len = text[start + 3..].find("```")?;
+    let end = start + 3 + len;
+    Some((&text[..start], &text[start + 3..end], &text[end + 3..]))
+}
+
+fn lines(text: &str, nodes: &mut Vec<Node>) {
+    for line in text.split_inclusive('\n') {
+        let (content, newline) = match line.strip_suffix('\n') {
+            Some(content) => (content, true),
+            None => (line, false),
+        };
+        let quoted = content
+            .strip_prefix('>')
+            .or_else(|| content.strip_prefix("&gt;"));
+        match quoted {
+            Some(quoted) => {
+                let quoted = quoted.strip_prefix(' ').unwrap_or(quoted);
+                nodes.push(Node::Quote(inline(quoted)));
+            }
+            None => extend(nodes, inline(content)),
+        }
+        if newline {
+            extend(nodes, vec![Node::Text("\n".to_string())]);
+        }
+    }
+}
+
+// Appends nodes, merging neighbouring text so renderers see whole runs.
+fn extend(nodes: &mut Vec<Node>, more: Vec<Node>) {
+    for node in more {
+        match (nodes.last_mut(), node) {
+            (Some(Node::Text(text)), Node::Text(next)) => text.push_str(&next),
+            (_, node) => nodes.push(node),
+        }
+    }
+}
+
+fn inline(text: &str) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    let mut plain = String::new();
+    let mut idx = 0;
+    while let Some(c) = text[idx..].chars().next() {
+        let rest = &text[idx..];
+        let parsed = match c {
+            '`' => rest[1..]
+                .find('`')
+                .map(|end| (Node::Code(unescape(&rest[1..end + 1])), end + 2)),
+            '<' => rest
+                .find('>')
+                .map(|end| (angle_brackets(&rest[1..end]), end + 1)),
+            '*' | '_' | '~' => closing_marker(text, idx, c).map(|end| {
+                let inner = inline(&text[idx + 1..end]);
+                let node = match c {
+                    '*' => Node::Bold(inner),
+                    '_' => Node::Italic(inner),
+                    _ => Node::Strike(inner),
+                };
+                (node, end + 1 - idx)
+            }),
+            _ => None,
+        };
+        match parsed {
+            Some((node, len)) => {
+                if !plain.is_empty() {
+                    nodes.push(Node::Text(unescape(&plain)));
+                    plain.clear();
+                }
+                nodes.push(node);
+                idx += len;
+            }
+            None => {
+                plain.push(c);
+                idx += c.len_utf8();
+            }
+        }
+    }
+    if !plain.is_empty() {
+        nodes.push(Node::Text(unescape(&plain)));
+    }
+    nodes
+}
+
+// Slack only treats a marker as formatting when it hugs a word, e.g. `*bold*`
+// but not `2 * 3 * 4`.
+fn closing_marker(text: &str, start: usize, marker: char) -> Option<usize> {
+    let before = text[..start].chars().next_back();
+    if before.is_some_and(char::is_alphanumeric) {
+        return None;
+    }
+    let inner = &text[start + 1..];
+    if inner.is_empty() || inner.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let mut prev = None;
+    for (idx, c) in inner.char_indices() {
+        if c == marker && idx > 0 && prev.is_some_and(|e: char| !e.is_whitespace()) {
+            let after = inner[idx + 1..].chars().next();
+            if !after.is_some_and(char::is_alphanumeric) {
+                return Some(start + 1 + idx);
+            }
+        }
+        prev = Some(c);
+    }
+    None
+}
+
+fn angle_brackets(inner: &str) -> Node {
+    let (target, label) = match inner.find('|') {
+        Some(idx) => (&inner[..idx], Some(unescape(&inner[idx + 1..]))),
+        None => (inner, None),
+    };
+    if let Some(id) = target.strip_prefix('@') {
+        return Node::Entity(format!("@{}", label.unwrap_or_else(|| unescape(id))));
+    }
+    if let Some(id) = target.strip_prefix('#') {
+        return Node::Entity(format!("#{}", label.unwrap_or_else(|| unescape(id))));
+    }
+    if let Some(command) = target.strip_prefix('!') {
+        if let Some(label) = label {
+            return Node::Entity(label);
+        }
+        let mut parts = command.split('^');
+        let text = match (parts.next(), parts.next()) {
+            (Some("subteam"), Some(id)) => format!("@{}", id),
+            (Some("date"), Some(ts)) => ts.parse().map(format_timestamp).unwrap_or_default(),
+            (Some(name), _) => format!("@{}", name),
+            (None, _) => String::new(),
+        };
+        return Node::Entity(text);
+    }
+    Node::Link {
+        url: unescape(target),
+        label,
+    }
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn text(text: &str) -> Node {
+        Node::Text(text.to_string())
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(
+            parse("*Deploy* _finished_ in `2 * 3` ~s~"),
+            vec![
+                Node::Bold(vec![text("Deploy")]),
+                text(" "),
+                Node::Italic(vec![text("finished")]),
+                text(" in "),
+                Node::Code("2 * 3".to_string()),
+                text(" "),
+                Node::Strike(vec![text("s")]),
+            ]
+        );
+        assert_eq!(
+            parse("2 * 3 * 4 and snake_case_name"),
+            vec![text("2 * 3 * 4 and snake_case_name")]
+        );
+    }
+
+    #[test]
+    fn links_and_mentions() {
+        assert_eq!(
+            parse("<https://example.com|Example &amp; co> <@U123> <#C123|general> <!here> <!subteam^S123>"),
+            vec![
+                Node::Link {
+                    url: "https://example.com".to_string(),
+                    label: Some("Example & co".to_string())
+                },
+                text(" "),
+                Node::Entity("@U123".to_string()),
+                text(" "),
+                Node::Entity("#general".to_string()),
+                text(" "),
+                Node::Entity("@here".to_string()),
+                text(" "),
+                Node::Entity("@S123".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn blocks() {
+        assert_eq!(
+            parse("Run:\n```\ncargo test\n```\n&gt; quoted *text*\n1 &lt; 2"),
+            vec![
+                text("Run:\n"),
+                Node::CodeBlock("cargo test".to_string()),
+                text("\n"),
+                Node::Quote(vec![text("quoted "), Node::Bold(vec![text("text")])]),
+                text("\n1 < 2"),
+            ]
+        );
+    }
+}
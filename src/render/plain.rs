@@ -0,0 +1,173 @@
+use crate::blocks::*;
+use crate::elements::Button;
+use crate::objects::rich_text::*;
+use crate::objects::Text;
+use crate::render::mrkdwn::{self, Node};
+use crate::render::{inline_text, list_item_prefix, trimmed_inlines, AnyBlock, Block};
+
+/// Renders blocks as readable plain text, e.g. for a message's `text`, which
+/// Slack shows in notifications and reads out to screen readers.
+///
+/// Formatting is stripped, links are shown by their label, and buttons and
+/// images are described by their text.
+pub fn plain_text<B: Block>(blocks: &[B]) -> String {
+    join(blocks.iter().map(|e| block(e.as_any())), "\n")
+}
+
+fn block(block: AnyBlock) -> String {
+    match block {
+        AnyBlock::Actions(e) => join(
+            e.elements.iter().map(|e| match e {
+                ActionsElement::Button(e) => button(e),
+                _ => String::new(),
+            }),
+            " ",
+        ),
+        AnyBlock::Call => "[Call]".to_string(),
+        AnyBlock::Context(e) => join(
+            e.elements.iter().map(|e| match e {
+                ContextElement::Image(e) => image(&e.alt_text),
+                ContextElement::Text(e) => text(e),
+                ContextElement::Unknown(_) => String::new(),
+            }),
+            " ",
+        ),
+        AnyBlock::Divider => String::new(),
+        AnyBlock::File => "[File]".to_string(),
+        AnyBlock::Header(e) => e.text.text().to_string(),
+        AnyBlock::Image(e) => image(&e.alt_text),
+        AnyBlock::Input(e) => e.label.text().to_string(),
+        AnyBlock::RichText(e) => join(e.elements.iter().map(rich_text), "\n"),
+        AnyBlock::Section(e) => {
+            let fields = e.fields.iter().flatten().map(text);
+            let accessory = match &e.accessory {
+                Some(SectionElement::Button(e)) => button(e),
+                Some(SectionElement::Image(e)) => image(&e.alt_text),
+                _ => String::new(),
+            };
+            let lines = e.text.iter().map(text).chain(fields).chain(Some(accessory));
+            join(lines, "\n")
+        }
+        AnyBlock::Video(e) => format!("[Video: {}]", e.title.text()),
+        AnyBlock::Unknown => String::new(),
+    }
+}
+
+// Joins the non-empty parts, so blocks with nothing to say don't leave gaps.
+fn join<I: Iterator<Item = String>>(parts: I, sep: &str) -> String {
+    parts
+        .filter(|e| !e.is_empty())
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+fn button(button: &Button) -> String {
+    format!("[{}]", button.text.text())
+}
+
+fn image(alt_text: &str) -> String {
+    format!("[Image: {}]", alt_text)
+}
+
+fn text(text: &Text) -> String {
+    match text {
+        Text::PlainText(e) => e.text().to_string(),
+        Text::Mrkdwn(e) => nodes(&mrkdwn::parse(e.text())),
+    }
+}
+
+fn nodes(nodes: &[Node]) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        match node {
+            Node::Text(e) | Node::Code(e) | Node::CodeBlock(e) | Node::Entity(e) => out.push_str(e),
+            Node::Bold(e) | Node::Italic(e) | Node::Strike(e) | Node::Quote(e) => {
+                out.push_str(&self::nodes(e))
+            }
+            Node::Link { url, label } => match label {
+                Some(label) => out.push_str(label),
+                None => out.push_str(url.strip_prefix("mailto:").unwrap_or(url)),
+            },
+        }
+    }
+    out
+}
+
+fn rich_text(element: &RichTextElement) -> String {
+    match element {
+        RichTextElement::Section(e) => inlines(&e.elements),
+        RichTextElement::List(e) => {
+            let items = e.elements.iter().enumerate().map(|(idx, item)| {
+                format!("{}{}", list_item_prefix(e, idx), inlines(&item.elements))
+            });
+            join(items, "\n")
+        }
+        RichTextElement::Quote(e) => inlines(&e.elements),
+        RichTextElement::Preformatted(e) => inlines(&e.elements),
+        RichTextElement::Unknown(_) => String::new(),
+    }
+}
+
+fn inlines(elements: &[RichTextInline]) -> String {
+    trimmed_inlines(elements).iter().map(inline_text).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::elements::{self, ButtonBuilder};
+    use crate::objects::{Mrkdwn, PlainText};
+    use crate::surfaces::{MessageBlock, ModalBlock};
+
+    #[test]
+    fn message() {
+        let section = SectionBuilder::new(
+            Mrkdwn::new("*api* is live, see <https://example.com/logs|the logs>").into(),
+        )
+        .set_fields(vec![
+            Mrkdwn::new("*Version*\n1.2.3").into(),
+            PlainText::new("Took 4m").into(),
+        ])
+        .set_accessory(
+            ButtonBuilder::new("rollback", PlainText::new("Roll back"))
+                .build()
+                .into(),
+        )
+        .build();
+        let avatar = elements::Image::new(
+            "https://example.com/avatar.png".parse().unwrap(),
+            "Avatar".to_string(),
+        );
+        let blocks = vec![
+            MessageBlock::Header(Header::new(PlainText::new("Deploy finished"))),
+            MessageBlock::Section(section),
+            MessageBlock::Divider(Divider::new()),
+            MessageBlock::Context(Context::new(vec![
+                avatar.into(),
+                Text::from(Mrkdwn::new("Deployed by <@U123>")).into(),
+            ])),
+        ];
+        assert_eq!(
+            plain_text(&blocks),
+            "Deploy finished\napi is live, see the logs\nVersion\n1.2.3\nTook 4m\n[Roll back]\n[Image: Avatar] Deployed by @U123"
+        );
+    }
+
+    #[test]
+    fn rich_text() {
+        let list = RichTextList::builder(ListStyle::Ordered)
+            .add_item(vec!["first".into()])
+            .add_item(vec![RichTextInline::emoji("wave")])
+            .build();
+        let section = RichTextSection::new(vec![
+            RichTextInline::text("Hi ").bold(),
+            RichTextInline::broadcast(BroadcastRange::Here),
+            RichTextInline::text("\n"),
+        ]);
+        let blocks = vec![ModalBlock::RichText(RichText::new(vec![
+            section.into(),
+            list.into(),
+        ]))];
+        assert_eq!(plain_text(&blocks), "Hi @here\n1. first\n2. :wave:");
+    }
+}
@@ -0,0 +1,153 @@
+// JSON Schema for blocks, elements and composition objects, for validating
+// block JSON without Rust, e.g. in a frontend or a config linter:
+//
+//     use slack_blocks::schema::schema_for;
+//     use slack_blocks::surfaces::MessageBlock;
+//
+//     let schema = schema_for!(MessageBlock);
+//
+// It's derived from the types themselves, with limits from the same constants
+// `Validate` checks against, so it follows the model as it changes. Rules that span
+// several fields, e.g. a menu's initial option being one of its options, are
+// only checked by `Validate`.
+use schemars::{json_schema, Schema};
+use serde_json::{json, Value};
+
+pub use schemars::{schema_for, JsonSchema};
+
+// A text object of one kind, with the flag only that kind takes.
+pub(crate) fn text_object(kind: &str, flag: &str) -> Schema {
+    json_schema!({
+        "type": "object",
+        "properties": {
+            "type": { "type": "string", "const": kind },
+            "text": { "type": "string" },
+            flag: { "type": "boolean" },
+        },
+        "required": ["type", "text"],
+    })
+}
+
+// Adds the "type" tag serde writes for a struct, which derived schemas leave
+// out.
+pub(crate) fn tagged(kind: &'static str) -> impl FnMut(&mut Schema) {
+    move |schema: &mut Schema| {
+        let object = schema.ensure_object();
+        let properties = object.entry("properties").or_insert_with(|| json!({}));
+        if let Value::Object(properties) = properties {
+            let tag = json!({ "type": "string", "const": kind });
+            properties.insert("type".to_string(), tag);
+        }
+        let required = object.entry("required").or_insert_with(|| json!([]));
+        if let Value::Array(required) = required {
+            required.insert(0, json!("type"));
+        }
+    }
+}
+
+// Limits the `text` of a text object, or of each one in a list of them.
+pub(crate) fn max_text_length(max: usize) -> impl FnMut(&mut Schema) {
+    move |schema: &mut Schema| {
+        let limit = json!({ "text": { "maxLength": max } });
+        match schema.get_mut("items") {
+            Some(Value::Object(items)) => {
+                items.insert("properties".to_string(), limit);
+            }
+            _ => {
+                schema.insert("properties".to_string(), limit);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::blocks::{Header, Section};
+    use crate::elements::{Button, TimePicker};
+    use crate::surfaces::{HomeTabBlock, MessageBlock, ModalBlock};
+
+    fn property(schema: &Schema, name: &str) -> Value {
+        schema.get("properties").unwrap()[name].clone()
+    }
+
+    #[test]
+    fn limits() {
+        let header = schema_for!(Header);
+        assert_eq!(
+            property(&header, "text")["properties"]["text"]["maxLength"],
+            150
+        );
+        assert_eq!(property(&header, "block_id")["maxLength"], 255);
+
+        let section = schema_for!(Section);
+        let fields = property(&section, "fields");
+        assert_eq!(fields["maxItems"], 10);
+        assert_eq!(fields["items"]["properties"]["text"]["maxLength"], 2000);
+
+        let button = schema_for!(Button);
+        assert_eq!(property(&button, "type")["const"], "button");
+        assert_eq!(property(&button, "url")["maxLength"], 3000);
+        assert_eq!(
+            button.get("required").unwrap(),
+            &json!(["type", "text", "action_id"])
+        );
+
+        let timepicker = schema_for!(TimePicker);
+        assert_eq!(
+            property(&timepicker, "initial_time")["pattern"],
+            r"^\d{2}:\d{2}$"
+        );
+    }
+
+    #[test]
+    fn rejects() {
+        let schema = serde_json::to_value(schema_for!(MessageBlock)).unwrap();
+        let validator = jsonschema::validator_for(&schema).unwrap();
+        let header =
+            |text: &str| json!({"type": "header", "text": {"type": "plain_text", "text": text}});
+        assert!(validator.is_valid(&header("Report")));
+        assert!(!validator.is_valid(&header(&"a".repeat(151))));
+        assert!(!validator
+            .is_valid(&json!({"type": "input", "label": {"type": "plain_text", "text": "Name"}})));
+        assert!(
+            !validator.is_valid(&json!({"type": "section", "text": {"type": "mrkdwn", "text": 1}}))
+        );
+    }
+
+    #[test]
+    fn surface_blocks() {
+        let kinds = |schema: Schema| -> Vec<String> {
+            let defs = schema.get("$defs").unwrap().as_object().unwrap();
+            schema
+                .get("anyOf")
+                .unwrap()
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|e| {
+                    let name = e["$ref"].as_str().unwrap().trim_start_matches("#/$defs/");
+                    defs[name]["properties"]["type"]["const"]
+                        .as_str()
+                        .unwrap()
+                        .to_string()
+                })
+                .collect()
+        };
+        assert_eq!(
+            kinds(schema_for!(HomeTabBlock)),
+            vec![
+                "actions",
+                "context",
+                "divider",
+                "header",
+                "image",
+                "rich_text",
+                "section",
+                "video"
+            ]
+        );
+        assert!(kinds(schema_for!(ModalBlock)).contains(&"input".to_string()));
+        assert!(kinds(schema_for!(MessageBlock)).contains(&"file".to_string()));
+    }
+}
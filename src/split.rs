@@ -0,0 +1,296 @@
+// Splits blocks that are over Slack's limits across several messages or modal
+// pages, for content built from unbounded data, e.g. reports.
+use crate::blocks::{
+    Section, SectionBuilder, MAX_FIELD_LENGTH, MAX_SECTION_FIELDS, MAX_SECTION_TEXT_LENGTH,
+};
+use crate::objects::Text;
+use crate::surfaces::{MessageBlock, ModalBlock};
+
+const FENCE: &str = "```";
+
+// The blocks splitting needs to look inside of.
+pub(crate) trait Paged: Clone + From<Section> {
+    fn is_header(&self) -> bool;
+    fn into_section(self) -> Result<Section, Self>;
+}
+
+impl Paged for MessageBlock {
+    fn is_header(&self) -> bool {
+        matches!(self, MessageBlock::Header(_))
+    }
+
+    fn into_section(self) -> Result<Section, Self> {
+        match self {
+            MessageBlock::Section(e) => Ok(e),
+            other => Err(other),
+        }
+    }
+}
+
+impl Paged for ModalBlock {
+    fn is_header(&self) -> bool {
+        matches!(self, ModalBlock::Header(_))
+    }
+
+    fn into_section(self) -> Result<Section, Self> {
+        match self {
+            ModalBlock::Section(e) => Ok(e),
+            other => Err(other),
+        }
+    }
+}
+
+// Splits long sections, then packs the blocks into pages of at most
+// `max_blocks`. Each page starts with the last header before it, so readers
+// know which part of a report they're looking at.
+pub(crate) fn pages<B: Paged>(blocks: Vec<B>, max_blocks: usize) -> Vec<Vec<B>> {
+    let mut pages = Vec::new();
+    let mut page: Vec<B> = Vec::new();
+    let mut header: Option<B> = None;
+    for block in blocks.into_iter().flat_map(split_block) {
+        if page.len() >= max_blocks {
+            // A header at the end of a page belongs with what follows it.
+            let last = match page.last() {
+                Some(last) if last.is_header() => page.pop(),
+                _ => None,
+            };
+            pages.push(std::mem::take(&mut page));
+            match last {
+                Some(last) => page.push(last),
+                None => page.extend(header.clone()),
+            }
+        }
+        if block.is_header() {
+            header = Some(block.clone());
+        }
+        page.push(block);
+    }
+    if !page.is_empty() || pages.is_empty() {
+        pages.push(page);
+    }
+    pages
+}
+
+fn split_block<B: Paged>(block: B) -> Vec<B> {
+    match block.into_section() {
+        Ok(section) => split_section(section).into_iter().map(B::from).collect(),
+        Err(block) => vec![block],
+    }
+}
+
+// Splits sections so their text is within 3000 characters, and they have at
+// most 10 fields of at most 2000 characters each. The accessory and block ID
+// stay with the first part of the text, and the fields go after the last, in
+// sections of their own once there are more than 10. Fields that are too long
+// are split across several.
+fn split_section(section: Section) -> Vec<Section> {
+    let fits = |text: &Text, max: usize| text.text().chars().count() <= max;
+    let fields_fit = section.fields.iter().all(|fields| {
+        fields.len() <= MAX_SECTION_FIELDS && fields.iter().all(|e| fits(e, MAX_FIELD_LENGTH))
+    });
+    if section
+        .text
+        .iter()
+        .all(|e| fits(e, MAX_SECTION_TEXT_LENGTH))
+        && fields_fit
+    {
+        return vec![section];
+    }
+    let mut sections: Vec<Section> = match &section.text {
+        Some(text) => split_text(text, MAX_SECTION_TEXT_LENGTH)
+            .into_iter()
+            .map(|e| SectionBuilder::new(e).build())
+            .collect(),
+        None => vec![SectionBuilder::new_with_fields(Vec::new()).build()],
+    };
+    let fields: Vec<Text> = section
+        .fields
+        .iter()
+        .flatten()
+        .flat_map(|e| split_text(e, MAX_FIELD_LENGTH))
+        .collect();
+    let mut chunks = fields.chunks(MAX_SECTION_FIELDS).map(<[Text]>::to_vec);
+    if let Some(last) = sections.last_mut() {
+        last.fields = chunks.next();
+    }
+    sections.extend(chunks.map(|e| SectionBuilder::new_with_fields(e).build()));
+    sections[0].block_id = section.block_id;
+    sections[0].accessory = section.accessory;
+    sections
+}
+
+fn split_text(text: &Text, max: usize) -> Vec<Text> {
+    if text.text().chars().count() <= max {
+        return vec![text.clone()];
+    }
+    let pieces = match text {
+        Text::PlainText(e) => split_lines(e.text(), max),
+        Text::Mrkdwn(e) => split_mrkdwn(e.text(), max),
+    };
+    pieces.into_iter().map(|e| text.with_text(e)).collect()
+}
+
+// Splits mrkdwn into pieces of at most `max` characters. A code block split
+// across pieces is closed at the end of one and opened again at the start of
+// the next.
+pub(crate) fn split_mrkdwn(text: &str, max: usize) -> Vec<String> {
+    if text.chars().count() <= max {
+        return vec![text.to_string()];
+    }
+    let fences = format!("{}\n\n{}", FENCE, FENCE).len();
+    let mut in_code_block = false;
+    split_lines(text, max - fences)
+        .into_iter()
+        .map(|piece| {
+            let opened = in_code_block;
+            in_code_block ^= piece.matches(FENCE).count() % 2 == 1;
+            let mut out = String::new();
+            if opened {
+                out.push_str(FENCE);
+                out.push('\n');
+            }
+            out.push_str(&piece);
+            if in_code_block {
+                out.push('\n');
+                out.push_str(FENCE);
+            }
+            out
+        })
+        .collect()
+}
+
+// Splits text at line breaks so no piece is over `max` characters, or anywhere
+// for lines that are too long on their own.
+pub(crate) fn split_lines(text: &str, max: usize) -> Vec<String> {
+    let mut pieces = Vec::new();
+    let mut piece = String::new();
+    for line in text.lines() {
+        let length = piece.chars().count() + 1 + line.chars().count();
+        if !piece.is_empty() && length > max {
+            pieces.push(std::mem::take(&mut piece));
+        }
+        if !piece.is_empty() {
+            piece.push('\n');
+        }
+        piece.push_str(line);
+        while piece.chars().count() > max {
+            let (head, tail) = piece.split_at(piece.char_indices().nth(max).unwrap().0);
+            pieces.push(head.to_string());
+            piece = tail.to_string();
+        }
+    }
+    if !piece.is_empty() {
+        pieces.push(piece);
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::blocks::{Divider, Header};
+    use crate::elements::ButtonBuilder;
+    use crate::objects::{Mrkdwn, PlainText};
+    use crate::surfaces::Message;
+    use crate::validation::Validate;
+
+    fn section(text: &str) -> MessageBlock {
+        SectionBuilder::new(Mrkdwn::new(text).into()).build().into()
+    }
+
+    #[test]
+    fn long_sections() {
+        let code = format!("Output:\n```\n{}```", "line\n".repeat(1000));
+        let section = SectionBuilder::new(Mrkdwn::new(code).into())
+            .set_block_id("output".to_string())
+            .set_fields(vec![PlainText::new("Took 4m").into()])
+            .set_accessory(
+                ButtonBuilder::new("rerun", PlainText::new("Rerun"))
+                    .build()
+                    .into(),
+            )
+            .build();
+        let sections = split_section(section);
+        assert_eq!(sections.len(), 2);
+        assert!(sections[0]
+            .text
+            .as_ref()
+            .unwrap()
+            .text()
+            .ends_with("line\n```"));
+        assert!(sections[1]
+            .text
+            .as_ref()
+            .unwrap()
+            .text()
+            .starts_with("```\nline"));
+        assert!(sections[0].block_id.is_some() && sections[0].accessory.is_some());
+        assert!(sections[0].fields.is_none() && sections[1].fields.is_some());
+        assert!(sections[1].block_id.is_none() && sections[1].accessory.is_none());
+        for section in &sections {
+            assert!(section.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn many_fields() {
+        let mut fields: Vec<Text> = (0..20)
+            .map(|idx| PlainText::new(idx.to_string()).into())
+            .collect();
+        fields.insert(0, Mrkdwn::new("word ".repeat(500)).into());
+        let section = SectionBuilder::new(PlainText::new("Results").into())
+            .set_block_id("results".to_string())
+            .set_fields(fields)
+            .build();
+        let sections = split_section(section);
+        // The long field becomes two, so there are 22 in all.
+        let counts: Vec<usize> = sections
+            .iter()
+            .map(|e| e.fields.as_ref().map_or(0, Vec::len))
+            .collect();
+        assert_eq!(counts, vec![10, 10, 2]);
+        assert!(sections[0].text.is_some() && sections[0].block_id.is_some());
+        assert!(sections[1].text.is_none() && sections[1].block_id.is_none());
+        for section in &sections {
+            assert!(section.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn message_pages() {
+        let mut blocks = vec![MessageBlock::from(Header::new(PlainText::new("Report")))];
+        blocks.extend((0..99).map(|idx| section(&idx.to_string())));
+        let messages = Message::builder(blocks).set_channel("C123").build().split();
+        // 50, then the header and 49 more, then the header and the last one.
+        let lengths: Vec<usize> = messages.iter().map(|e| e.blocks.len()).collect();
+        assert_eq!(lengths, vec![50, 50, 2]);
+        for message in &messages {
+            assert_eq!(message.channel.as_deref(), Some("C123"));
+            assert!(message.blocks[0].is_header());
+            assert!(message.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn replace_original_only_once() {
+        let blocks = (0..60).map(|idx| section(&idx.to_string())).collect();
+        let messages = Message::builder(blocks)
+            .set_replace_original(true)
+            .build()
+            .split();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].replace_original, Some(true));
+        assert_eq!(messages[1].replace_original, None);
+    }
+
+    #[test]
+    fn headers_start_pages() {
+        let mut blocks: Vec<MessageBlock> = (0..49).map(|_| Divider::new().into()).collect();
+        blocks.push(Header::new(PlainText::new("Errors")).into());
+        blocks.push(section("none"));
+        let pages = pages(blocks, 50);
+        assert_eq!(pages[0].len(), 49);
+        assert_eq!(pages[1].len(), 2);
+        assert!(pages[1][0].is_header());
+    }
+}
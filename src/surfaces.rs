@@ -1,11 +1,30 @@
 use crate::blocks::Image;
 use crate::blocks::*;
-use crate::objects::Text;
+use crate::de;
+use crate::objects::PlainText;
+use crate::split;
+use crate::validation::{Validate, Validator};
+use crate::Error;
 
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 
-#[derive(Serialize)]
+// For both home tabs and modals.
+const MAX_VIEW_BLOCKS: usize = 100;
+// For a modal's title and its close and submit buttons.
+const MAX_MODAL_TEXT_LENGTH: usize = 24;
+const MAX_MESSAGE_TEXT_LENGTH: usize = 40000;
+const MAX_MESSAGE_BLOCKS: usize = 50;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "HomeTab", transform = crate::schema::tagged("home"))
+)]
+#[serde(tag = "type", rename = "home")]
 pub struct HomeTab {
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_VIEW_BLOCKS)))]
     pub blocks: Vec<HomeTabBlock>,
 }
 
@@ -15,23 +34,60 @@ impl HomeTab {
     }
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "Modal", transform = crate::schema::tagged("modal"))
+)]
+#[serde(tag = "type", rename = "modal")]
 pub struct Modal {
-    pub title: Text,
-    pub close: Option<Text>,
-    pub submit: Option<Text>,
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_MODAL_TEXT_LENGTH))
+    )]
+    pub title: PlainText,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_MODAL_TEXT_LENGTH))
+    )]
+    pub close: Option<PlainText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(transform = crate::schema::max_text_length(MAX_MODAL_TEXT_LENGTH))
+    )]
+    pub submit: Option<PlainText>,
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_VIEW_BLOCKS)))]
     pub blocks: Vec<ModalBlock>,
 }
 
+impl Modal {
+    /// Splits the modal into pages that are within Slack's limits, e.g. to
+    /// push one after another. See `Message::split` for how it's split.
+    pub fn split(self) -> Vec<Modal> {
+        let mut modal = self;
+        let blocks = std::mem::take(&mut modal.blocks);
+        split::pages(blocks, MAX_VIEW_BLOCKS)
+            .into_iter()
+            .map(|blocks| Modal {
+                blocks,
+                ..modal.clone()
+            })
+            .collect()
+    }
+}
+
 pub struct ModalBuilder {
-    title: Text,
+    title: PlainText,
     blocks: Vec<ModalBlock>,
-    close: Option<Text>,
-    submit: Option<Text>,
+    close: Option<PlainText>,
+    submit: Option<PlainText>,
 }
 
 impl ModalBuilder {
-    pub fn new(title: Text, blocks: Vec<ModalBlock>) -> Self {
+    pub fn new(title: PlainText, blocks: Vec<ModalBlock>) -> Self {
         Self {
             title,
             blocks,
@@ -40,12 +96,12 @@ impl ModalBuilder {
         }
     }
 
-    pub fn set_close(mut self, close: Text) -> Self {
+    pub fn set_close(mut self, close: PlainText) -> Self {
         self.close = Some(close);
         self
     }
 
-    pub fn set_submit(mut self, submit: Text) -> Self {
+    pub fn set_submit(mut self, submit: PlainText) -> Self {
         self.submit = Some(submit);
         self
     }
@@ -58,18 +114,269 @@ impl ModalBuilder {
             submit: self.submit,
         }
     }
+
+    pub fn try_build(self) -> Result<Modal, Error> {
+        let modal = self.build();
+        modal.validate()?;
+        Ok(modal)
+    }
+}
+
+// Reference: https://api.slack.com/methods/chat.postMessage
+// This covers the fields shared by chat.postMessage, chat.update and
+// response_url bodies; Slack ignores the ones that don't apply to a call.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Message {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    // The message to change, for chat.update.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_MESSAGE_TEXT_LENGTH)))]
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_MESSAGE_BLOCKS)))]
+    pub blocks: Vec<MessageBlock>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_broadcast: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mrkdwn: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unfurl_links: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unfurl_media: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_type: Option<ResponseType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replace_original: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_original: Option<bool>,
+}
+
+impl Message {
+    pub fn builder(blocks: Vec<MessageBlock>) -> MessageBuilder {
+        MessageBuilder::new(blocks)
+    }
+
+    /// Splits the message into as many as it takes to stay within Slack's
+    /// limits, each with the same channel, text and options. Only the first
+    /// updates, replaces or deletes the original message, and the rest follow
+    /// it.
+    ///
+    /// Sections with too much text are split at line breaks, sections with
+    /// too many fields are split ten fields at a time, and each message after
+    /// the first starts with the last header before it.
+    pub fn split(self) -> Vec<Message> {
+        let mut message = self;
+        let blocks = std::mem::take(&mut message.blocks);
+        split::pages(blocks, MAX_MESSAGE_BLOCKS)
+            .into_iter()
+            .enumerate()
+            .map(|(idx, blocks)| Message {
+                blocks,
+                ts: message.ts.clone().filter(|_| idx == 0),
+                replace_original: message.replace_original.filter(|_| idx == 0),
+                delete_original: message.delete_original.filter(|_| idx == 0),
+                ..message.clone()
+            })
+            .collect()
+    }
+}
+
+pub struct MessageBuilder {
+    channel: Option<String>,
+    ts: Option<String>,
+    text: Option<String>,
+    blocks: Vec<MessageBlock>,
+    thread_ts: Option<String>,
+    reply_broadcast: Option<bool>,
+    mrkdwn: Option<bool>,
+    unfurl_links: Option<bool>,
+    unfurl_media: Option<bool>,
+    response_type: Option<ResponseType>,
+    replace_original: Option<bool>,
+    delete_original: Option<bool>,
+}
+
+impl MessageBuilder {
+    pub fn new(blocks: Vec<MessageBlock>) -> Self {
+        Self {
+            channel: None,
+            ts: None,
+            text: None,
+            blocks,
+            thread_ts: None,
+            reply_broadcast: None,
+            mrkdwn: None,
+            unfurl_links: None,
+            unfurl_media: None,
+            response_type: None,
+            replace_original: None,
+            delete_original: None,
+        }
+    }
+
+    pub fn set_channel<S: Into<String>>(mut self, channel: S) -> Self {
+        self.channel = Some(channel.into());
+        self
+    }
+
+    pub fn set_ts<S: Into<String>>(mut self, ts: S) -> Self {
+        self.ts = Some(ts.into());
+        self
+    }
+
+    // Used for notifications and by clients that can't display blocks.
+    pub fn set_text<S: Into<String>>(mut self, text: S) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn set_thread_ts<S: Into<String>>(mut self, thread_ts: S) -> Self {
+        self.thread_ts = Some(thread_ts.into());
+        self
+    }
+
+    pub fn set_reply_broadcast(mut self, broadcast: bool) -> Self {
+        self.reply_broadcast = Some(broadcast);
+        self
+    }
+
+    pub fn set_mrkdwn(mut self, mrkdwn: bool) -> Self {
+        self.mrkdwn = Some(mrkdwn);
+        self
+    }
+
+    pub fn set_unfurl_links(mut self, unfurl: bool) -> Self {
+        self.unfurl_links = Some(unfurl);
+        self
+    }
+
+    pub fn set_unfurl_media(mut self, unfurl: bool) -> Self {
+        self.unfurl_media = Some(unfurl);
+        self
+    }
+
+    pub fn set_response_type(mut self, response_type: ResponseType) -> Self {
+        self.response_type = Some(response_type);
+        self
+    }
+
+    pub fn set_replace_original(mut self, replace: bool) -> Self {
+        self.replace_original = Some(replace);
+        self
+    }
+
+    pub fn set_delete_original(mut self, delete: bool) -> Self {
+        self.delete_original = Some(delete);
+        self
+    }
+
+    pub fn build(self) -> Message {
+        Message {
+            channel: self.channel,
+            ts: self.ts,
+            text: self.text,
+            blocks: self.blocks,
+            thread_ts: self.thread_ts,
+            reply_broadcast: self.reply_broadcast,
+            mrkdwn: self.mrkdwn,
+            unfurl_links: self.unfurl_links,
+            unfurl_media: self.unfurl_media,
+            response_type: self.response_type,
+            replace_original: self.replace_original,
+            delete_original: self.delete_original,
+        }
+    }
+
+    pub fn try_build(self) -> Result<Message, Error> {
+        let message = self.build();
+        message.validate()?;
+        Ok(message)
+    }
+}
+
+// Only used when responding to a slash command or interaction.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseType {
+    InChannel,
+    Ephemeral,
+}
+
+impl From<Actions> for ModalBlock {
+    fn from(e: Actions) -> Self {
+        ModalBlock::Actions(e)
+    }
+}
+
+impl From<Context> for ModalBlock {
+    fn from(e: Context) -> Self {
+        ModalBlock::Context(e)
+    }
+}
+
+impl From<Divider> for ModalBlock {
+    fn from(e: Divider) -> Self {
+        ModalBlock::Divider(e)
+    }
+}
+
+impl From<Header> for ModalBlock {
+    fn from(e: Header) -> Self {
+        ModalBlock::Header(e)
+    }
+}
+
+impl From<Image> for ModalBlock {
+    fn from(e: Image) -> Self {
+        ModalBlock::Image(e)
+    }
+}
+
+impl From<Input> for ModalBlock {
+    fn from(e: Input) -> Self {
+        ModalBlock::Input(e)
+    }
+}
+
+impl From<RichText> for ModalBlock {
+    fn from(e: RichText) -> Self {
+        ModalBlock::RichText(e)
+    }
 }
 
-#[derive(Serialize)]
-pub struct Message {}
+impl From<Section> for ModalBlock {
+    fn from(e: Section) -> Self {
+        ModalBlock::Section(e)
+    }
+}
+
+impl From<Video> for ModalBlock {
+    fn from(e: Video) -> Self {
+        ModalBlock::Video(e)
+    }
+}
 
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema), schemars(untagged))]
 pub enum ModalBlock {
     Actions(Actions),
     Context(Context),
     Divider(Divider),
+    Header(Header),
     Image(Image),
     Input(Input),
+    RichText(RichText),
     Section(Section),
+    Video(Video),
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    Unknown(Value),
 }
 
 impl Serialize for ModalBlock {
@@ -81,19 +388,101 @@ impl Serialize for ModalBlock {
             ModalBlock::Actions(e) => e.serialize(serializer),
             ModalBlock::Context(e) => e.serialize(serializer),
             ModalBlock::Divider(e) => e.serialize(serializer),
+            ModalBlock::Header(e) => e.serialize(serializer),
             ModalBlock::Image(e) => e.serialize(serializer),
             ModalBlock::Input(e) => e.serialize(serializer),
+            ModalBlock::RichText(e) => e.serialize(serializer),
             ModalBlock::Section(e) => e.serialize(serializer),
+            ModalBlock::Video(e) => e.serialize(serializer),
+            ModalBlock::Unknown(e) => e.serialize(serializer),
         }
     }
 }
 
+impl<'de> Deserialize<'de> for ModalBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (kind, value) = de::tagged(deserializer)?;
+        match kind.as_str() {
+            "actions" => de::from_value(value).map(ModalBlock::Actions),
+            "context" => de::from_value(value).map(ModalBlock::Context),
+            "divider" => de::from_value(value).map(ModalBlock::Divider),
+            "header" => de::from_value(value).map(ModalBlock::Header),
+            "image" => de::from_value(value).map(ModalBlock::Image),
+            "input" => de::from_value(value).map(ModalBlock::Input),
+            "rich_text" => de::from_value(value).map(ModalBlock::RichText),
+            "section" => de::from_value(value).map(ModalBlock::Section),
+            "video" => de::from_value(value).map(ModalBlock::Video),
+            other => de::unknown(other, "modal block", BLOCK_TYPES.contains(&other), value)
+                .map(ModalBlock::Unknown),
+        }
+    }
+}
+
+impl From<Actions> for HomeTabBlock {
+    fn from(e: Actions) -> Self {
+        HomeTabBlock::Actions(e)
+    }
+}
+
+impl From<Context> for HomeTabBlock {
+    fn from(e: Context) -> Self {
+        HomeTabBlock::Context(e)
+    }
+}
+
+impl From<Divider> for HomeTabBlock {
+    fn from(e: Divider) -> Self {
+        HomeTabBlock::Divider(e)
+    }
+}
+
+impl From<Header> for HomeTabBlock {
+    fn from(e: Header) -> Self {
+        HomeTabBlock::Header(e)
+    }
+}
+
+impl From<Image> for HomeTabBlock {
+    fn from(e: Image) -> Self {
+        HomeTabBlock::Image(e)
+    }
+}
+
+impl From<RichText> for HomeTabBlock {
+    fn from(e: RichText) -> Self {
+        HomeTabBlock::RichText(e)
+    }
+}
+
+impl From<Section> for HomeTabBlock {
+    fn from(e: Section) -> Self {
+        HomeTabBlock::Section(e)
+    }
+}
+
+impl From<Video> for HomeTabBlock {
+    fn from(e: Video) -> Self {
+        HomeTabBlock::Video(e)
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema), schemars(untagged))]
 pub enum HomeTabBlock {
     Actions(Actions),
     Context(Context),
     Divider(Divider),
+    Header(Header),
     Image(Image),
+    RichText(RichText),
     Section(Section),
+    Video(Video),
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    Unknown(Value),
 }
 
 impl Serialize for HomeTabBlock {
@@ -105,21 +494,115 @@ impl Serialize for HomeTabBlock {
             HomeTabBlock::Actions(e) => e.serialize(serializer),
             HomeTabBlock::Context(e) => e.serialize(serializer),
             HomeTabBlock::Divider(e) => e.serialize(serializer),
+            HomeTabBlock::Header(e) => e.serialize(serializer),
             HomeTabBlock::Image(e) => e.serialize(serializer),
+            HomeTabBlock::RichText(e) => e.serialize(serializer),
             HomeTabBlock::Section(e) => e.serialize(serializer),
+            HomeTabBlock::Video(e) => e.serialize(serializer),
+            HomeTabBlock::Unknown(e) => e.serialize(serializer),
         }
     }
 }
 
+impl<'de> Deserialize<'de> for HomeTabBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (kind, value) = de::tagged(deserializer)?;
+        match kind.as_str() {
+            "actions" => de::from_value(value).map(HomeTabBlock::Actions),
+            "context" => de::from_value(value).map(HomeTabBlock::Context),
+            "divider" => de::from_value(value).map(HomeTabBlock::Divider),
+            "header" => de::from_value(value).map(HomeTabBlock::Header),
+            "image" => de::from_value(value).map(HomeTabBlock::Image),
+            "rich_text" => de::from_value(value).map(HomeTabBlock::RichText),
+            "section" => de::from_value(value).map(HomeTabBlock::Section),
+            "video" => de::from_value(value).map(HomeTabBlock::Video),
+            other => de::unknown(other, "home tab block", BLOCK_TYPES.contains(&other), value)
+                .map(HomeTabBlock::Unknown),
+        }
+    }
+}
+
+impl From<Actions> for MessageBlock {
+    fn from(e: Actions) -> Self {
+        MessageBlock::Actions(e)
+    }
+}
+
+impl From<Call> for MessageBlock {
+    fn from(e: Call) -> Self {
+        MessageBlock::Call(e)
+    }
+}
+
+impl From<Context> for MessageBlock {
+    fn from(e: Context) -> Self {
+        MessageBlock::Context(e)
+    }
+}
+
+impl From<Divider> for MessageBlock {
+    fn from(e: Divider) -> Self {
+        MessageBlock::Divider(e)
+    }
+}
+
+impl From<File> for MessageBlock {
+    fn from(e: File) -> Self {
+        MessageBlock::File(e)
+    }
+}
+
+impl From<Header> for MessageBlock {
+    fn from(e: Header) -> Self {
+        MessageBlock::Header(e)
+    }
+}
+
+impl From<Image> for MessageBlock {
+    fn from(e: Image) -> Self {
+        MessageBlock::Image(e)
+    }
+}
+
+impl From<RichText> for MessageBlock {
+    fn from(e: RichText) -> Self {
+        MessageBlock::RichText(e)
+    }
+}
+
+impl From<Section> for MessageBlock {
+    fn from(e: Section) -> Self {
+        MessageBlock::Section(e)
+    }
+}
+
+impl From<Video> for MessageBlock {
+    fn from(e: Video) -> Self {
+        MessageBlock::Video(e)
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema), schemars(untagged))]
 pub enum MessageBlock {
     Actions(Actions),
+    Call(Call),
     Context(Context),
     Divider(Divider),
     File(File),
+    Header(Header),
     Image(Image),
+    RichText(RichText),
     Section(Section),
-
+    Video(Video),
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    Unknown(Value),
 }
+
 impl Serialize for MessageBlock {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -127,11 +610,303 @@ impl Serialize for MessageBlock {
     {
         match self {
             MessageBlock::Actions(e) => e.serialize(serializer),
+            MessageBlock::Call(e) => e.serialize(serializer),
             MessageBlock::Context(e) => e.serialize(serializer),
             MessageBlock::Divider(e) => e.serialize(serializer),
             MessageBlock::File(e) => e.serialize(serializer),
+            MessageBlock::Header(e) => e.serialize(serializer),
             MessageBlock::Image(e) => e.serialize(serializer),
+            MessageBlock::RichText(e) => e.serialize(serializer),
             MessageBlock::Section(e) => e.serialize(serializer),
+            MessageBlock::Video(e) => e.serialize(serializer),
+            MessageBlock::Unknown(e) => e.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (kind, value) = de::tagged(deserializer)?;
+        match kind.as_str() {
+            "actions" => de::from_value(value).map(MessageBlock::Actions),
+            "call" => de::from_value(value).map(MessageBlock::Call),
+            "context" => de::from_value(value).map(MessageBlock::Context),
+            "divider" => de::from_value(value).map(MessageBlock::Divider),
+            "file" => de::from_value(value).map(MessageBlock::File),
+            "header" => de::from_value(value).map(MessageBlock::Header),
+            "image" => de::from_value(value).map(MessageBlock::Image),
+            "rich_text" => de::from_value(value).map(MessageBlock::RichText),
+            "section" => de::from_value(value).map(MessageBlock::Section),
+            "video" => de::from_value(value).map(MessageBlock::Video),
+            other => de::unknown(other, "message block", BLOCK_TYPES.contains(&other), value)
+                .map(MessageBlock::Unknown),
         }
     }
 }
+
+impl Validate for HomeTab {
+    fn validate_into(&self, v: &mut Validator) {
+        v.item_count("blocks", self.blocks.len(), 0, MAX_VIEW_BLOCKS);
+        v.items("blocks", &self.blocks);
+    }
+}
+
+impl Validate for Modal {
+    fn validate_into(&self, v: &mut Validator) {
+        v.max_text_length("title", &self.title, MAX_MODAL_TEXT_LENGTH);
+        v.optional_max_text_length("close", &self.close, MAX_MODAL_TEXT_LENGTH);
+        v.optional_max_text_length("submit", &self.submit, MAX_MODAL_TEXT_LENGTH);
+        v.item_count("blocks", self.blocks.len(), 0, MAX_VIEW_BLOCKS);
+        v.items("blocks", &self.blocks);
+    }
+}
+
+impl Validate for Message {
+    fn validate_into(&self, v: &mut Validator) {
+        v.optional_max_length("text", &self.text, MAX_MESSAGE_TEXT_LENGTH);
+        v.item_count("blocks", self.blocks.len(), 0, MAX_MESSAGE_BLOCKS);
+        v.items("blocks", &self.blocks);
+    }
+}
+
+impl Validate for ModalBlock {
+    fn validate_into(&self, v: &mut Validator) {
+        match self {
+            ModalBlock::Actions(e) => e.validate_into(v),
+            ModalBlock::Context(e) => e.validate_into(v),
+            ModalBlock::Divider(e) => e.validate_into(v),
+            ModalBlock::Header(e) => e.validate_into(v),
+            ModalBlock::Image(e) => e.validate_into(v),
+            ModalBlock::Input(e) => e.validate_into(v),
+            ModalBlock::RichText(e) => e.validate_into(v),
+            ModalBlock::Section(e) => e.validate_into(v),
+            ModalBlock::Video(e) => e.validate_into(v),
+            ModalBlock::Unknown(_) => {}
+        }
+    }
+}
+
+impl Validate for HomeTabBlock {
+    fn validate_into(&self, v: &mut Validator) {
+        match self {
+            HomeTabBlock::Actions(e) => e.validate_into(v),
+            HomeTabBlock::Context(e) => e.validate_into(v),
+            HomeTabBlock::Divider(e) => e.validate_into(v),
+            HomeTabBlock::Header(e) => e.validate_into(v),
+            HomeTabBlock::Image(e) => e.validate_into(v),
+            HomeTabBlock::RichText(e) => e.validate_into(v),
+            HomeTabBlock::Section(e) => e.validate_into(v),
+            HomeTabBlock::Video(e) => e.validate_into(v),
+            HomeTabBlock::Unknown(_) => {}
+        }
+    }
+}
+
+impl Validate for MessageBlock {
+    fn validate_into(&self, v: &mut Validator) {
+        match self {
+            MessageBlock::Actions(e) => e.validate_into(v),
+            MessageBlock::Call(e) => e.validate_into(v),
+            MessageBlock::Context(e) => e.validate_into(v),
+            MessageBlock::Divider(e) => e.validate_into(v),
+            MessageBlock::File(e) => e.validate_into(v),
+            MessageBlock::Header(e) => e.validate_into(v),
+            MessageBlock::Image(e) => e.validate_into(v),
+            MessageBlock::RichText(e) => e.validate_into(v),
+            MessageBlock::Section(e) => e.validate_into(v),
+            MessageBlock::Video(e) => e.validate_into(v),
+            MessageBlock::Unknown(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::objects::{FormattingType, Mrkdwn, Text};
+    use crate::validation::Validate;
+
+    #[test]
+    fn home_tab() {
+        let section = SectionBuilder::new(
+            Text::builder(
+                FormattingType::Markdown,
+                "A simple section with some sample sentence.",
+            )
+            .build(),
+        )
+        .build();
+        let home = HomeTab::new(vec![
+            HomeTabBlock::Section(section),
+            HomeTabBlock::Divider(Divider::new()),
+        ]);
+        let json = serde_json::to_string(&home).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"home","blocks":[{"type":"section","text":{"type":"mrkdwn","text":"A simple section with some sample sentence."}},{"type":"divider"}]}"#
+        );
+    }
+
+    #[test]
+    fn modal() {
+        let section =
+            SectionBuilder::new(PlainText::new("Just a plain text section").into()).build();
+        let modal = ModalBuilder::new(
+            PlainText::new("Modal title"),
+            vec![ModalBlock::Section(section)],
+        )
+        .set_close(PlainText::new("Cancel"))
+        .set_submit(PlainText::new("Save"))
+        .build();
+        let json = serde_json::to_string(&modal).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"modal","title":{"type":"plain_text","text":"Modal title"},"close":{"type":"plain_text","text":"Cancel"},"submit":{"type":"plain_text","text":"Save"},"blocks":[{"type":"section","text":{"type":"plain_text","text":"Just a plain text section"}}]}"#
+        );
+    }
+
+    #[test]
+    fn home_tab_round_trip() {
+        let json = r#"{"type":"home","blocks":[{"type":"section","text":{"type":"mrkdwn","text":"A simple section with some sample sentence."}},{"type":"divider"}]}"#;
+        let value: HomeTab = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn modal_round_trip() {
+        let json = r#"{"type":"modal","title":{"type":"plain_text","text":"Modal title"},"submit":{"type":"plain_text","text":"Save"},"blocks":[{"type":"input","label":{"type":"plain_text","text":"Label"},"element":{"type":"plain_text_input","action_id":"plain_input"},"block_id":"input1"}]}"#;
+        let modal: Modal = serde_json::from_str(json).unwrap();
+        assert_eq!(modal.blocks.len(), 1);
+        assert!(matches!(modal.blocks[0], ModalBlock::Input(_)));
+        assert_eq!(serde_json::to_string(&modal).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn message_blocks() {
+        let json = r#"[{"type":"section","text":{"type":"mrkdwn","text":"Hello"}},{"type":"file","external_id":"ABCD1","source":"remote"},{"type":"image","image_url":"http://placekitten.com/500/500","alt_text":"A kitten"}]"#;
+        let blocks: Vec<MessageBlock> = serde_json::from_str(json).unwrap();
+        assert!(matches!(blocks[0], MessageBlock::Section(_)));
+        assert!(matches!(blocks[1], MessageBlock::File(_)));
+        assert!(matches!(blocks[2], MessageBlock::Image(_)));
+        assert_eq!(serde_json::to_string(&blocks).unwrap().as_str(), json);
+    }
+
+    #[test]
+    fn block_not_allowed_in_surface() {
+        let json = r#"{"type":"home","blocks":[{"type":"input","label":{"type":"plain_text","text":"Label"},"element":{"type":"plain_text_input","action_id":"plain_input"}}]}"#;
+        let err = serde_json::from_str::<HomeTab>(json).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("unknown home tab block type `input`"));
+    }
+
+    #[test]
+    fn call_only_in_messages() {
+        let json = r#"[{"type":"header","text":{"type":"plain_text","text":"Standup"}},{"type":"call","call_id":"R014CKQGVRT"}]"#;
+        let blocks: Vec<MessageBlock> = serde_json::from_str(json).unwrap();
+        assert!(matches!(blocks[0], MessageBlock::Header(_)));
+        assert!(matches!(blocks[1], MessageBlock::Call(_)));
+
+        let err = serde_json::from_str::<Vec<ModalBlock>>(json).unwrap_err();
+        assert!(err.to_string().contains("unknown modal block type `call`"));
+    }
+
+    #[test]
+    fn message_post() {
+        let section = SectionBuilder::new(
+            Text::builder(FormattingType::Markdown, "Deploy *finished*").build(),
+        )
+        .build();
+        let message = Message::builder(vec![MessageBlock::Section(section)])
+            .set_channel("C1234567890")
+            .set_text("Deploy finished")
+            .set_thread_ts("1503435956.000247")
+            .set_reply_broadcast(true)
+            .set_unfurl_links(false)
+            .build();
+        let json = serde_json::to_string(&message).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"channel":"C1234567890","text":"Deploy finished","blocks":[{"type":"section","text":{"type":"mrkdwn","text":"Deploy *finished*"}}],"thread_ts":"1503435956.000247","reply_broadcast":true,"unfurl_links":false}"#
+        );
+    }
+
+    #[test]
+    fn message_update() {
+        let section = SectionBuilder::new(Mrkdwn::new("Deploy *rolled back*").into()).build();
+        let message = Message::builder(vec![MessageBlock::Section(section)])
+            .set_channel("C1234567890")
+            .set_ts("1503435956.000247")
+            .set_text("Deploy rolled back")
+            .build();
+        let json = serde_json::to_string(&message).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"channel":"C1234567890","ts":"1503435956.000247","text":"Deploy rolled back","blocks":[{"type":"section","text":{"type":"mrkdwn","text":"Deploy *rolled back*"}}]}"#
+        );
+    }
+
+    #[test]
+    fn message_response_url() {
+        let message = Message::builder(vec![])
+            .set_text("Done!")
+            .set_response_type(ResponseType::Ephemeral)
+            .set_replace_original(true)
+            .build();
+        let json = serde_json::to_string(&message).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"text":"Done!","response_type":"ephemeral","replace_original":true}"#
+        );
+
+        let message: Message = serde_json::from_str(r#"{"delete_original":true}"#).unwrap();
+        assert_eq!(
+            message,
+            Message::builder(vec![]).set_delete_original(true).build()
+        );
+    }
+
+    #[test]
+    fn validate_modal() {
+        let option = |text: String| {
+            crate::objects::OptionInput::builder(PlainText::new(text), "value").build()
+        };
+        let mut options: Vec<_> = (0..4).map(|i| option(format!("Option {}", i))).collect();
+        options[3] = option("x".repeat(80));
+        let overflow = crate::elements::OverflowMenu::builder("overflow", options).build();
+        let section = SectionBuilder::new(Text::builder(FormattingType::Markdown, "hi").build())
+            .set_accessory(overflow.into())
+            .build();
+
+        let mut blocks: Vec<_> = (0..101)
+            .map(|_| ModalBlock::Divider(Divider::new()))
+            .collect();
+        blocks[3] = ModalBlock::Section(section);
+        let modal =
+            ModalBuilder::new(PlainText::new("A title that is far too long"), blocks).build();
+
+        let errors = modal.validate().unwrap_err();
+        let paths: Vec<String> = errors.errors().iter().map(|e| e.path.to_string()).collect();
+        assert_eq!(
+            paths,
+            vec!["title", "blocks", "blocks[3].accessory.options[3].text"]
+        );
+    }
+
+    #[test]
+    fn validate_message_block_count() {
+        let message = Message::builder(
+            (0..51)
+                .map(|_| MessageBlock::Divider(Divider::new()))
+                .collect(),
+        )
+        .build();
+        assert_eq!(
+            message.validate().unwrap_err().to_string(),
+            "blocks: must have at most 50 items, but has 51"
+        );
+    }
+}
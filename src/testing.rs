@@ -0,0 +1,225 @@
+// Helpers for testing what gets sent to Slack, for this crate's tests and
+// apps' own. Payloads are compared as JSON values rather than strings, so key
+// order and whitespace don't matter, and failures list what differs by path.
+use serde::Serialize;
+use serde_json::Value;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// One place where two JSON values differ.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Difference {
+    /// Where they differ, e.g. `blocks[0].text.type`.
+    pub path: String,
+    /// The expected value, or `None` if there shouldn't be one.
+    pub expected: Option<Value>,
+    /// The actual value, or `None` if it's missing.
+    pub actual: Option<Value>,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "."
+        } else {
+            &self.path
+        };
+        match (&self.expected, &self.actual) {
+            (Some(expected), Some(actual)) => {
+                write!(f, "{}: expected {}, but got {}", path, expected, actual)
+            }
+            (Some(expected), None) => write!(f, "{}: missing, expected {}", path, expected),
+            (None, Some(actual)) => write!(f, "{}: unexpected {}", path, actual),
+            (None, None) => write!(f, "{}: missing", path),
+        }
+    }
+}
+
+/// Everywhere `actual` differs from `expected`, comparing objects by key and
+/// arrays by index.
+pub fn differences(expected: &Value, actual: &Value) -> Vec<Difference> {
+    let mut out = Vec::new();
+    diff(String::new(), expected, actual, &mut out);
+    out
+}
+
+fn diff(path: String, expected: &Value, actual: &Value, out: &mut Vec<Difference>) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, e) in expected {
+                let path = join(&path, key);
+                match actual.get(key) {
+                    Some(a) => diff(path, e, a, out),
+                    None => out.push(difference(path, Some(e), None)),
+                }
+            }
+            for (key, a) in actual {
+                if !expected.contains_key(key) {
+                    out.push(difference(join(&path, key), None, Some(a)));
+                }
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            for idx in 0..expected.len().max(actual.len()) {
+                let path = format!("{}[{}]", path, idx);
+                match (expected.get(idx), actual.get(idx)) {
+                    (Some(e), Some(a)) => diff(path, e, a, out),
+                    (e, a) => out.push(difference(path, e, a)),
+                }
+            }
+        }
+        (e, a) if e != a => out.push(difference(path, Some(e), Some(a))),
+        _ => {}
+    }
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn difference(path: String, expected: Option<&Value>, actual: Option<&Value>) -> Difference {
+    Difference {
+        path,
+        expected: expected.cloned(),
+        actual: actual.cloned(),
+    }
+}
+
+/// Asserts that `actual` serializes to the same JSON as `expected`, ignoring
+/// key order and whitespace.
+#[track_caller]
+pub fn assert_json_eq<T: Serialize>(actual: &T, expected: &str) {
+    let expected: Value = serde_json::from_str(expected).expect("expected JSON is invalid");
+    assert_value_eq(actual, &expected, "JSON");
+}
+
+/// Asserts that `actual` serializes to the JSON in the snapshot called
+/// `name`, at `tests/snapshots/<name>.json` in the crate being tested.
+///
+/// Missing snapshots fail, so one that wasn't checked in can't pass on CI.
+/// Set the `UPDATE_SNAPSHOTS` environment variable to write missing ones and
+/// rewrite failing ones, then check them in once they've been reviewed.
+#[track_caller]
+pub fn assert_snapshot<T: Serialize>(name: &str, actual: &T) {
+    let dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR isn't set");
+    let path: PathBuf = [&dir, "tests", "snapshots", &format!("{}.json", name)]
+        .iter()
+        .collect();
+    check_snapshot(
+        name,
+        &path,
+        actual,
+        env::var_os("UPDATE_SNAPSHOTS").is_some(),
+    );
+}
+
+#[track_caller]
+fn check_snapshot<T: Serialize>(name: &str, path: &Path, actual: &T, update: bool) {
+    if !update {
+        if !path.exists() {
+            panic!(
+                "snapshot `{}` is missing; set UPDATE_SNAPSHOTS=1 to write it to {}\n\nactual:\n{}",
+                name,
+                path.display(),
+                pretty(actual)
+            );
+        }
+        let expected = fs::read_to_string(path).expect("couldn't read snapshot");
+        let expected: Value = serde_json::from_str(&expected).expect("snapshot is invalid JSON");
+        assert_value_eq(actual, &expected, &format!("snapshot `{}`", name));
+        return;
+    }
+    // Written from the value rather than as a `Value`, to keep its fields in
+    // the order they're declared.
+    fs::create_dir_all(path.parent().unwrap()).expect("couldn't create snapshot directory");
+    fs::write(path, pretty(actual) + "\n").expect("couldn't write snapshot");
+}
+
+fn pretty<T: Serialize>(value: &T) -> String {
+    serde_json::to_string_pretty(value).expect("value couldn't be serialized")
+}
+
+#[track_caller]
+fn assert_value_eq<T: Serialize>(actual: &T, expected: &Value, what: &str) {
+    let json = pretty(actual);
+    let differences = differences(expected, &serde_json::from_str(&json).unwrap());
+    if !differences.is_empty() {
+        let lines: Vec<String> = differences.iter().map(|e| format!("  {}", e)).collect();
+        panic!(
+            "{} doesn't match:\n{}\n\nactual:\n{}",
+            what,
+            lines.join("\n"),
+            json
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::blocks::{Divider, SectionBuilder};
+    use crate::objects::{Mrkdwn, PlainText};
+    use crate::surfaces::ModalBuilder;
+    use serde_json::json;
+
+    #[test]
+    fn diffs() {
+        let expected =
+            json!({"type": "section", "text": {"type": "mrkdwn", "text": "Hi"}, "fields": [1, 2]});
+        let actual = json!({"text": {"text": "Hi", "type": "plain_text"}, "type": "section", "fields": [1], "block_id": "a"});
+        let lines: Vec<String> = differences(&expected, &actual)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                "fields[1]: missing, expected 2",
+                r#"text.type: expected "mrkdwn", but got "plain_text""#,
+                r#"block_id: unexpected "a""#,
+            ]
+        );
+    }
+
+    #[test]
+    fn json_eq() {
+        let section = SectionBuilder::new(Mrkdwn::new("Hi").into()).build();
+        assert_json_eq(
+            &section,
+            r#"{ "text": { "text": "Hi", "type": "mrkdwn" }, "type": "section" }"#,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "text.type: expected \"plain_text\", but got \"mrkdwn\"")]
+    fn json_ne() {
+        let section = SectionBuilder::new(Mrkdwn::new("Hi").into()).build();
+        assert_json_eq(
+            &section,
+            r#"{"type":"section","text":{"type":"plain_text","text":"Hi"}}"#,
+        );
+    }
+
+    #[test]
+    fn snapshot() {
+        let modal = ModalBuilder::new(PlainText::new("Settings"), vec![Divider::new().into()])
+            .set_submit(PlainText::new("Save"))
+            .build();
+        assert_snapshot("testing_modal", &modal);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "snapshot `testing_missing` is missing; set UPDATE_SNAPSHOTS=1 to write it to"
+    )]
+    fn missing_snapshot() {
+        let path = Path::new("tests/snapshots/testing_missing.json");
+        check_snapshot("testing_missing", path, &Divider::new(), false);
+    }
+}
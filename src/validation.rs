@@ -0,0 +1,370 @@
+// Reference: https://api.slack.com/reference/block-kit
+//
+// Slack rejects payloads that break its documented length and count limits,
+// but only reports the first problem it finds. These checks let us find every
+// violation up front, along with where it is in the payload.
+use std::error::Error;
+use std::fmt;
+
+/// One step into a payload: either a named field or an index into a list.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PathSegment {
+    Field(&'static str),
+    Index(usize),
+}
+
+/// The location of a value within a payload, e.g.
+/// `blocks[3].accessory.options[7].text`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path(Vec<PathSegment>);
+
+impl Path {
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{}", name)?,
+                PathSegment::Field(name) => write!(f, ".{}", name)?,
+                PathSegment::Index(idx) => write!(f, "[{}]", idx)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidationErrorKind {
+    TooLong { max: usize, actual: usize },
+    TooFewItems { min: usize, actual: usize },
+    TooManyItems { max: usize, actual: usize },
+    OutOfRange { min: u64, max: u64, actual: u64 },
+    GreaterThan { other: &'static str },
+    NotANumber { decimal_allowed: bool },
+    UnknownOption,
+    RequiredWithout { other: &'static str },
+}
+
+impl fmt::Display for ValidationErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationErrorKind::TooLong { max, actual } => write!(
+                f,
+                "must be at most {} characters long, but is {}",
+                max, actual
+            ),
+            ValidationErrorKind::TooFewItems { min, actual } => {
+                write!(f, "must have at least {} items, but has {}", min, actual)
+            }
+            ValidationErrorKind::TooManyItems { max, actual } => {
+                write!(f, "must have at most {} items, but has {}", max, actual)
+            }
+            ValidationErrorKind::OutOfRange { min, max, actual } => {
+                write!(f, "must be between {} and {}, but is {}", min, max, actual)
+            }
+            ValidationErrorKind::GreaterThan { other } => {
+                write!(f, "must not be greater than {}", other)
+            }
+            ValidationErrorKind::NotANumber {
+                decimal_allowed: true,
+            } => write!(f, "must be a number"),
+            ValidationErrorKind::NotANumber {
+                decimal_allowed: false,
+            } => write!(f, "must be an integer"),
+            ValidationErrorKind::UnknownOption => write!(f, "must be one of the options"),
+            ValidationErrorKind::RequiredWithout { other } => {
+                write!(f, "is required without {}", other)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationError {
+    pub path: Path,
+    pub kind: ValidationErrorKind,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.kind)
+    }
+}
+
+/// Every limit a payload breaks, in the order they appear in it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationErrors(Vec<ValidationError>);
+
+impl ValidationErrors {
+    pub fn errors(&self) -> &[ValidationError] {
+        &self.0
+    }
+
+    pub fn into_errors(self) -> Vec<ValidationError> {
+        self.0
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, error) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ValidationErrors {}
+
+/// Walks a payload, recording any limits it breaks against the current path.
+#[derive(Default)]
+pub struct Validator {
+    path: Vec<PathSegment>,
+    errors: Vec<ValidationError>,
+}
+
+impl Validator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn finish(self) -> Result<(), ValidationErrors> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors(self.errors))
+        }
+    }
+
+    fn push_error(&mut self, segments: &[PathSegment], kind: ValidationErrorKind) {
+        let mut path = self.path.clone();
+        path.extend_from_slice(segments);
+        self.errors.push(ValidationError {
+            path: Path(path),
+            kind,
+        });
+    }
+
+    fn nested<F: FnOnce(&mut Self)>(&mut self, segment: PathSegment, f: F) {
+        self.path.push(segment);
+        f(self);
+        self.path.pop();
+    }
+
+    pub fn field<V: Validate + ?Sized>(&mut self, name: &'static str, value: &V) {
+        self.nested(PathSegment::Field(name), |v| value.validate_into(v));
+    }
+
+    pub fn optional_field<V: Validate>(&mut self, name: &'static str, value: &Option<V>) {
+        if let Some(value) = value {
+            self.field(name, value);
+        }
+    }
+
+    pub fn items<V: Validate>(&mut self, name: &'static str, items: &[V]) {
+        self.nested(PathSegment::Field(name), |v| {
+            for (idx, item) in items.iter().enumerate() {
+                v.nested(PathSegment::Index(idx), |v| item.validate_into(v));
+            }
+        });
+    }
+
+    pub fn max_length(&mut self, name: &'static str, value: &str, max: usize) {
+        let actual = value.chars().count();
+        if actual > max {
+            self.push_error(
+                &[PathSegment::Field(name)],
+                ValidationErrorKind::TooLong { max, actual },
+            );
+        }
+    }
+
+    pub fn optional_max_length<S: AsRef<str>>(
+        &mut self,
+        name: &'static str,
+        value: &Option<S>,
+        max: usize,
+    ) {
+        if let Some(value) = value {
+            self.max_length(name, value.as_ref(), max);
+        }
+    }
+
+    pub fn max_text_length<T: AsRef<str>>(&mut self, name: &'static str, text: &T, max: usize) {
+        self.max_length(name, text.as_ref(), max);
+    }
+
+    pub fn optional_max_text_length<T: AsRef<str>>(
+        &mut self,
+        name: &'static str,
+        text: &Option<T>,
+        max: usize,
+    ) {
+        if let Some(text) = text {
+            self.max_text_length(name, text, max);
+        }
+    }
+
+    pub fn max_text_lengths<T: AsRef<str>>(&mut self, name: &'static str, texts: &[T], max: usize) {
+        for (idx, text) in texts.iter().enumerate() {
+            let actual = text.as_ref().chars().count();
+            if actual > max {
+                self.push_error(
+                    &[PathSegment::Field(name), PathSegment::Index(idx)],
+                    ValidationErrorKind::TooLong { max, actual },
+                );
+            }
+        }
+    }
+
+    pub fn item_count(&mut self, name: &'static str, actual: usize, min: usize, max: usize) {
+        if actual < min {
+            self.push_error(
+                &[PathSegment::Field(name)],
+                ValidationErrorKind::TooFewItems { min, actual },
+            );
+        } else if actual > max {
+            self.push_error(
+                &[PathSegment::Field(name)],
+                ValidationErrorKind::TooManyItems { max, actual },
+            );
+        }
+    }
+
+    pub fn range(&mut self, name: &'static str, value: Option<u32>, min: u32, max: u32) {
+        match value {
+            Some(actual) if actual < min || actual > max => self.push_error(
+                &[PathSegment::Field(name)],
+                ValidationErrorKind::OutOfRange {
+                    min: min.into(),
+                    max: max.into(),
+                    actual: actual.into(),
+                },
+            ),
+            _ => {}
+        }
+    }
+
+    pub fn not_greater_than<T: PartialOrd>(
+        &mut self,
+        name: &'static str,
+        value: Option<T>,
+        other: &'static str,
+        other_value: Option<T>,
+    ) {
+        if let (Some(value), Some(other_value)) = (value, other_value) {
+            if value > other_value {
+                self.push_error(
+                    &[PathSegment::Field(name)],
+                    ValidationErrorKind::GreaterThan { other },
+                );
+            }
+        }
+    }
+
+    /// Checks that a field is set when the one that can stand in for it isn't.
+    pub fn required_without(
+        &mut self,
+        name: &'static str,
+        present: bool,
+        other: &'static str,
+        other_present: bool,
+    ) {
+        if !present && !other_present {
+            self.push_error(
+                &[PathSegment::Field(name)],
+                ValidationErrorKind::RequiredWithout { other },
+            );
+        }
+    }
+
+    /// Parses a number sent as a string, returning it if it's valid.
+    pub fn number(
+        &mut self,
+        name: &'static str,
+        value: &Option<String>,
+        decimal_allowed: bool,
+    ) -> Option<f64> {
+        let value = value.as_deref()?;
+        let parsed = if decimal_allowed {
+            value.parse::<f64>().ok().filter(|n| n.is_finite())
+        } else {
+            value.parse::<i64>().ok().map(|n| n as f64)
+        };
+        if parsed.is_none() {
+            self.push_error(
+                &[PathSegment::Field(name)],
+                ValidationErrorKind::NotANumber { decimal_allowed },
+            );
+        }
+        parsed
+    }
+
+    /// Checks that each initial option is one of the options offered.
+    pub fn known_options<O, F>(&mut self, name: &'static str, initial: &[O], is_option: F)
+    where
+        F: Fn(&O) -> bool,
+    {
+        for (idx, option) in initial.iter().enumerate() {
+            if !is_option(option) {
+                self.push_error(
+                    &[PathSegment::Field(name), PathSegment::Index(idx)],
+                    ValidationErrorKind::UnknownOption,
+                );
+            }
+        }
+    }
+
+    pub fn known_option<O, F>(&mut self, name: &'static str, initial: &Option<O>, is_option: F)
+    where
+        F: Fn(&O) -> bool,
+    {
+        match initial {
+            Some(option) if !is_option(option) => self.push_error(
+                &[PathSegment::Field(name)],
+                ValidationErrorKind::UnknownOption,
+            ),
+            _ => {}
+        }
+    }
+}
+
+/// Checks a value against the limits Slack documents for it, e.g. lengths,
+/// item counts and initial options being among the options. Builders run this
+/// from `try_build`.
+pub trait Validate {
+    /// Checks this value against Slack's limits, reporting every violation.
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut validator = Validator::new();
+        self.validate_into(&mut validator);
+        validator.finish()
+    }
+
+    /// Records this value's violations relative to the validator's path.
+    fn validate_into(&self, validator: &mut Validator);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn path_display() {
+        let path = Path(vec![
+            PathSegment::Field("blocks"),
+            PathSegment::Index(3),
+            PathSegment::Field("accessory"),
+            PathSegment::Field("options"),
+            PathSegment::Index(7),
+            PathSegment::Field("text"),
+        ]);
+        assert_eq!(path.to_string(), "blocks[3].accessory.options[7].text");
+    }
+}
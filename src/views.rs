@@ -1,28 +1,301 @@
+// Reference: https://api.slack.com/reference/surfaces/views
 use crate::surfaces::*;
+use crate::validation::{Validate, Validator};
+use crate::Error;
 
+use serde::{Deserialize, Serialize};
+
+const MAX_PRIVATE_METADATA_LENGTH: usize = 3000;
+const MAX_CALLBACK_ID_LENGTH: usize = 255;
+const MAX_EXTERNAL_ID_LENGTH: usize = 255;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "schema",
+    derive(schemars::JsonSchema),
+    schemars(rename = "{T}View")
+)]
 pub struct View<T: ViewType> {
+    // The surface provides the "type" tag, title and blocks.
+    #[serde(flatten)]
     pub payload: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_PRIVATE_METADATA_LENGTH)))]
     pub private_metadata: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_CALLBACK_ID_LENGTH)))]
+    pub callback_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(length(max = MAX_EXTERNAL_ID_LENGTH)))]
+    pub external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clear_on_close: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_on_close: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit_disabled: Option<bool>,
+    // Slack takes this alongside the view rather than inside it, so it's only
+    // sent as part of a `ViewRequest`.
+    #[serde(skip)]
+    pub hash: Option<String>,
 }
 
 impl<T: ViewType> View<T> {
     pub fn new(payload: T) -> View<T> {
-        View {
+        ViewBuilder::new(payload).build()
+    }
+
+    pub fn new_with_metadata(payload: T, private_metadata: String) -> View<T> {
+        ViewBuilder::new(payload)
+            .set_private_metadata(private_metadata)
+            .build()
+    }
+
+    pub fn builder(payload: T) -> ViewBuilder<T> {
+        ViewBuilder::new(payload)
+    }
+
+    /// The body for a views.update call targeting `view_id`.
+    pub fn update<S: Into<String>>(self, view_id: S) -> ViewRequest<T> {
+        ViewRequest {
+            view_id: Some(view_id.into()),
+            ..ViewRequest::new(self)
+        }
+    }
+
+    /// The body for a views.update call targeting this view's `external_id`.
+    pub fn update_external(self) -> ViewRequest<T> {
+        ViewRequest {
+            external_id: self.external_id.clone(),
+            ..ViewRequest::new(self)
+        }
+    }
+}
+
+impl View<Modal> {
+    /// The body for a views.open call.
+    pub fn open<S: Into<String>>(self, trigger_id: S) -> ViewRequest<Modal> {
+        ViewRequest {
+            trigger_id: Some(trigger_id.into()),
+            ..ViewRequest::new(self)
+        }
+    }
+
+    /// The body for a views.push call.
+    pub fn push<S: Into<String>>(self, trigger_id: S) -> ViewRequest<Modal> {
+        self.open(trigger_id)
+    }
+}
+
+impl View<HomeTab> {
+    /// The body for a views.publish call.
+    pub fn publish<S: Into<String>>(self, user_id: S) -> ViewRequest<HomeTab> {
+        ViewRequest {
+            user_id: Some(user_id.into()),
+            ..ViewRequest::new(self)
+        }
+    }
+}
+
+pub struct ViewBuilder<T: ViewType> {
+    payload: T,
+    private_metadata: Option<String>,
+    callback_id: Option<String>,
+    external_id: Option<String>,
+    clear_on_close: Option<bool>,
+    notify_on_close: Option<bool>,
+    submit_disabled: Option<bool>,
+    hash: Option<String>,
+}
+
+impl<T: ViewType> ViewBuilder<T> {
+    pub fn new(payload: T) -> Self {
+        Self {
             payload,
             private_metadata: None,
+            callback_id: None,
+            external_id: None,
+            clear_on_close: None,
+            notify_on_close: None,
+            submit_disabled: None,
+            hash: None,
         }
     }
 
-    pub fn new_with_metadata(payload: T, private_metadata: String) -> View<T> {
+    pub fn set_private_metadata<S: Into<String>>(mut self, metadata: S) -> Self {
+        self.private_metadata = Some(metadata.into());
+        self
+    }
+
+    pub fn set_callback_id<S: Into<String>>(mut self, callback_id: S) -> Self {
+        self.callback_id = Some(callback_id.into());
+        self
+    }
+
+    pub fn set_external_id<S: Into<String>>(mut self, external_id: S) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    pub fn set_clear_on_close(mut self, clear: bool) -> Self {
+        self.clear_on_close = Some(clear);
+        self
+    }
+
+    pub fn set_notify_on_close(mut self, notify: bool) -> Self {
+        self.notify_on_close = Some(notify);
+        self
+    }
+
+    pub fn set_submit_disabled(mut self, disabled: bool) -> Self {
+        self.submit_disabled = Some(disabled);
+        self
+    }
+
+    pub fn set_hash<S: Into<String>>(mut self, hash: S) -> Self {
+        self.hash = Some(hash.into());
+        self
+    }
+
+    pub fn build(self) -> View<T> {
         View {
-            payload,
-            private_metadata: Some(private_metadata),
+            payload: self.payload,
+            private_metadata: self.private_metadata,
+            callback_id: self.callback_id,
+            external_id: self.external_id,
+            clear_on_close: self.clear_on_close,
+            notify_on_close: self.notify_on_close,
+            submit_disabled: self.submit_disabled,
+            hash: self.hash,
+        }
+    }
+
+    pub fn try_build(self) -> Result<View<T>, Error> {
+        let view = self.build();
+        view.validate()?;
+        Ok(view)
+    }
+}
+
+/// The body of a views.open, views.push, views.update or views.publish call.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ViewRequest<T: ViewType> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trigger_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    view_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hash: Option<String>,
+    view: View<T>,
+}
+
+impl<T: ViewType> ViewRequest<T> {
+    fn new(view: View<T>) -> Self {
+        Self {
+            trigger_id: None,
+            user_id: None,
+            view_id: None,
+            external_id: None,
+            hash: view.hash.clone(),
+            view,
         }
     }
 }
 
-pub trait ViewType {}
+pub trait ViewType: Validate {}
 
 impl ViewType for HomeTab {}
 
 impl ViewType for Modal {}
+
+impl<T: ViewType> Validate for View<T> {
+    fn validate_into(&self, v: &mut Validator) {
+        // The surface is flattened, so its fields share our path.
+        self.payload.validate_into(v);
+        v.optional_max_length(
+            "private_metadata",
+            &self.private_metadata,
+            MAX_PRIVATE_METADATA_LENGTH,
+        );
+        v.optional_max_length("callback_id", &self.callback_id, MAX_CALLBACK_ID_LENGTH);
+        v.optional_max_length("external_id", &self.external_id, MAX_EXTERNAL_ID_LENGTH);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::blocks::Divider;
+    use crate::objects::PlainText;
+
+    fn modal() -> Modal {
+        ModalBuilder::new(
+            PlainText::new("Modal title"),
+            vec![ModalBlock::Divider(Divider::new())],
+        )
+        .build()
+    }
+
+    #[test]
+    fn modal_view() {
+        let view = View::builder(modal())
+            .set_private_metadata("metadata")
+            .set_callback_id("view_identifier_12")
+            .set_external_id("ext_1")
+            .set_clear_on_close(true)
+            .set_notify_on_close(false)
+            .set_submit_disabled(false)
+            .set_hash("156772938.1827394")
+            .build();
+        let json = serde_json::to_string(&view).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"type":"modal","title":{"type":"plain_text","text":"Modal title"},"blocks":[{"type":"divider"}],"private_metadata":"metadata","callback_id":"view_identifier_12","external_id":"ext_1","clear_on_close":true,"notify_on_close":false,"submit_disabled":false}"#
+        );
+    }
+
+    #[test]
+    fn open_and_update() {
+        let json =
+            serde_json::to_string(&View::new(modal()).open("12345.98765.abcd2358fdea")).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"trigger_id":"12345.98765.abcd2358fdea","view":{"type":"modal","title":{"type":"plain_text","text":"Modal title"},"blocks":[{"type":"divider"}]}}"#
+        );
+
+        let view = View::builder(modal())
+            .set_external_id("ext_1")
+            .set_hash("156772938.1827394")
+            .build();
+        let json = serde_json::to_string(&view.update_external()).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"external_id":"ext_1","hash":"156772938.1827394","view":{"type":"modal","title":{"type":"plain_text","text":"Modal title"},"blocks":[{"type":"divider"}],"external_id":"ext_1"}}"#
+        );
+    }
+
+    #[test]
+    fn publish_home_tab() {
+        let view = View::new_with_metadata(
+            HomeTab::new(vec![HomeTabBlock::Divider(Divider::new())]),
+            "metadata".to_string(),
+        );
+        let json = serde_json::to_string(&view.publish("U0BPQUNTA")).unwrap();
+        assert_eq!(
+            json.as_str(),
+            r#"{"user_id":"U0BPQUNTA","view":{"type":"home","blocks":[{"type":"divider"}],"private_metadata":"metadata"}}"#
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let json = r#"{"type":"home","blocks":[{"type":"divider"}],"callback_id":"home"}"#;
+        let view: View<HomeTab> = serde_json::from_str(json).unwrap();
+        assert_eq!(view.callback_id.as_deref(), Some("home"));
+        assert_eq!(serde_json::to_string(&view).unwrap().as_str(), json);
+    }
+}
@@ -0,0 +1,481 @@
+// Checks the crate against the examples in Slack's Block Kit reference,
+// which are kept as JSON in `tests/conformance/`. Each one is built with the
+// builders and compared to the example, then parsed back from it, so any
+// drift from the documented format fails here.
+//
+// Slack's examples leave out a few fields it requires, e.g. `action_id`, so
+// the copies here include them.
+use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use slack_blocks::blocks::*;
+use slack_blocks::elements::{self, *};
+use slack_blocks::objects::rich_text::*;
+use slack_blocks::objects::*;
+use slack_blocks::surfaces::*;
+use slack_blocks::testing::assert_json_eq;
+use slack_blocks::views::View;
+use std::fmt::Debug;
+use std::fs;
+use std::path::{Path, PathBuf};
+use url::Url;
+
+fn corpus() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/conformance")
+}
+
+fn check<T>(name: &str, built: T)
+where
+    T: Serialize + DeserializeOwned + Debug + PartialEq,
+{
+    let path = corpus().join(format!("{}.json", name));
+    let json = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("couldn't read {}: {}", path.display(), e));
+    assert_json_eq(&built, &json);
+    let parsed: T =
+        serde_json::from_str(&json).unwrap_or_else(|e| panic!("couldn't parse {}: {}", name, e));
+    assert_eq!(parsed, built, "{} doesn't parse back", name);
+}
+
+// The example has to be valid against the schema for the type it's built as.
+#[cfg(feature = "schema")]
+fn check_schema<T: slack_blocks::schema::JsonSchema>(name: &str, _: T) {
+    let schema = serde_json::to_value(slack_blocks::schema::schema_for!(T)).unwrap();
+    let validator = jsonschema::validator_for(&schema).unwrap();
+    let json = fs::read_to_string(corpus().join(format!("{}.json", name))).unwrap();
+    let example: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let errors: Vec<String> = validator
+        .iter_errors(&example)
+        .map(|e| format!("{}: {}", e.instance_path, e))
+        .collect();
+    assert!(
+        errors.is_empty(),
+        "{} doesn't match its schema: {:#?}",
+        name,
+        errors
+    );
+}
+
+// Defines a test for each example, and the list of them that every file in
+// the corpus needs to be in.
+macro_rules! examples {
+    ($($test:ident: $name:literal => $built:expr,)*) => {
+        $(
+            #[test]
+            fn $test() {
+                check($name, $built);
+            }
+        )*
+
+        #[cfg(feature = "schema")]
+        mod schema {
+            use super::*;
+
+            $(
+                #[test]
+                fn $test() {
+                    check_schema($name, $built);
+                }
+            )*
+        }
+
+        const EXAMPLES: &[&str] = &[$($name),*];
+    };
+}
+
+fn url(url: &str) -> Url {
+    Url::parse(url).unwrap()
+}
+
+fn mrkdwn(text: &str) -> Text {
+    Mrkdwn::new(text).into()
+}
+
+fn option(text: &str, value: &str) -> OptionInput<PlainText> {
+    OptionInput::builder(PlainText::new(text), value).build()
+}
+
+fn choice(text: &str, value: &str) -> OptionInput {
+    OptionInput::builder(PlainText::new(text).into(), value).build()
+}
+
+// The placeholder options Slack's element examples use.
+fn options(count: usize) -> Vec<OptionInput<PlainText>> {
+    (0..count)
+        .map(|idx| option("*this is plain_text text*", &format!("value-{}", idx)))
+        .collect()
+}
+
+fn select(action_id: &str, placeholder: &str, menu_type: SelectMenuType) -> SelectMenu {
+    SelectMenu::new(action_id, PlainText::new(placeholder), menu_type)
+}
+
+fn rich_text(text: &str) -> Vec<RichTextInline> {
+    vec![RichTextInline::text(text)]
+}
+
+examples! {
+    // https://api.slack.com/reference/block-kit/blocks
+    actions: "blocks/actions" => Actions::new_with_id(
+        "actions1",
+        vec![
+            select(
+                "select_2",
+                "Which witch is the witchiest witch?",
+                StaticMenu::new(OptionNestingType::Flat(vec![
+                    option("Matilda", "matilda"),
+                    option("Glinda", "glinda"),
+                    option("Granny Weatherwax", "grannyWeatherwax"),
+                    option("Hermione", "hermione"),
+                ]))
+                .into(),
+            )
+            .into(),
+            ButtonBuilder::new("button_1", PlainText::new("Cancel"))
+                .set_value("cancel")
+                .build()
+                .into(),
+        ],
+    ),
+    call: "blocks/call" => Call::new("R0E9FCH9C"),
+    actions_datepicker_overflow: "blocks/actions_datepicker_overflow" => Actions::new_with_id(
+        "actionblock789",
+        vec![
+            DatePickerBuilder::new("datepicker123")
+                .set_initial_date(NaiveDate::from_ymd_opt(1990, 4, 28).unwrap())
+                .set_placeholder("Select a date")
+                .build()
+                .into(),
+            OverflowMenu::builder("overflow", options(5)).build().into(),
+            ButtonBuilder::new("button", PlainText::new("Click Me"))
+                .set_value("click_me_123")
+                .build()
+                .into(),
+        ],
+    ),
+    context: "blocks/context" => Context::new(vec![
+        elements::Image::new(
+            url("https://image.freepik.com/free-photo/red-drawing-pin_1156-445.jpg"),
+            "images".to_string(),
+        )
+        .into(),
+        Mrkdwn::new("Location: **Dogpatch**").into(),
+    ]),
+    divider: "blocks/divider" => Divider::new(),
+    file: "blocks/file" => File::new("ABCD1"),
+    header: "blocks/header" => Header::new(PlainText::new("Budget Performance")),
+    image: "blocks/image" => ImageBuilder::new(
+        url("http://placekitten.com/500/500"),
+        "An incredibly cute kitten.",
+    )
+    .set_title(PlainText::new("Please enjoy this photo of a kitten"))
+    .set_block_id("image4")
+    .build(),
+    input: "blocks/input" => InputBuilder::new(
+        PlainText::new_with_emoji("Label", true),
+        PlainTextInputBuilder::new("plain_text_input-action").build().into(),
+    )
+    .build(),
+    rich_text_block: "blocks/rich_text" => RichText::new(vec![RichTextSection::new(rich_text(
+        "Hello there, I am a basic rich text block!",
+    ))
+    .into()]),
+    rich_text_list: "blocks/rich_text_list" => RichTextBuilder::new(vec![
+        RichTextList::builder(ListStyle::Bullet)
+            .add_item(rich_text("Huddles"))
+            .add_item(rich_text("Canvas"))
+            .add_item(rich_text("Developing with Block Kit"))
+            .build()
+            .into(),
+    ])
+    .set_block_id("block1")
+    .build(),
+    rich_text_quote: "blocks/rich_text_quote" => RichText::new(vec![
+        RichTextQuote::new(rich_text(
+            "What we need is good examples in our documentation.",
+        ))
+        .into(),
+        RichTextSection::new(rich_text("Yes - I completely agree, Luke!")).into(),
+    ]),
+    rich_text_preformatted: "blocks/rich_text_preformatted" => RichText::new(vec![
+        RichTextPreformatted {
+            border: Some(0),
+            ..RichTextPreformatted::new(rich_text(
+                "{\n  \"object\": {\n    \"description\": \"this is an example of a json object\"\n  }\n}",
+            ))
+        }
+        .into(),
+    ]),
+    section: "blocks/section" => SectionBuilder::new(mrkdwn(
+        "A message *with some bold text* and _some italicized text_.",
+    ))
+    .build(),
+    section_fields: "blocks/section_fields" => SectionBuilder::new(mrkdwn(
+        "A message *with some bold text* and _some italicized text_.",
+    ))
+    .set_fields(vec![mrkdwn("High"), PlainText::new_with_emoji("String", true).into()])
+    .build(),
+    section_image: "blocks/section_image" => SectionBuilder::new(mrkdwn(
+        "This is a section block with an accessory image.",
+    ))
+    .set_accessory(
+        elements::Image::new(
+            url("https://pbs.twimg.com/profile_images/625633822235693056/lNGUneLX_400x400.jpg"),
+            "cute cat".to_string(),
+        )
+        .into(),
+    )
+    .build(),
+    video: "blocks/video" => VideoBuilder::new(
+        "How to use Slack?",
+        PlainText::new_with_emoji("How to use Slack.", true),
+        url("https://i.ytimg.com/vi/RRxQQxiM7AA/hqdefault.jpg"),
+        url("https://www.youtube.com/embed/RRxQQxiM7AA?feature=oembed&autoplay=1"),
+    )
+    .set_title_url(url("https://www.youtube.com/watch?v=RRxQQxiM7AA"))
+    .set_description(PlainText::new_with_emoji(
+        "Slack is a new way to communicate with your team. It's faster, better organized and more secure than email.",
+        true,
+    ))
+    .set_author_name("Arcado Buendia")
+    .set_provider_name("YouTube")
+    .set_provider_icon_url(url("https://a.slack-edge.com/80588/img/unfurl_icons/youtube.png"))
+    .build(),
+
+    // https://api.slack.com/reference/block-kit/block-elements
+    button: "elements/button" => ButtonBuilder::new("button", PlainText::new("Click Me"))
+        .set_value("click_me_123")
+        .build(),
+    button_primary: "elements/button_primary" => ButtonBuilder::new("button", PlainText::new("Save"))
+        .set_style(ButtonStyle::Primary)
+        .set_value("click_me_123")
+        .build(),
+    button_url: "elements/button_url" => ButtonBuilder::new("button", PlainText::new("Link Button"))
+        .set_url(url("https://api.slack.com/block-kit"))
+        .build(),
+    channels_select: "elements/channels_select" => select(
+        "text1234",
+        "Select an item",
+        SelectMenuType::Channel(ChannelMenu::new()),
+    ),
+    checkboxes: "elements/checkboxes" => CheckboxesBuilder::new(
+        "this_is_an_action_id".to_string(),
+        vec![choice("Checkbox 1", "A1"), choice("Checkbox 2", "A2")],
+    )
+    .set_initial_options(vec![choice("Checkbox 1", "A1")])
+    .build(),
+    conversations_select: "elements/conversations_select" => select(
+        "text1234",
+        "Select an item",
+        SelectMenuType::Conversation(ConversationMenu::new()),
+    ),
+    datepicker: "elements/datepicker" => DatePickerBuilder::new("datepicker123")
+        .set_initial_date(NaiveDate::from_ymd_opt(1990, 4, 28).unwrap())
+        .set_placeholder("Select a date")
+        .build(),
+    datetimepicker: "elements/datetimepicker" => DateTimePickerBuilder::new("datetimepicker-action")
+        .set_initial_date_time(Utc.timestamp_opt(1628633820, 0).unwrap())
+        .build(),
+    email_text_input: "elements/email_text_input" => EmailInputBuilder::new("email_text_input-action").build(),
+    external_select: "elements/external_select" => select(
+        "text1234",
+        "Select an item",
+        SelectMenuType::External(ExternalMenuBuilder::new().set_query_length(3).build()),
+    ),
+    file_input: "elements/file_input" => FileInputBuilder::new("file_input_action_id_1")
+        .set_filetypes(vec!["jpg", "png"])
+        .set_max_files(5)
+        .build(),
+    image_element: "elements/image" => elements::Image::new(
+        url("http://placekitten.com/700/500"),
+        "Multiple cute kittens".to_string(),
+    ),
+    multi_channels_select: "elements/multi_channels_select" => MultiSelectMenu::new(
+        "text1234",
+        PlainText::new("Select channels"),
+        MultiSelectMenuType::Channel(ChannelMultiMenu::new()),
+    ),
+    multi_conversations_select: "elements/multi_conversations_select" => MultiSelectMenu::new(
+        "text1234",
+        PlainText::new("Select conversations"),
+        MultiSelectMenuType::Conversation(ConversationMultiMenu::new()),
+    ),
+    multi_external_select: "elements/multi_external_select" => MultiSelectMenu::new(
+        "text1234",
+        PlainText::new("Select items"),
+        MultiSelectMenuType::External(ExternalMultiMenuBuilder::new().set_query_length(3).build()),
+    ),
+    multi_static_select: "elements/multi_static_select" => MultiSelectMenu::new(
+        "text1234",
+        PlainText::new("Select items"),
+        StaticMultiMenu::new(OptionNestingType::Flat(options(3))).into(),
+    ),
+    multi_users_select: "elements/multi_users_select" => MultiSelectMenu::new(
+        "text1234",
+        PlainText::new("Select users"),
+        MultiSelectMenuType::User(UserMultiMenu::new()),
+    ),
+    number_input: "elements/number_input" => NumberInput::integer("number_input-action").build(),
+    overflow: "elements/overflow" => OverflowMenu::builder("overflow", options(5)).build(),
+    plain_text_input: "elements/plain_text_input" => PlainTextInputBuilder::new("plain_input")
+        .set_placeholder(PlainText::new("Enter some plain text"))
+        .build(),
+    radio_buttons: "elements/radio_buttons" => RadioButtonGroupBuilder::new(
+        "this_is_an_action_id",
+        vec![choice("Radio 1", "A1"), choice("Radio 2", "A2")],
+    )
+    .set_initial_option(choice("Radio 1", "A1"))
+    .build(),
+    rich_text_input: "elements/rich_text_input" => RichTextInputBuilder::new("rich_text_input-action").build(),
+    static_select: "elements/static_select" => select(
+        "text1234",
+        "Select an item",
+        StaticMenu::new(OptionNestingType::Flat(options(3))).into(),
+    ),
+    static_select_groups: "elements/static_select_groups" => select(
+        "text1234",
+        "Select an item",
+        StaticMenu::new(OptionNestingType::Groups(vec![
+            OptionInputGroup::new(PlainText::new("Group 1"), options(3)),
+            OptionInputGroup::new(
+                PlainText::new("Group 2"),
+                vec![option("*this is plain_text text*", "value-3")],
+            ),
+        ]))
+        .into(),
+    ),
+    timepicker: "elements/timepicker" => TimePickerBuilder::new("timepicker123")
+        .set_initial_time(NaiveTime::from_hms_opt(11, 40, 0).unwrap())
+        .set_placeholder("Select a time")
+        .build(),
+    url_text_input: "elements/url_text_input" => UrlInputBuilder::new("url_text_input-action").build(),
+    users_select: "elements/users_select" => select(
+        "text1234",
+        "Select an item",
+        SelectMenuType::User(UserMenu::new()),
+    ),
+
+    // https://api.slack.com/reference/block-kit/composition-objects
+    confirm: "objects/confirm" => ConfirmationDialog::new(
+        "Are you sure?",
+        mrkdwn("Wouldn't you prefer a good game of _chess_?"),
+        "Do it",
+        "Stop, I've changed my mind!",
+    ),
+    option_object: "objects/option" => option("Maru", "maru"),
+    option_group: "objects/option_group" => OptionInputGroup::new(
+        PlainText::new("Group 1"),
+        vec![
+            option("Choice 1", "value-0"),
+            option("Choice 2", "value-1"),
+            option("Choice 3", "value-2"),
+        ],
+    ),
+    plain_text: "objects/plain_text" => Text::from(PlainText::new_with_emoji(
+        "This is a plain text section block.",
+        true,
+    )),
+    text: "objects/text" => mrkdwn("A message *with some bold text* and _some italicized text_."),
+
+    // https://api.slack.com/reference/surfaces/views
+    home: "surfaces/home" => HomeTab::new(vec![
+        SectionBuilder::new(mrkdwn(
+            "A simple stack of blocks for the simple sample Block Kit Home tab.",
+        ))
+        .build()
+        .into(),
+        Actions::new(vec![
+            ButtonBuilder::new("action_a", PlainText::new_with_emoji("Action A", true))
+                .build()
+                .into(),
+            ButtonBuilder::new("action_b", PlainText::new_with_emoji("Action B", true))
+                .build()
+                .into(),
+        ])
+        .into(),
+    ]),
+    // https://api.slack.com/methods/chat.postMessage
+    message: "surfaces/message" => Message::builder(vec![
+        SectionBuilder::new(mrkdwn(
+            "Danny Torrence left the following review for your property:",
+        ))
+        .build()
+        .into(),
+        SectionBuilder::new(mrkdwn(
+            "<https://example.com|Overlook Hotel> \n :star: \n Doors had too many axe holes, guest in room 237 was far too rowdy, whole place felt stuck in the 1920s.",
+        ))
+        .set_block_id("section567")
+        .set_accessory(
+            elements::Image::new(
+                url("https://is5-ssl.mzstatic.com/image/thumb/Purple3/v4/d3/72/5c/d3725c8f-c642-5d69-1904-aa36e4297885/source/256x256bb.jpg"),
+                "Haunted hotel image".to_string(),
+            )
+            .into(),
+        )
+        .build()
+        .into(),
+        SectionBuilder::new(mrkdwn("*Danny Torrence*"))
+            .set_block_id("section789")
+            .set_fields(vec![mrkdwn("*Average Rating*\n1.0")])
+            .build()
+            .into(),
+    ])
+    .set_channel("C123ABC456")
+    .set_text("Danny Torrence left a 1 star review for your property.")
+    .build(),
+    modal: "surfaces/modal" => View::builder(
+        ModalBuilder::new(
+            PlainText::new("Modal title"),
+            vec![
+                SectionBuilder::new(mrkdwn("It's Block Kit...but _in a modal_"))
+                    .set_block_id("section1")
+                    .set_accessory(
+                        ButtonBuilder::new("button_abc", PlainText::new("Click me"))
+                            .set_value("Button value")
+                            .set_style(ButtonStyle::Danger)
+                            .build()
+                            .into(),
+                    )
+                    .build()
+                    .into(),
+                InputBuilder::new(
+                    PlainText::new("Input label"),
+                    PlainTextInputBuilder::new("input1")
+                        .set_placeholder(PlainText::new("Type in here"))
+                        .set_multiline(false)
+                        .build()
+                        .into(),
+                )
+                .set_optional(false)
+                .build()
+                .into(),
+            ],
+        )
+        .set_close(PlainText::new("Cancel"))
+        .set_submit(PlainText::new("Save"))
+        .build(),
+    )
+    .set_private_metadata("Shhhhhhhh")
+    .set_callback_id("view_identifier_12")
+    .build(),
+}
+
+#[test]
+fn every_example_is_checked() {
+    let mut unchecked = Vec::new();
+    for dir in fs::read_dir(corpus()).unwrap() {
+        let dir = dir.unwrap().path();
+        for file in fs::read_dir(&dir).unwrap() {
+            let file = file.unwrap().path();
+            let name = format!(
+                "{}/{}",
+                dir.file_name().unwrap().to_str().unwrap(),
+                file.file_stem().unwrap().to_str().unwrap()
+            );
+            if !EXAMPLES.contains(&name.as_str()) {
+                unchecked.push(name);
+            }
+        }
+    }
+    assert!(unchecked.is_empty(), "not checked: {:?}", unchecked);
+}